@@ -0,0 +1,85 @@
+//! With the "embedded" feature, compiles a bundled tzdata release with zic and embeds the resulting tzfiles.
+//! The release is the newest one in tzdata/, unless pinned with the TZPARSE_TZDATA environment variable
+//! (ie. TZPARSE_TZDATA=2025b).
+//! TZPARSE_ZONES restricts the embedded zones to a comma separated list, where names ending with "/" select
+//! a whole area (ie. TZPARSE_ZONES=Europe/Paris,America/,UTC).
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=tzdata");
+    println!("cargo:rerun-if-env-changed=TZPARSE_TZDATA");
+    println!("cargo:rerun-if-env-changed=TZPARSE_ZONES");
+    if env::var_os("CARGO_FEATURE_EMBEDDED").is_none() {
+        return;
+    }
+    let manifest = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let mut releases: Vec<String> = fs::read_dir(manifest.join("tzdata"))
+        .expect("tzdata directory")
+        .filter_map(|e| e.ok())
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .collect();
+    // "2024a" < "2024b" < "2024za" < "2025a"
+    releases.sort_by_key(|r| (r.len(), r.clone()));
+    let release = match env::var("TZPARSE_TZDATA") {
+        Ok(pinned) if releases.contains(&pinned) => pinned,
+        Ok(pinned) => panic!("tzdata release {} is not bundled, available: {}", pinned, releases.join(", ")),
+        Err(_) => releases.last().expect("no bundled tzdata release").clone(),
+    };
+
+    let zoneinfo = out.join("zoneinfo");
+    let _ = fs::remove_dir_all(&zoneinfo);
+    let status = Command::new("zic")
+        .arg("-b")
+        .arg("fat")
+        .arg("-d")
+        .arg(&zoneinfo)
+        .arg(manifest.join("tzdata").join(&release).join("tzdata.zi"))
+        .status()
+        .expect("running zic, needed to compile embedded tzdata");
+    assert!(status.success(), "zic failed on tzdata {}", release);
+
+    let mut zones = Vec::new();
+    collect(&zoneinfo, "", &mut zones);
+    zones.sort();
+    if let Ok(selection) = env::var("TZPARSE_ZONES") {
+        zones = select(zones, &selection);
+    }
+    let mut code = format!("/// tzdata release compiled in\npub const EMBEDDED_RELEASE: &str = {:?};\n\n", release);
+    code.push_str("/// Embedded tzfiles, by zone name\npub(crate) static EMBEDDED_ZONES: &[(&str, &[u8])] = &[\n");
+    for (name, path) in zones {
+        code.push_str(&format!("    ({:?}, include_bytes!({:?})),\n", name, path));
+    }
+    code.push_str("];\n");
+    fs::write(out.join("embedded.rs"), code).unwrap();
+}
+
+/// Keeps the selected zones, failing on selections matching none: a typo would silently leave a zone out.
+fn select(zones: Vec<(String, PathBuf)>, selection: &str) -> Vec<(String, PathBuf)> {
+    let selection: Vec<&str> = selection.split(',').map(str::trim).filter(|s| !s.is_empty()).collect();
+    let selected = |name: &str, s: &str| if s.ends_with('/') { name.starts_with(s) } else { name == s };
+    for s in &selection {
+        if !zones.iter().any(|(name, _)| selected(name, s)) {
+            panic!("TZPARSE_ZONES: no zone matches {}", s);
+        }
+    }
+    zones.into_iter().filter(|(name, _)| selection.iter().any(|s| selected(name, s))).collect()
+}
+
+fn collect(dir: &Path, prefix: &str, zones: &mut Vec<(String, PathBuf)>) {
+    for entry in fs::read_dir(dir).unwrap().filter_map(|e| e.ok()) {
+        let name = format!("{}{}", prefix, entry.file_name().to_string_lossy());
+        let path = entry.path();
+        if path.is_dir() {
+            collect(&path, &format!("{}/", name), zones);
+        } else {
+            zones.push((name, path));
+        }
+    }
+}
@@ -0,0 +1,90 @@
+/// One meaning of a timezone abbreviation in human-written timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AbbreviationMeaning {
+    /// Abbreviation, upper case
+    pub abbreviation: &'static str,
+    /// What it stands for
+    pub name: &'static str,
+    /// Offset to GMT, in seconds
+    pub gmtoff: isize,
+    /// Zones using it with this meaning
+    pub zones: &'static [&'static str],
+}
+
+const fn meaning(
+    abbreviation: &'static str,
+    name: &'static str,
+    gmtoff: isize,
+    zones: &'static [&'static str],
+) -> AbbreviationMeaning {
+    AbbreviationMeaning { abbreviation, name, gmtoff, zones }
+}
+
+/// Abbreviations with several meanings, sorted by abbreviation.
+/// Some are no longer in tzdata, which now uses numeric ones (ie. "-04" for Amazon Time), but still appear in the wild.
+const AMBIGUOUS: &[AbbreviationMeaning] = &[
+    meaning("AMT", "Amazon Time", -14400, &["America/Manaus", "America/Porto_Velho", "America/Boa_Vista"]),
+    meaning("AMT", "Armenia Time", 14400, &["Asia/Yerevan"]),
+    meaning("AST", "Atlantic Standard Time", -14400, &["America/Halifax", "America/Puerto_Rico", "Atlantic/Bermuda"]),
+    meaning("AST", "Arabia Standard Time", 10800, &["Asia/Riyadh", "Asia/Baghdad", "Asia/Qatar"]),
+    meaning("BST", "British Summer Time", 3600, &["Europe/London"]),
+    meaning("BST", "Bangladesh Standard Time", 21600, &["Asia/Dhaka"]),
+    meaning("CDT", "Central Daylight Time", -18000, &["America/Chicago", "America/Winnipeg"]),
+    meaning("CDT", "Cuba Daylight Time", -14400, &["America/Havana"]),
+    meaning("CST", "Central Standard Time", -21600, &["America/Chicago", "America/Winnipeg", "America/Mexico_City"]),
+    meaning("CST", "China Standard Time", 28800, &["Asia/Shanghai", "Asia/Taipei", "Asia/Macau"]),
+    meaning("CST", "Cuba Standard Time", -18000, &["America/Havana"]),
+    meaning("ECT", "Ecuador Time", -18000, &["America/Guayaquil"]),
+    meaning("ECT", "Eastern Caribbean Time", -14400, &["America/Port_of_Spain", "America/Barbados"]),
+    meaning("GST", "Gulf Standard Time", 14400, &["Asia/Dubai", "Asia/Muscat"]),
+    meaning("GST", "South Georgia Time", -7200, &["Atlantic/South_Georgia"]),
+    meaning("IST", "India Standard Time", 19800, &["Asia/Kolkata"]),
+    meaning("IST", "Israel Standard Time", 7200, &["Asia/Jerusalem"]),
+    meaning("IST", "Irish Standard Time", 3600, &["Europe/Dublin"]),
+    meaning("MST", "Mountain Standard Time", -25200, &["America/Denver", "America/Phoenix", "America/Edmonton"]),
+    meaning("MST", "Malaysia Standard Time", 28800, &["Asia/Kuala_Lumpur"]),
+    meaning("PST", "Pacific Standard Time", -28800, &["America/Los_Angeles", "America/Vancouver"]),
+    meaning("PST", "Philippine Standard Time", 28800, &["Asia/Manila"]),
+    meaning("SST", "Samoa Standard Time", -39600, &["Pacific/Pago_Pago"]),
+    meaning("SST", "Singapore Standard Time", 28800, &["Asia/Singapore"]),
+    meaning("WST", "Western Standard Time", 28800, &["Australia/Perth"]),
+    meaning("WST", "West Samoa Time", 46800, &["Pacific/Apia"]),
+];
+
+/// Returns the meanings of an ambiguous abbreviation (case insensitive), empty if it is not known as ambiguous.
+pub fn abbreviation_meanings(abbreviation: &str) -> Vec<&'static AbbreviationMeaning> {
+    AMBIGUOUS
+        .iter()
+        .filter(|m| m.abbreviation.eq_ignore_ascii_case(abbreviation))
+        .collect()
+}
+
+/// Returns the abbreviations known to have several meanings, sorted.
+pub fn ambiguous_abbreviations() -> Vec<&'static str> {
+    let mut abbreviations: Vec<_> = AMBIGUOUS.iter().map(|m| m.abbreviation).collect();
+    abbreviations.dedup();
+    abbreviations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Resolver;
+    #[test]
+    fn meanings() {
+        let cst: Vec<_> = abbreviation_meanings("cst").iter().map(|m| m.gmtoff).collect();
+        assert_eq!(cst, vec![-21600, 28800, -18000]);
+        assert_eq!(abbreviation_meanings("IST")[0].zones, &["Asia/Kolkata"]);
+        assert!(abbreviation_meanings("CET").is_empty());
+        let abbreviations = ambiguous_abbreviations();
+        assert!(abbreviations.windows(2).all(|w| w[0] < w[1]));
+        assert!(abbreviations.contains(&"AMT"));
+        let resolver = Resolver::system();
+        for meaning in AMBIGUOUS {
+            assert!(abbreviation_meanings(meaning.abbreviation).len() > 1);
+            for zone in meaning.zones {
+                assert!(resolver.resolve(zone).is_ok(), "{}", zone);
+            }
+        }
+    }
+}
@@ -0,0 +1,402 @@
+use crate::{Error, ErrorKind, Timechange, Zone};
+use chrono::prelude::*;
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashSet},
+};
+
+/// An unusual event in a zone's history.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Anomaly {
+    /// UTC time of the transition showing the anomaly
+    pub time: DateTime<Utc>,
+    /// What is unusual
+    pub kind: AnomalyKind,
+}
+
+/// Kinds of anomalies.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AnomalyKind {
+    /// Offset to GMT changing by more than two hours at once
+    LargeOffsetJump { from: isize, to: isize },
+    /// DST two hours or more ahead of standard time
+    DoubleSummerTime { saving: isize },
+    /// Offset to GMT which is not a whole number of minutes
+    SubMinuteOffset { gmtoff: isize },
+    /// Abbreviation made of digits and signs (ie. "+0530") rather than letters
+    NumericAbbreviation { abbreviation: String },
+}
+
+/// Returns the unusual events in a zone's recorded history, see Zone::anomalies.
+pub fn get_anomalies(requested_timezone: &str) -> Result<Vec<Anomaly>, Error> {
+    Ok(Zone::from_tzfile(requested_timezone)?.anomalies())
+}
+
+impl Zone {
+    /// Returns the unusual events in the zone's recorded history, in chronological order:
+    /// offset jumps larger than two hours, double summer time, sub-minute offsets and numeric abbreviations.
+    pub fn anomalies(&self) -> Vec<Anomaly> {
+        let mut anomalies = Vec::new();
+        let mut previous: Option<isize> = None;
+        let mut standard: Option<isize> = None;
+        for tc in self.timechanges() {
+            let mut push = |kind| anomalies.push(Anomaly { time: tc.time, kind });
+            if let Some(from) = previous {
+                if (tc.gmtoff - from).abs() > 7200 {
+                    push(AnomalyKind::LargeOffsetJump { from, to: tc.gmtoff });
+                }
+            }
+            if tc.isdst {
+                if let Some(standard) = standard {
+                    if tc.gmtoff - standard >= 7200 {
+                        push(AnomalyKind::DoubleSummerTime { saving: tc.gmtoff - standard });
+                    }
+                }
+            } else {
+                standard = Some(tc.gmtoff);
+            }
+            if tc.gmtoff % 60 != 0 {
+                push(AnomalyKind::SubMinuteOffset { gmtoff: tc.gmtoff });
+            }
+            let abbreviation = &tc.abbreviation;
+            if abbreviation.chars().any(|c| c.is_ascii_digit())
+                && abbreviation.chars().all(|c| c.is_ascii_digit() || c == '+' || c == '-')
+            {
+                push(AnomalyKind::NumericAbbreviation { abbreviation: abbreviation.clone() });
+            }
+            previous = Some(tc.gmtoff);
+        }
+        anomalies
+    }
+}
+
+/// Structured profile of a zone.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Stats {
+    /// Number of recorded transitions
+    pub transition_count: usize,
+    /// First recorded transition
+    pub first_transition: Option<DateTime<Utc>>,
+    /// Last recorded transition
+    pub last_transition: Option<DateTime<Utc>>,
+    /// Number of distinct offsets to GMT
+    pub offset_count: usize,
+    /// Number of distinct abbreviations
+    pub abbreviation_count: usize,
+    /// Does the tzfile end with a POSIX TZ string, ruling times after the last transition ?
+    pub has_footer: bool,
+}
+
+/// Returns a structured profile of a zone, see Zone::stats.
+pub fn get_stats(requested_timezone: &str) -> Result<Stats, Error> {
+    Ok(Zone::from_tzfile(requested_timezone)?.stats())
+}
+
+impl Zone {
+    /// Returns a structured profile of the zone: transition count and range, distinct offsets and abbreviations, POSIX footer presence.
+    pub fn stats(&self) -> Stats {
+        let timechanges = self.timechanges();
+        let offsets: HashSet<isize> = timechanges.iter().map(|tc| tc.gmtoff).collect();
+        let abbreviations: HashSet<&str> = timechanges.iter().map(|tc| tc.abbreviation.as_str()).collect();
+        Stats {
+            transition_count: timechanges.len(),
+            first_transition: timechanges.first().map(|tc| tc.time),
+            last_transition: timechanges.last().map(|tc| tc.time),
+            offset_count: offsets.len(),
+            abbreviation_count: abbreviations.len(),
+            has_footer: self.footer().is_some(),
+        }
+    }
+}
+
+/// A local time type: offset to GMT, DST flag and abbreviation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZoneOffset {
+    /// Offset to GMT, in seconds
+    pub gmtoff: isize,
+    /// Is it DST ?
+    pub isdst: bool,
+    /// Abbreviation
+    pub abbreviation: String,
+}
+
+impl From<&Timechange> for ZoneOffset {
+    /// Local time type a timechange switches to
+    fn from(tc: &Timechange) -> ZoneOffset {
+        ZoneOffset {
+            gmtoff: tc.gmtoff,
+            isdst: tc.isdst,
+            abbreviation: tc.abbreviation.clone(),
+        }
+    }
+}
+
+/// What a transition does to the clocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransitionKind {
+    /// Clocks move forward, entering DST (or leaving negative DST, as in Europe/Dublin)
+    SpringForward,
+    /// Clocks move back, leaving DST (or entering negative DST)
+    FallBack,
+    /// Offset to GMT changes without entering or leaving DST, mostly a change of standard time
+    StandardOffsetChange,
+    /// Only the abbreviation changes, ie. "EWT" to "EPT" in 1945, or nothing does
+    AbbreviationOnly,
+}
+
+impl Timechange {
+    /// Classifies the timechange against the one before it.
+    /// Entering or leaving DST is told by the direction of the offset change, so that negative DST is handled.
+    pub fn classify(&self, previous: &Timechange) -> TransitionKind {
+        match (self.isdst != previous.isdst, self.gmtoff.cmp(&previous.gmtoff)) {
+            (true, Ordering::Greater) => TransitionKind::SpringForward,
+            (true, Ordering::Less) => TransitionKind::FallBack,
+            // DST flag alone
+            (true, Ordering::Equal) if self.isdst => TransitionKind::SpringForward,
+            (true, Ordering::Equal) => TransitionKind::FallBack,
+            (false, Ordering::Equal) => TransitionKind::AbbreviationOnly,
+            (false, _) => TransitionKind::StandardOffsetChange,
+        }
+    }
+}
+
+impl TransitionKind {
+    /// Kind of a timechange given the previous one, if any.
+    /// Without one, the timechange leaves Local Mean Time: entering DST, or changing standard time.
+    pub(crate) fn of(previous: Option<&Timechange>, tc: &Timechange) -> TransitionKind {
+        match previous {
+            Some(previous) => tc.classify(previous),
+            None if tc.isdst => TransitionKind::SpringForward,
+            None => TransitionKind::StandardOffsetChange,
+        }
+    }
+}
+
+/// Returns the distinct local time types a zone has used, see Zone::offsets.
+pub fn get_offsets(requested_timezone: &str) -> Result<Vec<ZoneOffset>, Error> {
+    Ok(Zone::from_tzfile(requested_timezone)?.offsets())
+}
+
+impl Zone {
+    /// Returns the distinct local time types the zone has used in its recorded history, in order of first use.
+    pub fn offsets(&self) -> Vec<ZoneOffset> {
+        let mut offsets: Vec<ZoneOffset> = Vec::new();
+        for tc in self.timechanges() {
+            let offset = ZoneOffset::from(tc);
+            if !offsets.contains(&offset) {
+                offsets.push(offset);
+            }
+        }
+        offsets
+    }
+}
+
+/// A span of time, from `start` included to `end` excluded. No end means "still ongoing".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Period {
+    /// Start of the period
+    pub start: DateTime<Utc>,
+    /// End of the period, if any
+    pub end: Option<DateTime<Utc>>,
+}
+
+/// Returns every abbreviation a zone has used, see Zone::abbreviations.
+pub fn get_abbreviations(requested_timezone: &str) -> Result<BTreeMap<String, Vec<Period>>, Error> {
+    Ok(Zone::from_tzfile(requested_timezone)?.abbreviations())
+}
+
+impl Zone {
+    /// Returns every abbreviation the zone has used in its recorded history, with the periods during which it was active.
+    /// The last period stays open, even though the zone's POSIX rule may alternate abbreviations afterwards.
+    pub fn abbreviations(&self) -> BTreeMap<String, Vec<Period>> {
+        let timechanges = self.timechanges();
+        let mut abbreviations: BTreeMap<String, Vec<Period>> = BTreeMap::new();
+        for (i, tc) in timechanges.iter().enumerate() {
+            let end = timechanges.get(i + 1).map(|next| next.time);
+            let periods = abbreviations.entry(tc.abbreviation.clone()).or_default();
+            // Transitions keeping the abbreviation (ie. offset change) extend the current period
+            match periods.last_mut() {
+                Some(last) if last.end == Some(tc.time) => last.end = end,
+                _ => periods.push(Period { start: tc.time, end }),
+            }
+        }
+        abbreviations
+    }
+}
+
+/// Range of time backed by a zone's recorded transitions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Coverage {
+    /// Earliest recorded transition. Before it, the zone's initial local time type applies.
+    pub first: Option<DateTime<Utc>>,
+    /// Latest recorded transition
+    pub last: Option<DateTime<Utc>>,
+    /// POSIX TZ string extrapolating the zone after the latest transition, if any
+    pub rule: Option<String>,
+}
+
+impl Coverage {
+    /// Are answers about this instant backed by recorded transitions rather than extrapolated ?
+    pub fn is_recorded(&self, time: DateTime<Utc>) -> bool {
+        match (self.first, self.last) {
+            (Some(first), Some(last)) => first <= time && time <= last,
+            _ => false,
+        }
+    }
+}
+
+/// Returns the range of time backed by a zone's recorded transitions, see Zone::coverage.
+pub fn get_coverage(requested_timezone: &str) -> Result<Coverage, Error> {
+    Ok(Zone::from_tzfile(requested_timezone)?.coverage())
+}
+
+impl Zone {
+    /// Returns the earliest and latest recorded transitions of the zone, and the POSIX rule extrapolating it beyond, if any.
+    pub fn coverage(&self) -> Coverage {
+        Coverage {
+            first: self.timechanges().first().map(|tc| tc.time),
+            last: self.timechanges().last().map(|tc| tc.time),
+            rule: self.footer().map(String::from),
+        }
+    }
+}
+
+/// Returns the periods of a (UTC) year during which the offset of zone `b` to zone `a` stays the same,
+/// with that difference in seconds (`b` minus `a`, ie. -18000 for New York relative to London most of the year).
+pub fn offset_differences(a: &Zone, b: &Zone, year: i32) -> Result<Vec<(Period, isize)>, Error> {
+    const OPERATION: &str = "comparing offsets";
+    let bound = |year: Option<i32>| {
+        year.and_then(|y| NaiveDate::from_ymd_opt(y, 1, 1))
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .map(|d| Utc.from_utc_datetime(&d))
+            .ok_or_else(|| Error::new(ErrorKind::OutOfRange, OPERATION))
+    };
+    let (start, end) = (bound(Some(year))?, bound(year.checked_add(1))?);
+    let mut boundaries: Vec<DateTime<Utc>> = a
+        .changes_between(start, end)
+        .iter()
+        .chain(b.changes_between(start, end).iter())
+        .map(|tc| tc.time)
+        .filter(|time| *time < end)
+        .collect();
+    boundaries.push(start);
+    boundaries.sort();
+    boundaries.dedup();
+    let mut differences: Vec<(Period, isize)> = Vec::new();
+    for (i, from) in boundaries.iter().enumerate() {
+        let offset = |zone: &Zone| {
+            zone.offset_at(*from)
+                .map(|o| o.gmtoff)
+                .ok_or_else(|| Error::new(ErrorKind::NoData, OPERATION).with_zone(zone.name()))
+        };
+        let difference = offset(b)? - offset(a)?;
+        let until = Some(boundaries.get(i + 1).copied().unwrap_or(end));
+        match differences.last_mut() {
+            Some((period, last)) if *last == difference => period.end = until,
+            _ => differences.push((Period { start: *from, end: until }, difference)),
+        }
+    }
+    Ok(differences)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn anomalies() {
+        // British Double Summer Time during World War II
+        let london = get_anomalies("/usr/share/zoneinfo/Europe/London").unwrap();
+        assert!(london.iter().any(|a| a.time.year() == 1941
+            && a.kind == AnomalyKind::DoubleSummerTime { saving: 7200 }));
+        // Paris Mean Time was +0:09:21
+        let paris = get_anomalies("/usr/share/zoneinfo/Europe/Paris").unwrap();
+        assert!(paris.iter().any(|a| a.kind == AnomalyKind::SubMinuteOffset { gmtoff: 561 }));
+        assert!(get_anomalies("/usr/share/zoneinfo/America/Phoenix").unwrap().is_empty());
+    }
+
+    #[test]
+    fn stats() {
+        let phoenix = get_stats("/usr/share/zoneinfo/America/Phoenix").unwrap();
+        assert_eq!(phoenix.transition_count, 11);
+        assert_eq!(phoenix.first_transition, Some(Utc.ymd(1883, 11, 18).and_hms(19, 0, 0)));
+        assert_eq!(phoenix.last_transition, Some(Utc.ymd(1967, 10, 29).and_hms(8, 0, 0)));
+        assert_eq!(phoenix.offset_count, 2);
+        assert_eq!(phoenix.abbreviation_count, 3);
+        assert!(phoenix.has_footer);
+        let utc = get_stats("/usr/share/zoneinfo/Etc/UTC").unwrap();
+        assert_eq!(utc.transition_count, 0);
+        assert_eq!(utc.first_transition, None);
+    }
+
+    #[test]
+    fn offsets() {
+        let phoenix = get_offsets("/usr/share/zoneinfo/America/Phoenix").unwrap();
+        assert_eq!(
+            phoenix,
+            vec![
+                ZoneOffset { gmtoff: -25200, isdst: false, abbreviation: "MST".to_string() },
+                ZoneOffset { gmtoff: -21600, isdst: true, abbreviation: "MDT".to_string() },
+                ZoneOffset { gmtoff: -21600, isdst: true, abbreviation: "MWT".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn abbreviations() {
+        let phoenix = get_abbreviations("/usr/share/zoneinfo/America/Phoenix").unwrap();
+        assert_eq!(phoenix.keys().collect::<Vec<_>>(), vec!["MDT", "MST", "MWT"]);
+        assert_eq!(
+            phoenix["MWT"],
+            vec![
+                Period {
+                    start: Utc.ymd(1942, 2, 9).and_hms(9, 0, 0),
+                    end: Some(Utc.ymd(1944, 1, 1).and_hms(6, 1, 0)),
+                },
+                Period {
+                    start: Utc.ymd(1944, 4, 1).and_hms(7, 1, 0),
+                    end: Some(Utc.ymd(1944, 10, 1).and_hms(6, 1, 0)),
+                },
+            ]
+        );
+        assert_eq!(phoenix["MST"].last().unwrap().end, None);
+    }
+
+    #[test]
+    fn classification() {
+        let kinds = |zone: &str, year: i32| {
+            let timechanges = Zone::from_tzfile(&format!("/usr/share/zoneinfo/{}", zone)).unwrap().timechanges().to_vec();
+            timechanges
+                .windows(2)
+                .filter(|w| w[1].time.year() == year)
+                .map(|w| w[1].classify(&w[0]))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(kinds("Europe/Paris", 2019), vec![TransitionKind::SpringForward, TransitionKind::FallBack]);
+        // Negative DST: GMT is flagged DST in winter
+        assert_eq!(kinds("Europe/Dublin", 2019), vec![TransitionKind::SpringForward, TransitionKind::FallBack]);
+        assert_eq!(kinds("Asia/Pyongyang", 2015), vec![TransitionKind::StandardOffsetChange]);
+        assert_eq!(kinds("America/New_York", 1945), vec![TransitionKind::AbbreviationOnly, TransitionKind::FallBack]);
+    }
+
+    #[test]
+    fn coverage() {
+        let paris = get_coverage("/usr/share/zoneinfo/Europe/Paris").unwrap();
+        assert_eq!(paris.first, Some(Utc.ymd(1891, 3, 15).and_hms(23, 50, 39)));
+        assert_eq!(paris.rule.as_deref(), Some("CET-1CEST,M3.5.0,M10.5.0/3"));
+        assert!(paris.is_recorded(Utc.ymd(1990, 1, 1).and_hms(0, 0, 0)));
+        assert!(!paris.is_recorded(Utc.ymd(2100, 1, 1).and_hms(0, 0, 0)));
+    }
+
+    #[test]
+    fn differences() {
+        let london = Zone::from_tzfile("/usr/share/zoneinfo/Europe/London").unwrap();
+        let new_york = Zone::from_tzfile("/usr/share/zoneinfo/America/New_York").unwrap();
+        let differences = offset_differences(&london, &new_york, 2019).unwrap();
+        let hours: Vec<_> = differences.iter().map(|(_, d)| d / 3600).collect();
+        assert_eq!(hours, vec![-5, -4, -5, -4, -5]);
+        // New York moved to DST on March 10th, London on March 31st
+        assert_eq!(differences[1].0.start, Utc.ymd(2019, 3, 10).and_hms(7, 0, 0));
+        assert_eq!(differences[1].0.end, Some(Utc.ymd(2019, 3, 31).and_hms(1, 0, 0)));
+        assert_eq!(differences[4].0.end, Some(Utc.ymd(2020, 1, 1).and_hms(0, 0, 0)));
+    }
+}
@@ -0,0 +1,64 @@
+//! Async variants of the loading functions, with the "tokio" feature. libtzfile only reads files synchronously:
+//! reads run on tokio's blocking thread pool, so slow filesystems never stall the runtime threads.
+
+use crate::{get_timechanges_for, get_zoneinfo, Error, Timechange, TzDatabase, Tzinfo, YearSpec, Zone};
+use std::{io, panic};
+
+/// Runs blocking reads on tokio's blocking thread pool.
+async fn blocking<T, F>(read: F, operation: &'static str) -> Result<T, Error>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, Error> + Send + 'static,
+{
+    match tokio::task::spawn_blocking(read).await {
+        Ok(result) => result,
+        Err(e) if e.is_panic() => panic::resume_unwind(e.into_panic()),
+        // The runtime is shutting down
+        Err(e) => Err(Error::io(io::Error::other(e), operation)),
+    }
+}
+
+/// Same as get_zoneinfo, without blocking the async runtime.
+pub async fn get_zoneinfo_async(requested_timezone: &str) -> Result<Tzinfo, Error> {
+    let path = requested_timezone.to_string();
+    blocking(move || get_zoneinfo(&path), "computing zoneinfo").await
+}
+
+/// Same as get_timechanges_for, without blocking the async runtime.
+pub async fn get_timechanges_for_async(requested_timezone: &str, years: YearSpec) -> Result<Vec<Timechange>, Error> {
+    let path = requested_timezone.to_string();
+    blocking(move || get_timechanges_for(&path, years), "reading timechanges").await
+}
+
+impl TzDatabase {
+    /// Same as TzDatabase::zone, without blocking the async runtime.
+    pub async fn load_async<Z: AsRef<str>>(&self, name: Z) -> Result<Zone, Error> {
+        let (db, name) = (self.clone(), name.as_ref().to_string());
+        blocking(move || db.zone(&name), "loading zone").await
+    }
+
+    /// Same as TzDatabase::zones, without blocking the async runtime.
+    pub async fn zones_async(&self) -> Result<Vec<String>, Error> {
+        let db = self.clone();
+        blocking(move || db.zones(), "listing zones").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn load_async() {
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        runtime.block_on(async {
+            let paris = get_zoneinfo_async("/usr/share/zoneinfo/Europe/Paris").await.unwrap();
+            assert_eq!(paris.timezone, "Europe/Paris");
+            let changes = get_timechanges_for_async("/usr/share/zoneinfo/Europe/Paris", YearSpec::Year(2019)).await;
+            assert_eq!(changes.unwrap().len(), 2);
+            assert!(get_zoneinfo_async("/usr/share/zoneinfo/Europe/Nowhere").await.is_err());
+            let db = TzDatabase::open("/usr/share/zoneinfo");
+            assert_eq!(db.load_async("Asia/Tokyo").await.unwrap(), db.zone("Asia/Tokyo").unwrap());
+            assert!(db.zones_async().await.unwrap().contains(&"Asia/Tokyo".to_string()));
+        });
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{Error, Timechange, TzDatabase, Zone};
+use chrono::prelude::*;
+
+/// A timechange of a zone's history, with where it comes from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourcedTimechange {
+    /// The timechange
+    pub timechange: Timechange,
+    /// The timechange comes from backzone, ie. is not in the main data
+    pub backzone: bool,
+}
+
+/// Pre-1970 history of zones from the backzone data set, marked against the main data.
+/// The main data only guarantees timestamps since 1970, merging zones which have agreed since then
+/// (ie. Europe/Oslo is a link to Europe/Berlin); backzone keeps their best known separate history.
+/// The backzone tree is compiled with it (`make PACKRATDATA=backzone PACKRATLIST=zone.tab`),
+/// as some distributions do for their system tree.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Backzone {
+    main: TzDatabase,
+    backzone: TzDatabase,
+}
+
+impl Backzone {
+    /// Combines a main tree with a tree compiled with backzone.
+    pub fn new(main: TzDatabase, backzone: TzDatabase) -> Backzone {
+        Backzone { main, backzone }
+    }
+
+    /// Loads a zone with its backzone history, or the main one if backzone does not have the zone.
+    pub fn zone(&self, name: &str) -> Result<Zone, Error> {
+        self.backzone.zone(name).or_else(|_| self.main.zone(name))
+    }
+
+    /// Returns the instant from which the main data takes over a zone's history, None if backzone adds nothing.
+    /// Periods before it come from backzone.
+    pub fn divergence(&self, name: &str) -> Result<Option<DateTime<Utc>>, Error> {
+        let history = self.history(name)?;
+        Ok(match history.iter().position(|tc| !tc.backzone) {
+            Some(0) => None,
+            Some(i) => Some(history[i].timechange.time),
+            // Nothing shared: the whole recorded history is backzone's
+            None => history.last().map(|tc| tc.timechange.time),
+        })
+    }
+
+    /// Returns the backzone history of a zone, marking the timechanges which do not come from the main data.
+    pub fn history(&self, name: &str) -> Result<Vec<SourcedTimechange>, Error> {
+        let (backzone, main) = (self.zone(name)?, self.main.zone(name)?);
+        let own = backzone.timechanges().len() - shared_suffix(backzone.timechanges(), main.timechanges());
+        Ok(backzone
+            .timechanges()
+            .iter()
+            .enumerate()
+            .map(|(i, tc)| SourcedTimechange { timechange: tc.clone(), backzone: i < own })
+            .collect())
+    }
+}
+
+/// Number of timechanges both histories end with
+fn shared_suffix(a: &[Timechange], b: &[Timechange]) -> usize {
+    a.iter().rev().zip(b.iter().rev()).take_while(|(a, b)| a == b).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, fs};
+    #[test]
+    fn backzone() {
+        // The system tree is compiled with backzone, while the main data links Europe/Oslo to Europe/Berlin
+        let main = env::temp_dir().join(format!("tzparse-main-{}", std::process::id()));
+        fs::create_dir_all(main.join("Europe")).unwrap();
+        fs::copy("/usr/share/zoneinfo/Europe/Berlin", main.join("Europe/Oslo")).unwrap();
+        fs::copy("/usr/share/zoneinfo/Europe/Berlin", main.join("Europe/Berlin")).unwrap();
+        let backzone = Backzone::new(TzDatabase::open(&main), TzDatabase::open("/usr/share/zoneinfo"));
+        let history = backzone.history("Europe/Oslo").unwrap();
+        assert_eq!(history[0].timechange.time.year(), 1894);
+        assert!(history[0].backzone);
+        assert!(!history.last().unwrap().backzone);
+        let divergence = backzone.divergence("Europe/Oslo").unwrap().unwrap();
+        assert!(divergence.year() < 1990);
+        assert!(history.iter().all(|tc| tc.backzone == (tc.timechange.time < divergence)));
+        assert_eq!(backzone.divergence("Europe/Berlin").unwrap(), None);
+        fs::remove_dir_all(main).unwrap();
+    }
+}
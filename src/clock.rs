@@ -0,0 +1,27 @@
+use chrono::prelude::*;
+
+/// Source of the current instant, so results depending on "now" can be made deterministic.
+pub trait Clock {
+    /// Current instant
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The system clock, used by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock stopped at a given instant, for tests and replays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
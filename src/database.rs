@@ -0,0 +1,182 @@
+use crate::{Error, Resolver, Tzinfo, TzfileProvider, Zone, ZoneId, ZONEINFO_DIR};
+use chrono::prelude::*;
+use std::{
+    collections::BTreeMap,
+    env,
+    fs::{self, File},
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+/// Top-level entries of a zoneinfo tree which are not zones of their own
+const SKIPPED: &[&str] = &["posix", "right", "posixrules", "localtime"];
+
+/// A zoneinfo tree, giving access to all its zones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TzDatabase {
+    root: PathBuf,
+    provider: TzfileProvider,
+}
+
+impl TzDatabase {
+    /// Opens the zoneinfo tree in the given directory.
+    pub fn open<P: Into<PathBuf>>(root: P) -> TzDatabase {
+        let root = root.into();
+        TzDatabase {
+            provider: TzfileProvider::new(Resolver::new(vec![root.clone()])),
+            root,
+        }
+    }
+
+    /// Opens the zoneinfo tree in the TZDIR environment variable if set, else the system one.
+    pub fn system() -> TzDatabase {
+        match env::var_os("TZDIR") {
+            Some(dir) => TzDatabase::open(dir),
+            None => TzDatabase::open(ZONEINFO_DIR),
+        }
+    }
+
+    /// Root directory of the tree
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the names of all zones in the tree, sorted.
+    /// posix/ and right/ variants, posixrules, localtime and files which are not tzfiles are left out.
+    pub fn zones(&self) -> Result<Vec<String>, Error> {
+        let mut zones = Vec::new();
+        self.scan(&self.root, "", &mut zones)?;
+        zones.sort();
+        Ok(zones)
+    }
+
+    fn scan(&self, dir: &Path, prefix: &str, zones: &mut Vec<String>) -> Result<(), Error> {
+        let entries = fs::read_dir(dir).map_err(|e| Error::io(e, "listing zones").with_zone(&dir.to_string_lossy()))?;
+        for entry in entries.flatten() {
+            let file_name = entry.file_name().to_string_lossy().into_owned();
+            if prefix.is_empty() && SKIPPED.contains(&file_name.as_str()) {
+                continue;
+            }
+            let name = format!("{}{}", prefix, file_name);
+            let path = entry.path();
+            if path.is_dir() {
+                self.scan(&path, &format!("{}/", name), zones)?;
+            } else if is_tzfile(&path) {
+                zones.push(name);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the zones of the tree as identifiers, sorted. Deprecated names, normalized to another zone, are left out.
+    pub fn zone_ids(&self) -> Result<Vec<ZoneId>, Error> {
+        let mut ids = Vec::new();
+        for name in self.zones()? {
+            let id = ZoneId::new(&name)?;
+            if id.as_str() == name {
+                ids.push(id);
+            }
+        }
+        Ok(ids)
+    }
+
+    /// Loads a zone of the tree by name (ie. "Europe/Paris") or ZoneId.
+    pub fn zone<Z: AsRef<str>>(&self, name: Z) -> Result<Zone, Error> {
+        Zone::load(&self.provider, name.as_ref())
+    }
+
+    /// Returns the Tzinfo of every zone of the tree at one shared instant, or why it could not be computed
+    /// (ie. a corrupt tzfile, or more than two timechanges that year): no zone is left out.
+    pub fn zoneinfo_all(&self, now: DateTime<Utc>) -> Result<BTreeMap<String, Result<Tzinfo, Error>>, Error> {
+        self.zoneinfo_all_with_progress(now, |_| {})
+    }
+
+    /// Same as zoneinfo_all, calling `progress` after each zone, ie. to show a progress bar over the whole tree.
+    /// Failed zones are counted in Progress::errors, and the load goes on.
+    pub fn zoneinfo_all_with_progress<F: FnMut(&Progress)>(
+        &self,
+        now: DateTime<Utc>,
+        mut progress: F,
+    ) -> Result<BTreeMap<String, Result<Tzinfo, Error>>, Error> {
+        let zones = self.zones()?;
+        let mut all = BTreeMap::new();
+        let mut errors = 0;
+        for (done, name) in zones.iter().enumerate() {
+            let tzinfo = self.zone(name).and_then(|zone| zone.tzinfo(now));
+            if let Err(e) = &tzinfo {
+                debug!("{}: {}", name, e);
+                errors += 1;
+            }
+            all.insert(name.clone(), tzinfo);
+            progress(&Progress { done: done + 1, total: zones.len(), zone: name, errors });
+        }
+        Ok(all)
+    }
+}
+
+/// Progress of a bulk load over a zoneinfo tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Progress<'a> {
+    /// Zones done, the current one included
+    pub done: usize,
+    /// Zones in the tree
+    pub total: usize,
+    /// Current zone
+    pub zone: &'a str,
+    /// Zones failed so far
+    pub errors: usize,
+}
+
+/// Does the file start with the TZif magic ?
+fn is_tzfile(path: &Path) -> bool {
+    let mut magic = [0; 4];
+    File::open(path)
+        .and_then(|mut f| f.read_exact(&mut magic))
+        .map(|_| &magic == b"TZif")
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn database() {
+        let db = TzDatabase::open("/usr/share/zoneinfo");
+        let zones = db.zones().unwrap();
+        assert!(zones.contains(&"Europe/Paris".to_string()));
+        assert!(!zones.iter().any(|z| z.starts_with("right/") || z == "posixrules" || z.ends_with(".tab")));
+        let all = db.zoneinfo_all(Utc.ymd(2019, 7, 14).and_hms(12, 0, 0)).unwrap();
+        assert_eq!(all["Europe/Paris"].as_ref().unwrap().abbreviation, "CEST");
+        assert_eq!(all["Asia/Tokyo"].as_ref().unwrap().utc_offset, FixedOffset::east(9 * 3600));
+        assert_eq!(all["Etc/UTC"].as_ref().unwrap().abbreviation, "UTC");
+        assert_eq!(all.len(), zones.len());
+    }
+
+    #[test]
+    fn progress() {
+        let db = TzDatabase::open("/usr/share/zoneinfo");
+        let now = Utc.ymd(2019, 7, 14).and_hms(12, 0, 0);
+        let mut steps = Vec::new();
+        let all = db.zoneinfo_all_with_progress(now, |p| steps.push((p.done, p.total, p.errors))).unwrap();
+        let (done, total, errors) = *steps.last().unwrap();
+        assert_eq!(done, total);
+        assert_eq!(total, db.zones().unwrap().len());
+        assert_eq!(all.len(), total);
+        assert_eq!(all.values().filter(|tzinfo| tzinfo.is_err()).count(), errors);
+        assert!(steps.windows(2).all(|w| w[1].0 == w[0].0 + 1 && w[1].2 >= w[0].2));
+
+        // A corrupt tzfile is counted, not fatal
+        let dir = std::env::temp_dir().join(format!("tzparse-progress-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("Europe")).unwrap();
+        std::fs::copy("/usr/share/zoneinfo/Europe/Paris", dir.join("Europe/Paris")).unwrap();
+        std::fs::write(dir.join("Europe/Broken"), b"TZif2 truncated").unwrap();
+        let mut errors = Vec::new();
+        let db = TzDatabase::open(&dir);
+        let all = db.zoneinfo_all_with_progress(now, |p| errors.push((p.zone.to_string(), p.errors)));
+        let all = all.unwrap();
+        assert_eq!(all.keys().collect::<Vec<_>>(), vec!["Europe/Broken", "Europe/Paris"]);
+        assert!(all["Europe/Broken"].is_err() && all["Europe/Paris"].is_ok());
+        assert_eq!(errors, vec![("Europe/Broken".to_string(), 1), ("Europe/Paris".to_string(), 1)]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,125 @@
+use crate::ZONEINFO_DIR;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Usual locations of zoneinfo trees, besides the system one
+const CANDIDATES: &[&str] = &[
+    "/usr/share/zoneinfo",
+    "/usr/lib/zoneinfo",
+    "/usr/share/lib/zoneinfo",
+    "/usr/local/share/zoneinfo",
+    "/usr/pkg/share/zoneinfo",
+    "/etc/zoneinfo",
+];
+
+/// A zoneinfo tree installed on the host.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Installation {
+    /// Root directory of the tree
+    pub path: PathBuf,
+    /// tzdata release (ie. "2024a"), if the tree records it
+    pub version: Option<String>,
+}
+
+/// Returns the zoneinfo trees found on the host: usual system locations, snaps and Nix store.
+/// Trees reached through several paths (symlinks) are listed once.
+pub fn find_installations() -> Vec<Installation> {
+    let mut dirs = vec![PathBuf::from(ZONEINFO_DIR)];
+    dirs.extend(CANDIDATES.iter().map(PathBuf::from));
+    // Snaps ship their own copy, in /snap/<name>/current/usr/share/zoneinfo
+    for snap in subdirs("/snap") {
+        dirs.push(snap.join("current/usr/share/zoneinfo"));
+    }
+    // Nix store paths of tzdata derivations are named <hash>-tzdata-<version>
+    for entry in subdirs("/nix/store") {
+        if entry.to_string_lossy().contains("-tzdata-") {
+            dirs.push(entry.join("share/zoneinfo"));
+        }
+    }
+
+    let mut seen = Vec::new();
+    let mut installations = Vec::new();
+    for dir in dirs {
+        let canonical = match dir.canonicalize() {
+            Ok(canonical) if canonical.is_dir() => canonical,
+            _ => continue,
+        };
+        if seen.contains(&canonical) {
+            continue;
+        }
+        seen.push(canonical);
+        let version = read_version(&dir);
+        debug!("zoneinfo tree {} version {:?}", dir.display(), version);
+        installations.push(Installation { version, path: dir });
+    }
+    installations
+}
+
+/// Returns the installed zoneinfo tree with the most recent tzdata release.
+/// Trees without a recorded version are only picked if no other tree is present.
+pub fn newest_installation() -> Option<Installation> {
+    let mut newest: Option<Installation> = None;
+    for installation in find_installations() {
+        let newer = match &newest {
+            None => true,
+            Some(n) => version_key(&installation.version) > version_key(&n.version),
+        };
+        if newer {
+            newest = Some(installation);
+        }
+    }
+    newest
+}
+
+/// Reads the tzdata release of a tree, from its +VERSION file or the header of tzdata.zi
+fn read_version(dir: &Path) -> Option<String> {
+    if let Ok(version) = fs::read_to_string(dir.join("+VERSION")) {
+        let version = version.trim();
+        if !version.is_empty() {
+            return Some(version.to_string());
+        }
+    }
+    let zi = fs::read_to_string(dir.join("tzdata.zi")).ok()?;
+    zi.lines()
+        .next()?
+        .strip_prefix("# version ")
+        .map(|v| v.trim().to_string())
+}
+
+/// Sort key of a release: "2024a" < "2024b" < "2024z" < "2024za" < "2025a"
+fn version_key(version: &Option<String>) -> Option<(u32, usize, String)> {
+    let version = version.as_ref()?;
+    let digits = version.find(|c: char| !c.is_ascii_digit()).unwrap_or(version.len());
+    let year = version[..digits].parse().ok()?;
+    let letters = &version[digits..];
+    Some((year, letters.len(), letters.to_string()))
+}
+
+fn subdirs(dir: &str) -> Vec<PathBuf> {
+    match fs::read_dir(dir) {
+        Ok(entries) => entries.filter_map(|e| e.ok()).map(|e| e.path()).collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn versions() {
+        let v = |s: &str| version_key(&Some(s.to_string()));
+        assert!(v("2024a") < v("2024b"));
+        assert!(v("2024z") < v("2024za"));
+        assert!(v("2024za") < v("2025a"));
+        assert!(version_key(&None) < v("1996l"));
+    }
+
+    #[test]
+    fn installations() {
+        let found = find_installations();
+        assert!(found.iter().any(|i| i.path == Path::new("/usr/share/zoneinfo")));
+        assert!(newest_installation().is_some());
+    }
+}
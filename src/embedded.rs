@@ -0,0 +1,105 @@
+//! tzdata compiled into the library, with the "embedded" feature. See build.rs to pin the release.
+
+use crate::{Error, TzDatabase};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+include!(concat!(env!("OUT_DIR"), "/embedded.rs"));
+
+/// Per-user cache directory: XDG_CACHE_HOME, LOCALAPPDATA, ~/.cache, or the temporary directory as a last resort
+fn cache_dir() -> PathBuf {
+    env::var_os("XDG_CACHE_HOME")
+        .or_else(|| env::var_os("LOCALAPPDATA"))
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .unwrap_or_else(env::temp_dir)
+        .join("tzparse")
+}
+
+/// Creates a directory only its owner can access, or checks an existing one is a directory (not a symlink)
+/// that nobody else can write to, so that another user can not plant tzfiles in it.
+fn private_dir(dir: &Path) -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+        fs::DirBuilder::new().recursive(true).mode(0o700).create(dir)?;
+        for dir in &[dir.parent().unwrap_or(dir), dir] {
+            let metadata = fs::symlink_metadata(dir)?;
+            if !metadata.is_dir() || metadata.permissions().mode() & 0o022 != 0 {
+                return Err(io::Error::new(io::ErrorKind::PermissionDenied, "directory not private"));
+            }
+        }
+        Ok(())
+    }
+    #[cfg(not(unix))]
+    {
+        fs::create_dir_all(dir)?;
+        if fs::symlink_metadata(dir)?.is_dir() {
+            Ok(())
+        } else {
+            Err(io::Error::new(io::ErrorKind::PermissionDenied, "directory not private"))
+        }
+    }
+}
+
+impl TzDatabase {
+    /// Opens the tzdata embedded in the library (release EMBEDDED_RELEASE), independent of the host's.
+    /// Its tzfiles are written once in a private directory of the user's cache, and checked against the embedded
+    /// ones on each call: a tampered or truncated tzfile is written again.
+    pub fn embedded() -> Result<TzDatabase, Error> {
+        const OPERATION: &str = "extracting embedded tzdata";
+        let root = cache_dir().join(format!(
+            "{}-{}-{}",
+            env!("CARGO_PKG_VERSION"),
+            EMBEDDED_RELEASE,
+            EMBEDDED_ZONES.len()
+        ));
+        private_dir(&root).map_err(|e| Error::io(e, OPERATION))?;
+        let files = EMBEDDED_ZONES.iter().copied().chain(Some(("+VERSION", EMBEDDED_RELEASE.as_bytes())));
+        for (name, data) in files {
+            let path = root.join(name);
+            if matches!(fs::read(&path), Ok(existing) if existing == data) {
+                continue;
+            }
+            let write = || -> io::Result<()> {
+                if let Some(parent) = path.parent() {
+                    private_dir(parent)?;
+                }
+                // Written aside then renamed, so that concurrent processes never read a partial file
+                let partial = path.with_extension(format!("partial-{}", std::process::id()));
+                fs::write(&partial, data)?;
+                fs::rename(&partial, &path)
+            };
+            write().map_err(|e| Error::io(e, OPERATION).with_zone(name))?;
+        }
+        Ok(TzDatabase::open(root))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::prelude::*;
+    #[test]
+    fn embedded() {
+        let db = TzDatabase::embedded().unwrap();
+        let zones = db.zones().unwrap();
+        assert_eq!(zones.len(), EMBEDDED_ZONES.len());
+        let paris = db.zone("Europe/Paris").unwrap();
+        assert_eq!(paris.offset_at(Utc.ymd(2019, 7, 1).and_hms(0, 0, 0)).unwrap().abbreviation, "CEST");
+        assert_eq!(fs::read_to_string(db.root().join("+VERSION")).unwrap(), EMBEDDED_RELEASE);
+        // Opening again reuses the extracted files, once checked
+        let paris = db.root().join("Europe/Paris");
+        let original = fs::read(&paris).unwrap();
+        fs::write(&paris, vec![0; original.len()]).unwrap();
+        assert_eq!(TzDatabase::embedded().unwrap().root(), db.root());
+        assert_eq!(fs::read(&paris).unwrap(), original);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(fs::metadata(db.root()).unwrap().permissions().mode() & 0o077, 0);
+        }
+    }
+}
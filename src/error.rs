@@ -0,0 +1,231 @@
+use libtzfile::TzError;
+use std::{error, fmt, io};
+
+/// Kind of error. New kinds may be added: matches need a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Invalid zone name or path, or unreadable tzfile
+    InvalidTimezone,
+    /// Malformed tzfile
+    InvalidTzfile,
+    /// Tzfile exceeding the configured limits
+    LimitExceeded,
+    /// Malformed POSIX TZ string
+    InvalidPosixString,
+    /// No data matched the request
+    NoData,
+    /// Date or offset out of the supported range
+    OutOfRange,
+    /// Local time occurring twice, refused by the disambiguation policy
+    AmbiguousLocalTime,
+    /// Local time skipped by a timechange, refused by the disambiguation policy
+    NonexistentLocalTime,
+    /// Failed request to a remote service
+    Network,
+    /// Downloaded data not matching its published checksum
+    ChecksumMismatch,
+}
+
+impl ErrorKind {
+    /// Stable numeric code of the kind, for FFI consumers. Codes are never reused.
+    pub fn code(self) -> u32 {
+        match self {
+            ErrorKind::InvalidTimezone => 1,
+            ErrorKind::InvalidTzfile => 2,
+            ErrorKind::LimitExceeded => 3,
+            ErrorKind::InvalidPosixString => 4,
+            ErrorKind::NoData => 5,
+            ErrorKind::OutOfRange => 6,
+            ErrorKind::AmbiguousLocalTime => 7,
+            ErrorKind::NonexistentLocalTime => 8,
+            ErrorKind::Network => 9,
+            ErrorKind::ChecksumMismatch => 10,
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ErrorKind::InvalidTimezone => "invalid timezone",
+            ErrorKind::InvalidTzfile => "malformed tzfile",
+            ErrorKind::LimitExceeded => "tzfile exceeds limits",
+            ErrorKind::InvalidPosixString => "malformed POSIX TZ string",
+            ErrorKind::NoData => "no data",
+            ErrorKind::OutOfRange => "out of range",
+            ErrorKind::AmbiguousLocalTime => "ambiguous local time",
+            ErrorKind::NonexistentLocalTime => "nonexistent local time",
+            ErrorKind::Network => "network failure",
+            ErrorKind::ChecksumMismatch => "checksum mismatch",
+        })
+    }
+}
+
+/// Underlying cause of an error
+#[derive(Debug)]
+enum Cause {
+    Tzfile(TzError),
+    Io(io::Error),
+    #[allow(dead_code)]
+    Other(Box<dyn error::Error + Send + Sync>),
+}
+
+/// Error returned by this crate, with the offending zone, the failed operation and the underlying cause.
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    operation: &'static str,
+    zone: Option<String>,
+    offset: Option<u64>,
+    cause: Option<Cause>,
+}
+
+impl Error {
+    pub(crate) fn new(kind: ErrorKind, operation: &'static str) -> Error {
+        Error {
+            kind,
+            operation,
+            zone: None,
+            offset: None,
+            cause: None,
+        }
+    }
+
+    /// Error reported by libtzfile
+    pub(crate) fn tzfile(err: TzError, operation: &'static str) -> Error {
+        let kind = match err {
+            TzError::InvalidTimezone => ErrorKind::InvalidTimezone,
+            TzError::NoData => ErrorKind::NoData,
+            _ => ErrorKind::InvalidTzfile,
+        };
+        Error {
+            cause: Some(Cause::Tzfile(err)),
+            ..Error::new(kind, operation)
+        }
+    }
+
+    /// I/O error: a truncated file is malformed, other failures mean the file can't be read
+    pub(crate) fn io(err: io::Error, operation: &'static str) -> Error {
+        let kind = match err.kind() {
+            io::ErrorKind::UnexpectedEof => ErrorKind::InvalidTzfile,
+            _ => ErrorKind::InvalidTimezone,
+        };
+        Error {
+            cause: Some(Cause::Io(err)),
+            ..Error::new(kind, operation)
+        }
+    }
+
+    /// Error of another library, ie. an HTTP client. Only optional features use it.
+    #[allow(dead_code)]
+    pub(crate) fn other<E: Into<Box<dyn error::Error + Send + Sync>>>(
+        kind: ErrorKind,
+        err: E,
+        operation: &'static str,
+    ) -> Error {
+        Error {
+            cause: Some(Cause::Other(err.into())),
+            ..Error::new(kind, operation)
+        }
+    }
+
+    pub(crate) fn with_zone(mut self, zone: &str) -> Error {
+        self.zone = Some(zone.to_string());
+        self
+    }
+
+    pub(crate) fn with_offset(mut self, offset: u64) -> Error {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Kind of error
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Stable numeric code, see ErrorKind::code
+    pub fn code(&self) -> u32 {
+        self.kind.code()
+    }
+
+    /// Operation which failed, ie. "parsing tzfile"
+    pub fn operation(&self) -> &'static str {
+        self.operation
+    }
+
+    /// Zone name, path or TZ string involved
+    pub fn zone(&self) -> Option<&str> {
+        self.zone.as_deref()
+    }
+
+    /// Byte offset of a parse error, in the tzfile or TZ string
+    pub fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    /// Error reported by libtzfile, if it is the cause
+    pub fn tzfile_error(&self) -> Option<&TzError> {
+        match &self.cause {
+            Some(Cause::Tzfile(err)) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// I/O error, if it is the cause
+    pub fn io_error(&self) -> Option<&io::Error> {
+        match &self.cause {
+            Some(Cause::Io(err)) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// ie. "malformed tzfile while parsing tzfile, zone /usr/share/zoneinfo/zone.tab: InvalidMagic"
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} while {}", self.kind, self.operation)?;
+        if let Some(zone) = &self.zone {
+            write!(f, ", zone {}", zone)?;
+        }
+        if let Some(offset) = self.offset {
+            write!(f, ", at offset {}", offset)?;
+        }
+        match &self.cause {
+            Some(Cause::Tzfile(err)) => write!(f, ": {:?}", err),
+            Some(Cause::Io(err)) => write!(f, ": {}", err),
+            Some(Cause::Other(err)) => write!(f, ": {}", err),
+            None => Ok(()),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.cause {
+            Some(Cause::Io(err)) => Some(err),
+            Some(Cause::Other(err)) => Some(err.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn display_and_codes() {
+        let err = Error::new(ErrorKind::InvalidPosixString, "parsing POSIX TZ string")
+            .with_zone("CET-1CEST,X")
+            .with_offset(10);
+        assert_eq!(
+            err.to_string(),
+            "malformed POSIX TZ string while parsing POSIX TZ string, zone CET-1CEST,X, at offset 10"
+        );
+        assert_eq!(err.code(), 4);
+        let err = Error::io(io::Error::new(io::ErrorKind::NotFound, "gone"), "checking limits");
+        assert_eq!(err.to_string(), "invalid timezone while checking limits: gone");
+        assert!(error::Error::source(&err).is_some());
+    }
+}
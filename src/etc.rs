@@ -0,0 +1,76 @@
+/// Returns the offset to GMT in seconds of an Etc/GMT zone name, with or without the "Etc/" prefix.
+/// Beware: following POSIX, the sign of these names is inverted, so "Etc/GMT+5" is 5 hours *behind* GMT (-18000).
+pub fn etc_gmt_offset(name: &str) -> Option<isize> {
+    let name = name.strip_prefix("Etc/").unwrap_or(name);
+    let hours = match name.strip_prefix("GMT")? {
+        "" | "0" | "+0" | "-0" => return Some(0),
+        hours => hours,
+    };
+    // Only "+" and "-" followed by digits, without leading zero
+    let (behind, digits) = match (hours.strip_prefix('+'), hours.strip_prefix('-')) {
+        (Some(digits), _) => (true, digits),
+        (_, Some(digits)) => (false, digits),
+        _ => return None,
+    };
+    if digits.starts_with('0') || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let hours: isize = digits.parse().ok()?;
+    match behind {
+        true if hours <= 12 => Some(-hours * 3600),
+        false if hours <= 14 => Some(hours * 3600),
+        _ => None,
+    }
+}
+
+/// Returns the Etc/GMT zone name of an offset to GMT in seconds, ie. "Etc/GMT+5" for -18000 (5 hours behind GMT),
+/// if there is one: whole hours from 12 hours behind to 14 hours ahead.
+pub fn etc_gmt_name(gmtoff: isize) -> Option<String> {
+    if gmtoff % 3600 != 0 {
+        return None;
+    }
+    match gmtoff / 3600 {
+        0 => Some(String::from("Etc/GMT")),
+        hours @ -12..=-1 => Some(format!("Etc/GMT+{}", -hours)),
+        hours @ 1..=14 => Some(format!("Etc/GMT-{}", hours)),
+        _ => None,
+    }
+}
+
+/// Logs a warning when a zone is an Etc/GMT zone with an offset, whose sign is often misread.
+pub(crate) fn warn_inverted_sign(name: &str) {
+    if let Some(gmtoff) = etc_gmt_offset(name).filter(|gmtoff| *gmtoff != 0) {
+        warn!(
+            "{} is {} hours {} GMT: Etc/GMT zones have POSIX inverted signs",
+            name,
+            gmtoff.abs() / 3600,
+            if gmtoff < 0 { "behind" } else { "ahead of" }
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Zone;
+    use chrono::prelude::*;
+    #[test]
+    fn etc_gmt() {
+        assert_eq!(etc_gmt_offset("Etc/GMT+5"), Some(-18000));
+        assert_eq!(etc_gmt_offset("GMT-14"), Some(14 * 3600));
+        assert_eq!(etc_gmt_offset("Etc/GMT0"), Some(0));
+        assert_eq!(etc_gmt_offset("Etc/GMT+13"), None);
+        assert_eq!(etc_gmt_offset("Etc/GMT+05"), None);
+        assert_eq!(etc_gmt_offset("Europe/Paris"), None);
+        assert_eq!(etc_gmt_offset("GMTé"), None);
+        assert_eq!(etc_gmt_name(-18000).unwrap(), "Etc/GMT+5");
+        assert_eq!(etc_gmt_name(0).unwrap(), "Etc/GMT");
+        assert_eq!(etc_gmt_name(19800), None);
+        for hours in -12..=14 {
+            let name = etc_gmt_name(hours * 3600).unwrap();
+            assert_eq!(etc_gmt_offset(&name), Some(hours * 3600));
+            let zone = Zone::from_tzfile(&format!("/usr/share/zoneinfo/{}", name)).unwrap();
+            assert_eq!(zone.offset_at(Utc.ymd(2019, 7, 1).and_hms(0, 0, 0)).unwrap().gmtoff, hours * 3600);
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Download and compilation of IANA tzdata releases, with the "fetch" feature.
+
+use crate::{Error, ErrorKind, TzDatabase};
+use std::{
+    fs,
+    io::Read,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Latest tzdata release published by IANA
+pub const TZDATA_URL: &str = "https://data.iana.org/time-zones/tzdata-latest.tar.gz";
+
+/// Source files of a tzdata release compiled into zones, in zic order
+const SOURCES: &[&str] = &[
+    "africa",
+    "antarctica",
+    "asia",
+    "australasia",
+    "europe",
+    "northamerica",
+    "southamerica",
+    "etcetera",
+    "backward",
+    "factory",
+];
+
+/// Largest archive accepted: releases are about 450 KB
+const MAX_ARCHIVE: u64 = 64 * 1024 * 1024;
+
+/// Downloads the latest tzdata release, compiles it into `dir` and opens it, see compile_tzdata.
+/// The archive is not verified: automated updates should rather use fetch_tzdata_verified.
+pub fn fetch_tzdata<P: AsRef<Path>>(dir: P) -> Result<TzDatabase, Error> {
+    compile_tzdata(&download(TZDATA_URL)?, dir)
+}
+
+/// Downloads a tzdata release and its published SHA-512 checksum file, checks the archive against it,
+/// then compiles it into `dir` and opens it. A mismatch is reported as ErrorKind::ChecksumMismatch.
+pub fn fetch_tzdata_verified<P: AsRef<Path>>(url: &str, checksum_url: &str, dir: P) -> Result<TzDatabase, Error> {
+    let archive = download(url)?;
+    let checksum = download(checksum_url)?;
+    verify_sha512(&archive, &String::from_utf8_lossy(&checksum)).map_err(|e| e.with_zone(url))?;
+    compile_tzdata(&archive, dir)
+}
+
+/// Checks data against a SHA-512 checksum: hexadecimal digest, or a line in sha512sum format ("<digest>  <file>").
+pub fn verify_sha512(data: &[u8], checksum: &str) -> Result<(), Error> {
+    use sha2::{Digest, Sha512};
+    const OPERATION: &str = "verifying checksum";
+    let expected = checksum
+        .split_whitespace()
+        .next()
+        .ok_or_else(|| Error::new(ErrorKind::NoData, OPERATION))?;
+    let digest: String = Sha512::digest(data).iter().map(|b| format!("{:02x}", b)).collect();
+    if digest.eq_ignore_ascii_case(expected) {
+        Ok(())
+    } else {
+        debug!("expected SHA-512 {}, got {}", expected, digest);
+        Err(Error::new(ErrorKind::ChecksumMismatch, OPERATION))
+    }
+}
+
+fn download(url: &str) -> Result<Vec<u8>, Error> {
+    const OPERATION: &str = "downloading tzdata";
+    debug!("downloading {}", url);
+    let response = ureq::get(url)
+        .call()
+        .map_err(|e| Error::other(ErrorKind::Network, e, OPERATION).with_zone(url))?;
+    let mut data = Vec::new();
+    response
+        .into_reader()
+        .take(MAX_ARCHIVE)
+        .read_to_end(&mut data)
+        .map_err(|e| Error::other(ErrorKind::Network, e, OPERATION).with_zone(url))?;
+    Ok(data)
+}
+
+/// Extracts a tzdata release archive (tzdataXXXXx.tar.gz) into `dir`/src, compiles it with the system zic
+/// into `dir`/zoneinfo, recording its version in +VERSION, and opens the resulting tree.
+pub fn compile_tzdata<P: AsRef<Path>>(archive: &[u8], dir: P) -> Result<TzDatabase, Error> {
+    const OPERATION: &str = "compiling tzdata";
+    let dir = dir.as_ref();
+    let (src, zoneinfo): (PathBuf, PathBuf) = (dir.join("src"), dir.join("zoneinfo"));
+    tar::Archive::new(flate2::read::GzDecoder::new(archive))
+        .unpack(&src)
+        .map_err(|e| Error::io(e, OPERATION).with_zone(&src.to_string_lossy()))?;
+    let sources: Vec<PathBuf> = SOURCES.iter().map(|s| src.join(s)).filter(|s| s.is_file()).collect();
+    if sources.is_empty() {
+        return Err(Error::new(ErrorKind::NoData, OPERATION).with_zone(&src.to_string_lossy()));
+    }
+    let output = Command::new("zic")
+        .arg("-d")
+        .arg(&zoneinfo)
+        .args(&sources)
+        .output()
+        .map_err(|e| Error::io(e, OPERATION).with_zone("zic"))?;
+    if !output.status.success() {
+        debug!("zic: {}", String::from_utf8_lossy(&output.stderr));
+        return Err(Error::new(ErrorKind::InvalidTzfile, OPERATION).with_zone(&src.to_string_lossy()));
+    }
+    if let Ok(version) = fs::read_to_string(src.join("version")) {
+        fs::write(zoneinfo.join("+VERSION"), version.trim())
+            .map_err(|e| Error::io(e, OPERATION).with_zone(&zoneinfo.to_string_lossy()))?;
+    }
+    Ok(TzDatabase::open(zoneinfo))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::prelude::*;
+
+    /// A release archive with a single zone
+    fn archive() -> Vec<u8> {
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default()));
+        let mut add = |name: &str, data: &[u8]| {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, name, data).unwrap();
+        };
+        add("version", b"2099z\n");
+        add("europe", b"Zone\tTest/Fixed\t1:00\t-\tFXT\n");
+        builder.into_inner().unwrap().finish().unwrap()
+    }
+
+    #[test]
+    fn compile() {
+        let dir = std::env::temp_dir().join(format!("tzparse-fetch-{}", std::process::id()));
+        let db = compile_tzdata(&archive(), &dir).unwrap();
+        assert_eq!(db.zones().unwrap(), vec!["Test/Fixed"]);
+        let zone = db.zone("Test/Fixed").unwrap();
+        assert_eq!(zone.offset_at(Utc.ymd(2020, 1, 1).and_hms(0, 0, 0)).unwrap().abbreviation, "FXT");
+        assert_eq!(fs::read_to_string(dir.join("zoneinfo/+VERSION")).unwrap(), "2099z");
+        assert!(compile_tzdata(b"not an archive", &dir).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn checksum() {
+        // sha512sum of "abc"
+        let abc = "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+                   2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f";
+        let abc: String = abc.split_whitespace().collect();
+        assert!(verify_sha512(b"abc", &abc).is_ok());
+        assert!(verify_sha512(b"abc", &format!("{}  tzdata2099z.tar.gz\n", abc.to_uppercase())).is_ok());
+        assert_eq!(verify_sha512(b"abd", &abc).unwrap_err().kind(), ErrorKind::ChecksumMismatch);
+        assert_eq!(verify_sha512(b"abc", "").unwrap_err().kind(), ErrorKind::NoData);
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{zoneinfo, Error, Timechange, Tzinfo};
+use chrono::prelude::*;
+
+/// Parses a fixed offset identifier: "UTC+5", "UTC-03:30", "+0845" or "-03", into an offset to GMT in seconds.
+/// Offsets are limited to 18 hours. "GMT+5" is not accepted, as Etc/GMT+5 is 5 hours *behind* GMT.
+pub fn parse_fixed_offset(id: &str) -> Option<isize> {
+    let offset = id.strip_prefix("UTC").unwrap_or(id);
+    let (sign, offset) = match (offset.strip_prefix('+'), offset.strip_prefix('-')) {
+        (Some(offset), _) => (1, offset),
+        (_, Some(offset)) => (-1, offset),
+        _ => return None,
+    };
+    if !offset.chars().all(|c| c.is_ascii_digit() || c == ':') {
+        return None;
+    }
+    let (hours, minutes) = match (offset.find(':'), offset.len()) {
+        (Some(2), 5) | (Some(1), 4) => offset.split_at(offset.len() - 3),
+        (None, 1) | (None, 2) => (offset, ":00"),
+        (None, 4) => {
+            let (hours, minutes) = offset.split_at(2);
+            return fixed(sign, hours, minutes);
+        }
+        _ => return None,
+    };
+    fixed(sign, hours, &minutes[1..])
+}
+
+fn fixed(sign: isize, hours: &str, minutes: &str) -> Option<isize> {
+    let (hours, minutes): (isize, isize) = (hours.parse().ok()?, minutes.parse().ok()?);
+    if hours > 18 || minutes > 59 || (hours == 18 && minutes > 0) {
+        return None;
+    }
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Abbreviation of a fixed offset, numeric as in tzdata: "+05", "-0330"
+pub(crate) fn fixed_abbreviation(gmtoff: isize) -> String {
+    let sign = if gmtoff < 0 { '-' } else { '+' };
+    let (hours, minutes) = (gmtoff.abs() / 3600, gmtoff.abs() % 3600 / 60);
+    match minutes {
+        0 => format!("{}{:02}", sign, hours),
+        _ => format!("{}{:02}{:02}", sign, hours, minutes),
+    }
+}
+
+/// Tzinfo of a fixed offset identifier, with a normalized name ("UTC+05:30") and no DST.
+pub(crate) fn fixed_zoneinfo(gmtoff: isize, now: DateTime<Utc>) -> Result<Tzinfo, Error> {
+    let abbreviation = fixed_abbreviation(gmtoff);
+    let (hours, minutes) = (gmtoff.abs() / 3600, gmtoff.abs() % 3600 / 60);
+    let name = format!("UTC{}{:02}:{:02}", &abbreviation[..1], hours, minutes);
+    zoneinfo(name, vec![Timechange::new(now, gmtoff, false, &abbreviation)], now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_zoneinfo_at;
+    #[test]
+    fn fixed_offsets() {
+        assert_eq!(parse_fixed_offset("UTC+5"), Some(18000));
+        assert_eq!(parse_fixed_offset("UTC-03:30"), Some(-12600));
+        assert_eq!(parse_fixed_offset("+0845"), Some(31500));
+        assert_eq!(parse_fixed_offset("-3:30"), Some(-12600));
+        assert_eq!(parse_fixed_offset("UTC+18:30"), None);
+        assert_eq!(parse_fixed_offset("GMT+5"), None);
+        assert_eq!(parse_fixed_offset("UTC"), None);
+        assert_eq!(parse_fixed_offset("+084"), None);
+        let now = Utc.ymd(2019, 7, 1).and_hms(12, 0, 0);
+        let tzinfo = get_zoneinfo_at("UTC+05:30", now).unwrap();
+        assert_eq!(tzinfo.timezone, "UTC+05:30");
+        assert_eq!(tzinfo.abbreviation, "+0530");
+        assert_eq!(tzinfo.raw_offset, 19800);
+        assert!(!tzinfo.dst_period);
+        assert_eq!(get_zoneinfo_at("-03", now).unwrap().timezone, "UTC-03:00");
+        assert_eq!(get_zoneinfo_at("UTC+5", now).unwrap().abbreviation, "+05");
+    }
+}
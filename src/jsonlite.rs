@@ -0,0 +1,98 @@
+//! Tzinfo JSON document without serde, with the "json-lite" feature.
+
+use crate::Tzinfo;
+use chrono::prelude::*;
+use std::fmt::{self, Write};
+
+/// Appends a JSON string
+fn string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Appends a value formatted as chrono's serde support does, as a JSON string
+fn debug<T: fmt::Debug>(out: &mut String, value: &T) {
+    string(out, &format!("{:?}", value));
+}
+
+fn optional_time(out: &mut String, time: &Option<DateTime<Utc>>) {
+    match time {
+        Some(time) => debug(out, time),
+        None => out.push_str("null"),
+    }
+}
+
+impl Tzinfo {
+    /// Transforms the Tzinfo struct to a JSON string, the same as to_json does, without serde.
+    pub fn to_json_lite(&self) -> String {
+        let mut out = String::with_capacity(512);
+        out.push_str("{\"timezone\":");
+        string(&mut out, &self.timezone);
+        out.push_str(",\"utc_datetime\":");
+        debug(&mut out, &self.utc_datetime);
+        out.push_str(",\"datetime\":");
+        debug(&mut out, &self.datetime);
+        out.push_str(",\"dst_from\":");
+        optional_time(&mut out, &self.dst_from);
+        out.push_str(",\"dst_until\":");
+        optional_time(&mut out, &self.dst_until);
+        let _ = write!(
+            out,
+            ",\"dst_period\":{},\"raw_offset\":{},\"dst_offset\":{},\"utc_offset\":",
+            self.dst_period, self.raw_offset, self.dst_offset
+        );
+        debug(&mut out, &self.utc_offset);
+        out.push_str(",\"abbreviation\":");
+        string(&mut out, &self.abbreviation);
+        let _ = write!(out, ",\"week_number\":{},\"transition_count\":", self.week_number);
+        match self.transition_count {
+            Some(count) => {
+                let _ = write!(out, "{}", count);
+            }
+            None => out.push_str("null"),
+        }
+        out.push_str(",\"first_transition\":");
+        optional_time(&mut out, &self.first_transition);
+        out.push_str(",\"last_transition\":");
+        optional_time(&mut out, &self.last_transition);
+        out.push('}');
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_zoneinfo_at;
+    #[test]
+    fn json_lite() {
+        let now = Utc.ymd(2019, 7, 14).and_hms_micro(12, 0, 0, 792_898);
+        let paris = get_zoneinfo_at("/usr/share/zoneinfo/Europe/Paris", now).unwrap();
+        let json = paris.to_json_lite();
+        assert!(json.starts_with(r#"{"timezone":"Europe/Paris","utc_datetime":"2019-07-14T12:00:00.792898Z","#));
+        assert!(json.contains(r#""datetime":"2019-07-14T14:00:00.792898+02:00","#));
+        assert!(json.contains(r#""utc_offset":"+02:00","abbreviation":"CEST""#));
+        let mut s = String::new();
+        string(&mut s, "a\"b\\\u{1}");
+        assert_eq!(s, r#""a\"b\\\u0001""#);
+        #[cfg(feature = "json")]
+        {
+            assert_eq!(json, paris.to_json().unwrap());
+            let tokyo = get_zoneinfo_at("/usr/share/zoneinfo/Asia/Tokyo", now).unwrap();
+            assert_eq!(tokyo.to_json_lite(), tokyo.to_json().unwrap());
+        }
+    }
+}
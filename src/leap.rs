@@ -0,0 +1,69 @@
+use crate::Zone;
+use chrono::prelude::*;
+
+/// A leap second recorded in a tzfile of the right/ tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LeapSecond {
+    /// UTC instant following the leap second
+    pub time: DateTime<Utc>,
+    /// Total number of leap seconds inserted since 1972, from then on
+    pub correction: i32,
+}
+
+/// Converts the leap second records of a tzfile, whose occurrences count the previous leap seconds.
+pub(crate) fn leap_seconds(records: &[(i64, i32)]) -> Vec<LeapSecond> {
+    let mut previous = 0;
+    records
+        .iter()
+        .filter_map(|(occurrence, correction)| {
+            let time = Utc.timestamp_opt(occurrence - i64::from(previous), 0).single();
+            previous = *correction;
+            Some(LeapSecond { time: time?, correction: *correction })
+        })
+        .collect()
+}
+
+/// Converts a timestamp counting leap seconds (right/ tree) to a POSIX one.
+pub(crate) fn to_posix(time: i64, records: &[(i64, i32)]) -> i64 {
+    match records.iter().rev().find(|(occurrence, _)| *occurrence <= time) {
+        Some((_, correction)) => time - i64::from(*correction),
+        None => time,
+    }
+}
+
+impl Zone {
+    /// Tells whether the zone comes from the right/ tree, whose clock counts leap seconds (TAI-based, TAI - 10s)
+    /// rather than ignoring them (UTC-based). Its timechanges are converted to UTC when loaded, like for other zones.
+    pub fn is_tai_based(&self) -> bool {
+        !self.leap_seconds().is_empty()
+    }
+
+    /// Number of leap seconds inserted up to an instant, 0 for UTC-based zones. TAI is ahead of UTC by 10 more seconds.
+    pub fn leap_correction(&self, time: DateTime<Utc>) -> i32 {
+        match self.leap_seconds().iter().rev().find(|leap| leap.time <= time) {
+            Some(leap) => leap.correction,
+            None => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_timechanges_for, YearSpec};
+    #[test]
+    fn right() {
+        let right = Zone::from_tzfile("/usr/share/zoneinfo/right/Europe/Paris").unwrap();
+        assert!(right.is_tai_based());
+        assert_eq!(right.leap_seconds()[0].time, Utc.ymd(1972, 7, 1).and_hms(0, 0, 0));
+        assert_eq!(right.leap_seconds()[1].time, Utc.ymd(1973, 1, 1).and_hms(0, 0, 0));
+        assert_eq!(right.leap_correction(Utc.ymd(2019, 1, 1).and_hms(0, 0, 0)), 27);
+        // Same timechanges as the UTC-based zone
+        let posix = Zone::from_tzfile("/usr/share/zoneinfo/Europe/Paris").unwrap();
+        assert!(!posix.is_tai_based());
+        assert_eq!(posix.leap_correction(Utc.ymd(2019, 1, 1).and_hms(0, 0, 0)), 0);
+        let year = |zone| get_timechanges_for(zone, YearSpec::Year(2019)).unwrap();
+        assert_eq!(year("/usr/share/zoneinfo/right/Europe/Paris"), year("/usr/share/zoneinfo/Europe/Paris"));
+        assert_eq!(right.offset_at(Utc.ymd(2019, 7, 1).and_hms(0, 0, 0)).unwrap().gmtoff, 7200);
+    }
+}
@@ -8,7 +8,7 @@
 //! `get_zoneinfo` parses the tzfile and returns a Tzinfo struct which provides useful and human-readable data about the timezone
 //! and can be converted to a json string with an optional feature.
 //!
-//! `get_timechanges` obtains time changes for specified year, or all time changes recorded in the TZfile if no year is specified.
+//! `get_timechanges_for` obtains time changes for specified years, or all time changes recorded in the TZfile with `YearSpec::All`.
 //!
 //! Example with get_zoneinfo:
 //! ```text
@@ -26,17 +26,158 @@
 //! ```text
 //! {"timezone":"Europe/Paris","utc_datetime":"2020-01-22T14:12:36.792898Z","datetime":"2020-01-22T15:12:36.792898+01:00",
 //! "dst_from":"2020-03-29T01:00:00Z","dst_until":"2020-10-25T01:00:00Z","dst_period":false,"raw_offset":3600,
-//! "dst_offset":7200,"utc_offset":"+01:00","abbreviation":"CET","week_number":4}
+//! "dst_offset":7200,"utc_offset":"+01:00","abbreviation":"CET","week_number":4,"transition_count":184,
+//! "first_transition":"1891-03-15T23:50:39Z","last_transition":"2037-10-25T01:00:00Z"}
 //! ```
-//! The get_timechanges function for Europe/Paris in 2019 returns:
+//! The get_timechanges_for function for Europe/Paris and YearSpec::Year(2019) returns:
 //! ```text
 //! [Timechange { time: 2019-03-31T01:00:00Z, gmtoff: 7200, isdst: true, abbreviation: "CEST" },
 //! Timechange { time: 2019-10-27T01:00:00Z, gmtoff: 3600, isdst: false, abbreviation: "CET" }]
 //! ```
 //!
 
+// Diagnostics through the log crate, compiled out without the "log" feature
+#[cfg(feature = "log")]
+macro_rules! debug {
+    ($($arg:tt)*) => { log::debug!($($arg)*) };
+}
+#[cfg(not(feature = "log"))]
+macro_rules! debug {
+    ($($arg:tt)*) => {{ let _ = format_args!($($arg)*); }};
+}
+#[cfg(feature = "log")]
+macro_rules! warn {
+    ($($arg:tt)*) => { log::warn!($($arg)*) };
+}
+#[cfg(not(feature = "log"))]
+macro_rules! warn {
+    ($($arg:tt)*) => {{ let _ = format_args!($($arg)*); }};
+}
+#[cfg(feature = "log")]
+macro_rules! trace {
+    ($($arg:tt)*) => { log::trace!($($arg)*) };
+}
+#[cfg(not(feature = "log"))]
+macro_rules! trace {
+    ($($arg:tt)*) => {{ let _ = format_args!($($arg)*); }};
+}
+
+mod abbreviations;
+mod analysis;
+#[cfg(feature = "tokio")]
+mod asyncio;
+mod backzone;
+mod bcp47;
+mod clock;
+mod database;
+mod discover;
+#[cfg(feature = "embedded")]
+mod embedded;
+mod error;
+mod etc;
+#[cfg(feature = "fetch")]
+mod fetch;
+mod fixed;
+#[cfg(feature = "json-lite")]
+mod jsonlite;
+mod leap;
+mod legacy;
+mod limits;
+mod local;
+mod localzone;
+mod memory;
+#[cfg(feature = "cldr")]
+mod metazone;
+mod military;
+#[cfg(feature = "cldr")]
+mod names;
+#[cfg(feature = "json")]
+mod ndjson;
+mod policy;
+mod posix;
+mod provider;
+mod query;
+mod raw;
+mod recurrence;
+mod resolve;
+mod table;
+mod timeline;
+#[cfg(feature = "tzdist")]
+mod tzdist;
+mod tzif;
+mod validate;
+mod worldclock;
+mod zone;
+mod zoneid;
+
 use chrono::prelude::*;
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    convert::TryFrom,
+    ops::{Range, RangeInclusive},
+    path::Path,
+    str::FromStr,
+};
+/// Error reported by libtzfile, available as the cause of an Error
 pub use libtzfile::TzError;
+/// Tzfile as parsed by libtzfile, available from RawTzfile
+pub use libtzfile::{Ttinfo, Tz};
+pub use abbreviations::{abbreviation_meanings, ambiguous_abbreviations, AbbreviationMeaning};
+pub use analysis::{
+    get_abbreviations, get_anomalies, get_coverage, get_offsets, get_stats, offset_differences, Anomaly, AnomalyKind,
+    Coverage, Period, Stats, TransitionKind, ZoneOffset,
+};
+#[cfg(feature = "tokio")]
+pub use asyncio::{get_timechanges_for_async, get_zoneinfo_async};
+pub use backzone::{Backzone, SourcedTimechange};
+pub use bcp47::{bcp47_to_iana, iana_to_bcp47};
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use database::{Progress, TzDatabase};
+pub use discover::{find_installations, newest_installation, Installation};
+#[cfg(feature = "embedded")]
+pub use embedded::EMBEDDED_RELEASE;
+pub use error::{Error, ErrorKind};
+pub use etc::{etc_gmt_name, etc_gmt_offset};
+#[cfg(feature = "fetch")]
+pub use fetch::{compile_tzdata, fetch_tzdata, fetch_tzdata_verified, verify_sha512, TZDATA_URL};
+pub use fixed::parse_fixed_offset;
+pub use leap::LeapSecond;
+pub use legacy::canonical_zone;
+pub use limits::Limits;
+pub use local::{
+    convert_between, day_bounds_utc, elapsed, hours_in_day, is_ambiguous, is_nonexistent, month_bounds_utc, wall_duration,
+    Disambiguation, Discontinuity, DiscontinuityKind, LocalHour, LocalHours, LocalResolution, Moment,
+};
+pub use localzone::{get_local_zoneinfo, get_local_zoneinfo_at, get_local_zoneinfo_with_clock, local_zone, LocalZone};
+pub use memory::MemoryUsage;
+#[cfg(feature = "cldr")]
+pub use metazone::metazone;
+pub use military::{military_letter, military_offset};
+#[cfg(feature = "cldr")]
+pub use names::{display_names, exemplar_city, format_localized, DisplayNames, NAME_LOCALES};
+#[cfg(feature = "json")]
+pub use ndjson::NdjsonWriter;
+pub use policy::{Provenance, RangePolicy};
+pub use posix::{PosixDst, PosixRule, PosixTz, RuleDate};
+pub use provider::{TransitionProvider, TzfileProvider};
+pub use query::{Order, ZoneQuery};
+pub use raw::{get_raw_tzfile, RawTzfile};
+pub use recurrence::Recurrence;
+pub use resolve::{resolve_zone, resolve_zone_in, Resolver, ZONEINFO_DIR};
+pub use table::{dst_calendar_html, dst_calendar_markdown, timechanges_html, timechanges_markdown};
+pub use timeline::{
+    daily_offsets, dst_bounds, dst_bounds_with_provenance, dst_calendar, dst_matrix, merge_transitions, observances,
+    offset_periods, project_transitions, project_transitions_at, with_durations, DayOffset, DstPeriod, DstYear,
+    Observance, ObservanceKind, OffsetPeriod, ProjectedTimechange, SpacedTimechange, ZoneTimechange,
+};
+#[cfg(feature = "tzdist")]
+pub use tzdist::{TzdistClient, ZoneList};
+pub use tzif::get_tzif_version;
+pub use validate::{validate, validate_with_limits, Violation};
+pub use worldclock::{RelativeDay, WorldClock, ZoneTime};
+pub use zone::{Zone, ZoneBuilder};
+pub use zoneid::ZoneId;
 #[cfg(feature = "json")]
 use serde::Serialize;
 
@@ -57,7 +198,8 @@ mod offset_serializer {
 
 /// Convenient and human-readable informations about a timezone.
 #[cfg(feature = "json")]
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[non_exhaustive]
 pub struct Tzinfo {
     /// Timezone name
     pub timezone: String,
@@ -82,10 +224,17 @@ pub struct Tzinfo {
     pub abbreviation: String,
     /// Week number
     pub week_number: i32,
+    /// Number of transitions recorded in the tzfile
+    pub transition_count: Option<usize>,
+    /// First transition recorded in the tzfile
+    pub first_transition: Option<DateTime<Utc>>,
+    /// Last transition recorded in the tzfile
+    pub last_transition: Option<DateTime<Utc>>,
 }
 
 #[cfg(not(feature = "json"))]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub struct Tzinfo {
     /// Timezone name
     pub timezone: String,
@@ -109,10 +258,20 @@ pub struct Tzinfo {
     pub abbreviation: String,
     /// Week number
     pub week_number: i32,
+    /// Number of transitions recorded in the tzfile
+    pub transition_count: Option<usize>,
+    /// First transition recorded in the tzfile
+    pub first_transition: Option<DateTime<Utc>>,
+    /// Last transition recorded in the tzfile
+    pub last_transition: Option<DateTime<Utc>>,
 }
 
 /// The Timechange struct contains one timechange from the parsed TZfile.
-#[derive(Debug, PartialEq)]
+/// Fields may be added in future versions: build it with Timechange::new.
+/// Timechanges are ordered by instant, and all functions return them in chronological order
+/// (unless asked otherwise, see ZoneQuery::order), so results can be binary-searched and merged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub struct Timechange {
     /// The UTC time and date of the timechange, BEFORE new parameters apply
     pub time: DateTime<Utc>,
@@ -124,24 +283,349 @@ pub struct Timechange {
     pub abbreviation: String,
 }
 
+/// By instant, then by the other fields to stay consistent with Eq
+impl Ord for Timechange {
+    fn cmp(&self, other: &Timechange) -> Ordering {
+        self.time
+            .cmp(&other.time)
+            .then(self.gmtoff.cmp(&other.gmtoff))
+            .then(self.isdst.cmp(&other.isdst))
+            .then_with(|| self.abbreviation.cmp(&other.abbreviation))
+    }
+}
+
+impl PartialOrd for Timechange {
+    fn partial_cmp(&self, other: &Timechange) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Timechange {
+    /// Creates a timechange to the given offset, DST flag and abbreviation.
+    pub fn new(time: DateTime<Utc>, gmtoff: isize, isdst: bool, abbreviation: &str) -> Timechange {
+        Timechange {
+            time,
+            gmtoff,
+            isdst,
+            abbreviation: abbreviation.to_string(),
+        }
+    }
+
+    /// The UTC time and date of the timechange, BEFORE new parameters apply
+    pub fn time(&self) -> DateTime<Utc> {
+        self.time
+    }
+
+    /// The UPCOMING offset to GMT
+    pub fn gmtoff(&self) -> isize {
+        self.gmtoff
+    }
+
+    /// Is upcoming change dst ?
+    pub fn isdst(&self) -> bool {
+        self.isdst
+    }
+
+    /// TZ abbreviation of upcoming change
+    pub fn abbreviation(&self) -> &str {
+        &self.abbreviation
+    }
+}
+
+/// Years selection for get_timechanges_for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum YearSpec {
+    /// Current year, in UTC
+    Current,
+    /// A single year
+    Year(i32),
+    /// All time changes recorded in the TZfile
+    All,
+    /// Several consecutive years, ie. YearSpec::Range(2020..=2030)
+    Range(RangeInclusive<i32>),
+}
+
+impl YearSpec {
+    /// Maps the former Option<i32> year parameter, where Some(0) meant current year.
+    fn from_option(y: Option<i32>) -> YearSpec {
+        match y {
+            None => YearSpec::All,
+            Some(0) => YearSpec::Current,
+            Some(y) => YearSpec::Year(y),
+        }
+    }
+
+    /// Replaces YearSpec::Current by the year of `now`, so the selection no longer depends on the system clock.
+    pub fn at(&self, now: DateTime<Utc>) -> YearSpec {
+        match self {
+            YearSpec::Current => YearSpec::Year(now.year()),
+            years => years.clone(),
+        }
+    }
+
+    /// First and last selected years, None for all years.
+    fn bounds(&self) -> Option<(i32, i32)> {
+        match self {
+            YearSpec::Current => {
+                let y = Utc::now().year();
+                Some((y, y))
+            }
+            YearSpec::Year(y) => Some((*y, *y)),
+            YearSpec::All => None,
+            YearSpec::Range(r) => Some((*r.start(), *r.end())),
+        }
+    }
+}
+
+impl From<i32> for YearSpec {
+    fn from(year: i32) -> YearSpec {
+        YearSpec::Year(year)
+    }
+}
+
+impl From<RangeInclusive<i32>> for YearSpec {
+    fn from(years: RangeInclusive<i32>) -> YearSpec {
+        YearSpec::Range(years)
+    }
+}
+
+impl Tzinfo {
+    /// Builds the Tzinfo of a timezone at the `now` instant, from its timechanges in chronological order,
+    /// ie. those of a tzfile or of any other source (tests, synthetic zones...).
+    pub fn from_timechanges(timezone: &str, timechanges: &[Timechange], now: DateTime<Utc>) -> Result<Tzinfo, Error> {
+        Tzinfo::from_selected(timezone, year_timechanges(timechanges, now.year()), timechanges, now)
+    }
+
+    /// Same as from_timechanges, with the timechanges of the year already selected
+    pub(crate) fn from_selected(
+        timezone: &str,
+        selected: Vec<Timechange>,
+        timechanges: &[Timechange],
+        now: DateTime<Utc>,
+    ) -> Result<Tzinfo, Error> {
+        let mut tzinfo = zoneinfo(timezone.to_string(), selected, now)?;
+        tzinfo.transition_count = Some(timechanges.len());
+        tzinfo.first_transition = timechanges.first().map(|tc| tc.time);
+        tzinfo.last_transition = timechanges.last().map(|tc| tc.time);
+        Ok(tzinfo)
+    }
+}
+
+/// Selects the timechanges of a year, or the last one before when there's none that year.
+pub(crate) fn year_timechanges(timechanges: &[Timechange], year: i32) -> Vec<Timechange> {
+    let mut selected: Vec<Timechange> = timechanges.iter().filter(|tc| tc.time.year() == year).cloned().collect();
+    // No timechange this year ? the last one applies
+    if selected.is_empty() {
+        selected.extend(timechanges.iter().rev().find(|tc| tc.time.year() < year).cloned());
+    }
+    selected
+}
+
+/// Parses a zone name (ie. "Europe/Paris", resolved with Resolver::system), a tzfile path or a fixed offset
+/// identifier into the Tzinfo of the current instant: `let tzinfo: Tzinfo = "Europe/Paris".parse()?;`
+impl FromStr for Tzinfo {
+    type Err = Error;
+
+    fn from_str(zone: &str) -> Result<Tzinfo, Error> {
+        if fixed::parse_fixed_offset(zone).is_some() || Path::new(zone).is_absolute() {
+            return get_zoneinfo(zone);
+        }
+        let mut tzinfo = get_zoneinfo(&Resolver::system().resolve(zone)?)?;
+        // The requested name, rather than the one derived from the path
+        tzinfo.timezone = zone.to_string();
+        Ok(tzinfo)
+    }
+}
+
+impl TryFrom<&str> for Tzinfo {
+    type Error = Error;
+
+    fn try_from(zone: &str) -> Result<Tzinfo, Error> {
+        zone.parse()
+    }
+}
+
+impl Tzinfo {
+    /// Returns the (name, rendered value) pairs of all fields, in declaration order, ie. for templates and tables.
+    /// Names are those of the JSON document, missing values are rendered empty.
+    pub fn fields(&self) -> impl Iterator<Item = (&'static str, String)> {
+        let optional = |value: Option<String>| value.unwrap_or_default();
+        let time = |time: Option<DateTime<Utc>>| optional(time.map(|t| t.to_string()));
+        vec![
+            ("timezone", self.timezone.clone()),
+            ("utc_datetime", self.utc_datetime.to_string()),
+            ("datetime", self.datetime.to_string()),
+            ("dst_from", time(self.dst_from)),
+            ("dst_until", time(self.dst_until)),
+            ("dst_period", self.dst_period.to_string()),
+            ("raw_offset", self.raw_offset.to_string()),
+            ("dst_offset", self.dst_offset.to_string()),
+            ("utc_offset", self.utc_offset.to_string()),
+            ("abbreviation", self.abbreviation.clone()),
+            ("week_number", self.week_number.to_string()),
+            ("transition_count", optional(self.transition_count.map(|c| c.to_string()))),
+            ("first_transition", time(self.first_transition)),
+            ("last_transition", time(self.last_transition)),
+        ]
+        .into_iter()
+    }
+}
+
+/// The current offset to GMT
+impl From<&Tzinfo> for FixedOffset {
+    fn from(tzinfo: &Tzinfo) -> FixedOffset {
+        tzinfo.utc_offset
+    }
+}
+
+impl From<Tzinfo> for FixedOffset {
+    fn from(tzinfo: Tzinfo) -> FixedOffset {
+        tzinfo.utc_offset
+    }
+}
+
+/// The local date and time
+impl From<&Tzinfo> for DateTime<FixedOffset> {
+    fn from(tzinfo: &Tzinfo) -> DateTime<FixedOffset> {
+        tzinfo.datetime
+    }
+}
+
+impl From<Tzinfo> for DateTime<FixedOffset> {
+    fn from(tzinfo: Tzinfo) -> DateTime<FixedOffset> {
+        tzinfo.datetime
+    }
+}
+
+/// The DST period of the year, failing with ErrorKind::NoData when DST is not observed
+impl TryFrom<&Tzinfo> for Range<DateTime<Utc>> {
+    type Error = Error;
+
+    fn try_from(tzinfo: &Tzinfo) -> Result<Range<DateTime<Utc>>, Error> {
+        match (tzinfo.dst_from, tzinfo.dst_until) {
+            (Some(from), Some(until)) => Ok(from..until),
+            _ => Err(Error::new(ErrorKind::NoData, "converting DST period").with_zone(&tzinfo.timezone)),
+        }
+    }
+}
+
+impl TryFrom<Tzinfo> for Range<DateTime<Utc>> {
+    type Error = Error;
+
+    fn try_from(tzinfo: Tzinfo) -> Result<Range<DateTime<Utc>>, Error> {
+        Range::try_from(&tzinfo)
+    }
+}
+
 /// Transforms the Tzinfo struct to a JSON string
 #[cfg(feature = "json")]
 impl Tzinfo {
     pub fn to_json(&self) -> Result<String, serde_json::error::Error> {
         serde_json::to_string(self)
     }
+
+    /// Same as to_json, indented for humans reading logs and terminals.
+    pub fn to_json_pretty(&self) -> Result<String, serde_json::error::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Same as to_json, as a JSON tree to merge into larger documents.
+    pub fn to_json_value(&self) -> Result<serde_json::Value, serde_json::error::Error> {
+        serde_json::to_value(self)
+    }
 }
 
 /// Returns year's timechanges for a timezone.
 /// If year is Some(0), returns current year's timechanges.
 /// If there's no timechange for selected year, returns the last occured timechange to see selected zone's applying parameters.
 /// If no year (None) is specified, returns all time changes recorded in the TZfile .
+#[deprecated(note = "use get_timechanges_for, Some(0) becoming YearSpec::Current")]
 pub fn get_timechanges(
     requested_timezone: &str,
     y: Option<i32>,
-) -> Result<Vec<Timechange>, TzError> {
-    // low-level parse of tzfile
-    let timezone = libtzfile::parse(requested_timezone)?;
+) -> Result<Vec<Timechange>, Error> {
+    get_timechanges_for(requested_timezone, YearSpec::from_option(y))
+}
+
+/// Same as get_timechanges, but rejects a tzfile exceeding the given limits before parsing it.
+#[deprecated(note = "use get_timechanges_for_with_limits, Some(0) becoming YearSpec::Current")]
+pub fn get_timechanges_with_limits(
+    requested_timezone: &str,
+    y: Option<i32>,
+    limits: &Limits,
+) -> Result<Vec<Timechange>, Error> {
+    get_timechanges_for_with_limits(requested_timezone, YearSpec::from_option(y), limits)
+}
+
+/// Returns the timechanges of a timezone for the selected years, in chronological order (see ZoneQuery for most recent first).
+/// If there's no timechange in the selected years, returns the last occured timechange to see selected zone's applying parameters.
+/// Years past the recorded timechanges get those of the POSIX footer rule, and years before the first one the initial
+/// local time type (ie. LMT), dated at the start of the selected years.
+/// With YearSpec::All, returns all time changes recorded in the TZfile.
+pub fn get_timechanges_for(requested_timezone: &str, years: YearSpec) -> Result<Vec<Timechange>, Error> {
+    get_timechanges_for_with_limits(requested_timezone, years, &Limits::UNLIMITED)
+}
+
+/// Same as get_timechanges_for, but rejects a tzfile exceeding the given limits before parsing it.
+pub fn get_timechanges_for_with_limits(
+    requested_timezone: &str,
+    years: YearSpec,
+    limits: &Limits,
+) -> Result<Vec<Timechange>, Error> {
+    timechanges(requested_timezone, &years, limits, true, RangePolicy::Extrapolate)
+}
+
+/// Returns the timechanges of a timezone occurring in the selected years only.
+/// Unlike get_timechanges_for, returns an empty Vec when there's no timechange in the selected years.
+pub fn get_exact_timechanges(requested_timezone: &str, years: YearSpec) -> Result<Vec<Timechange>, Error> {
+    timechanges(requested_timezone, &years, &Limits::UNLIMITED, false, RangePolicy::Extrapolate)
+}
+
+/// Returns the timechanges of a timezone occurring in the selected years, grouped by (UTC) year.
+/// Years without timechange are left out.
+pub fn get_timechanges_by_year(
+    requested_timezone: &str,
+    years: YearSpec,
+) -> Result<BTreeMap<i32, Vec<Timechange>>, Error> {
+    Ok(group_by_year(get_exact_timechanges(requested_timezone, years)?))
+}
+
+pub(crate) fn group_by_year(timechanges: Vec<Timechange>) -> BTreeMap<i32, Vec<Timechange>> {
+    let mut years: BTreeMap<i32, Vec<Timechange>> = BTreeMap::new();
+    for tc in timechanges {
+        years.entry(tc.time.year()).or_default().push(tc);
+    }
+    years
+}
+
+/// Parses a tzfile and selects its timechanges. With `fallback`, an empty selection is replaced by the timechange in force.
+/// `policy` rules years outside the recorded transitions.
+pub(crate) fn timechanges(
+    requested_timezone: &str,
+    years: &YearSpec,
+    limits: &Limits,
+    fallback: bool,
+    policy: RangePolicy,
+) -> Result<Vec<Timechange>, Error> {
+    // low-level parse of tzfile: transitions come from the 64-bit data block, valid before 1901 and after 2038
+    debug!("parsing tzfile {}", requested_timezone);
+    let tzif = tzif::Tzif::load(requested_timezone, limits)?;
+    select(requested_timezone, &tzif, years, fallback, policy)
+}
+
+/// Selects the timechanges of a parsed tzfile, as `timechanges` does.
+pub(crate) fn select(
+    requested_timezone: &str,
+    tzif: &tzif::Tzif,
+    years: &YearSpec,
+    fallback: bool,
+    policy: RangePolicy,
+) -> Result<Vec<Timechange>, Error> {
+    let timezone = &tzif.tz;
+    trace!("{}: {} transitions recorded", requested_timezone, timezone.tzh_timecnt_data.len());
+    // right/ tzfiles count leap seconds in their timestamps
+    let times: Vec<i64> = timezone.tzh_timecnt_data.iter().map(|t| leap::to_posix(*t, &tzif.leap_seconds)).collect();
+    let rule = || tzif.footer.as_ref().and_then(|footer| footer.parse::<PosixTz>().ok());
 
     // used to store timechange indices
     let mut timechanges = Vec::new();
@@ -150,79 +634,167 @@ pub fn get_timechanges(
     // Used to store parsed timechanges
     let mut parsedtimechanges = Vec::new();
 
-    // Get and store the timechange indices for requested year
-    if y.is_some() {
-        let d = Utc::now();
-        let y = y.unwrap();
-        // year = 0 ? current year is requested
-        let y = if y == 0 {
-            d.format("%Y").to_string().parse()?
-        } else {
-            y
+    // Get and store the timechange indices for requested years
+    let mut selection = None;
+    if let Some((first, last)) = years.bounds() {
+        // for year comparison: from first year's beginning, to the beginning of the year following the last one
+        let timestamp = |y: Option<i32>| {
+            y.and_then(|y| NaiveDate::from_ymd_opt(y, 1, 1))
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+                .map(|d| Utc.from_utc_datetime(&d).timestamp())
+                .ok_or_else(|| Error::new(ErrorKind::OutOfRange, "selecting year").with_zone(requested_timezone))
         };
-        // for year comparison
-        let yearbeg = Utc.ymd(y, 1, 1).and_hms(0, 0, 0).timestamp();
-        let yearend = Utc.ymd(y, 12, 31).and_hms(0, 0, 0).timestamp();
-        for t in 0..timezone.tzh_timecnt_data.len() {
-            if timezone.tzh_timecnt_data[t] > yearbeg && timezone.tzh_timecnt_data[t] < yearend {
+        let yearbeg = timestamp(Some(first))?;
+        let yearend = timestamp(last.checked_add(1))?;
+        selection = Some((first, last, yearbeg, yearend));
+        for t in 0..times.len() {
+            if times[t] >= yearbeg && times[t] < yearend {
                 timechanges.push(t);
             }
-            if timezone.tzh_timecnt_data[t] < yearbeg {
+            // latest transition before the selected years, even in an unsorted tzfile
+            if times[t] < yearbeg && times[t] >= times[nearest_timechange] {
                 nearest_timechange = t;
             };
         }
     } else {
         // No year requested ? stores all timechanges
-        for t in 0..timezone.tzh_timecnt_data.len() {
+        for (t, time) in times.iter().enumerate() {
             /* patch : chrono panics on an overflowing timestamp, and a 0xF800000000000000 timestamp is present in some Debian 10 TZfiles.*/
-            if timezone.tzh_timecnt_data[t] != -576460752303423488 { timechanges.push(t) };
+            if *time != -576460752303423488 { timechanges.push(t) };
         }
     }
 
+    // Builds the timechange of a transition, checking indices and timestamp range
+    let timechange = |t: usize| -> Result<Timechange, Error> {
+        let invalid = || Error::new(ErrorKind::InvalidTzfile, "parsing tzfile").with_zone(requested_timezone);
+        let ttinfo = timezone
+            .tzh_timecnt_indices
+            .get(t)
+            .and_then(|i| timezone.tzh_typecnt.get(*i as usize))
+            .ok_or_else(invalid)?;
+        let abbreviation = timezone.tz_abbr.get(ttinfo.tt_abbrind as usize).ok_or_else(invalid)?;
+        let time = Utc
+            .timestamp_opt(times[t], 0)
+            .single()
+            .ok_or_else(|| Error::new(ErrorKind::OutOfRange, "parsing tzfile").with_zone(requested_timezone))?;
+        Ok(Timechange {
+            time,
+            gmtoff: ttinfo.tt_gmtoff,
+            isdst: ttinfo.tt_isdst == 1,
+            abbreviation: abbreviation.to_string(),
+        })
+    };
+
     // Populating returned Vec<Timechange>
-    if timechanges.len() != 0 {
-        for t in 0..timechanges.len() {
-            let tc = Timechange {
-                time: Utc.timestamp(timezone.tzh_timecnt_data[timechanges[t]], 0),
-                gmtoff: timezone.tzh_typecnt[timezone.tzh_timecnt_indices[timechanges[t]] as usize]
-                    .tt_gmtoff,
-                isdst: timezone.tzh_typecnt[timezone.tzh_timecnt_indices[timechanges[t]] as usize]
-                    .tt_isdst
-                    == 1,
-                abbreviation: timezone.tz_abbr[timezone.tzh_typecnt
-                    [timezone.tzh_timecnt_indices[timechanges[t]] as usize]
-                    .tt_abbrind as usize]
-                    .to_string(),
+    debug!("{}: {} transitions matched {:?}", requested_timezone, timechanges.len(), years);
+    for t in 0..timechanges.len() {
+        let tc = timechange(timechanges[t])?;
+        trace!("{}: {:?}", requested_timezone, tc);
+        parsedtimechanges.push(tc);
+    }
+    if let Some((first, last, yearbeg, yearend)) = selection {
+        let latest = times.iter().copied().max();
+        if policy == RangePolicy::Reject && !times.is_empty() {
+            let year = |time: Option<i64>| time.and_then(|time| Utc.timestamp_opt(time, 0).single()).map(|t| t.year());
+            let recorded = (year(times.iter().copied().min()), year(latest));
+            if !matches!(recorded, (Some(a), Some(b)) if a <= first && last <= b) {
+                return Err(Error::new(ErrorKind::OutOfRange, "selecting year").with_zone(requested_timezone));
+            }
+        }
+        // Past the last recorded transition, the footer rule extrapolates the zone (ie. 2150)
+        if policy == RangePolicy::Extrapolate && !matches!(latest, Some(latest) if latest >= yearend) {
+            if let Some(rule) = rule() {
+                let from = latest.and_then(|latest| Utc.timestamp_opt(latest, 0).single()).map_or(first, |l| l.year());
+                // Local years of the rule overlapping the selected UTC years
+                for year in from.max(first).saturating_sub(1)..=last.saturating_add(1) {
+                    parsedtimechanges.extend(rule.timechanges(year).into_iter().filter(|tc| {
+                        let time = tc.time.timestamp();
+                        time >= yearbeg && time < yearend && !matches!(latest, Some(latest) if time <= latest)
+                    }));
+                }
+            }
+        }
+        if parsedtimechanges.is_empty() && fallback {
+            let time = || {
+                Utc.timestamp_opt(yearbeg, 0)
+                    .single()
+                    .ok_or_else(|| Error::new(ErrorKind::OutOfRange, "selecting year").with_zone(requested_timezone))
             };
-            parsedtimechanges.push(tc);
+            let invalid = || Error::new(ErrorKind::InvalidTzfile, "parsing tzfile").with_zone(requested_timezone);
+            if times.is_empty() {
+                // No transition recorded (ie. Etc/UTC): the footer rule applies, or else the single local time type
+                let time = time()?;
+                let offset = match rule() {
+                    Some(rule) => rule.offset_at(time),
+                    None => tzif.initial().ok_or_else(invalid)?,
+                };
+                parsedtimechanges.push(Timechange::new(time, offset.gmtoff, offset.isdst, &offset.abbreviation));
+            } else if policy == RangePolicy::Extrapolate && times.iter().all(|time| *time >= yearbeg) {
+                // Before the first transition (ie. 1880), the initial local time type applies since the selected years
+                debug!("{}: falling back to the initial local time type", requested_timezone);
+                let initial = tzif.initial().ok_or_else(invalid)?;
+                parsedtimechanges.push(Timechange::new(time()?, initial.gmtoff, initial.isdst, &initial.abbreviation));
+            } else {
+                debug!("{}: falling back to transition {}", requested_timezone, nearest_timechange);
+                parsedtimechanges.push(timechange(nearest_timechange)?);
+            }
         }
-    } else {
-        let tc = Timechange {
-            time: Utc.timestamp(timezone.tzh_timecnt_data[nearest_timechange], 0),
-            gmtoff: timezone.tzh_typecnt[timezone.tzh_timecnt_indices[nearest_timechange] as usize]
-                .tt_gmtoff,
-            isdst: timezone.tzh_typecnt[timezone.tzh_timecnt_indices[nearest_timechange] as usize]
-                .tt_isdst
-                == 1,
-            abbreviation: timezone.tz_abbr[timezone.tzh_typecnt
-                [timezone.tzh_timecnt_indices[nearest_timechange] as usize]
-                .tt_abbrind as usize]
-                .to_string(),
-        };
-        parsedtimechanges.push(tc);
     }
+    // Chronological order is guaranteed, whatever the order in the tzfile
+    parsedtimechanges.sort_by_key(|tc| tc.time);
     Ok(parsedtimechanges)
 }
 
+/// Returns a stable fingerprint of a timezone's parsed data.
+/// Two tzfiles describing the same transitions, footer rule, initial local time type and leap seconds share
+/// the same fingerprint, even if their raw bytes differ,
+/// so caches can tell whether a tzdata upgrade really changed a zone.
+pub fn get_fingerprint(requested_timezone: &str) -> Result<u64, Error> {
+    Ok(Zone::from_tzfile(requested_timezone)?.fingerprint())
+}
+
+/// Returns the POSIX TZ string ending a version 2+ tzfile verbatim, without parsing it, if there is one:
+/// ie. to pass it through to devices taking TZ strings. Zone::footer gives it for a loaded zone.
+pub fn get_footer(requested_timezone: &str) -> Result<Option<String>, Error> {
+    tzif::read_footer(requested_timezone)
+}
+
 /// Returns convenient data about a timezone for current date and time.
-pub fn get_zoneinfo(requested_timezone: &str) -> Result<Tzinfo, TzError> {
+pub fn get_zoneinfo(requested_timezone: &str) -> Result<Tzinfo, Error> {
+    get_zoneinfo_with_limits(requested_timezone, &Limits::UNLIMITED)
+}
+
+/// Same as get_zoneinfo, but rejects a tzfile exceeding the given limits before parsing it.
+pub fn get_zoneinfo_with_limits(requested_timezone: &str, limits: &Limits) -> Result<Tzinfo, Error> {
+    zoneinfo_at(requested_timezone, limits, SystemClock.now())
+}
+
+/// Same as get_zoneinfo, with the current date and time given by a clock.
+pub fn get_zoneinfo_with_clock<C: Clock + ?Sized>(requested_timezone: &str, clock: &C) -> Result<Tzinfo, Error> {
+    get_zoneinfo_at(requested_timezone, clock.now())
+}
+
+/// Returns convenient data about a timezone at the `now` instant.
+/// Unlike get_zoneinfo, identical inputs always give identical results.
+pub fn get_zoneinfo_at(requested_timezone: &str, now: DateTime<Utc>) -> Result<Tzinfo, Error> {
+    zoneinfo_at(requested_timezone, &Limits::UNLIMITED, now)
+}
+
+/// Returns convenient data about a timezone at the `now` instant, its name being derived from the tzfile path.
+/// A fixed offset identifier (ie. "UTC+05:30") needs no tzfile.
+fn zoneinfo_at(requested_timezone: &str, limits: &Limits, now: DateTime<Utc>) -> Result<Tzinfo, Error> {
+    if let Some(gmtoff) = fixed::parse_fixed_offset(requested_timezone) {
+        return fixed::fixed_zoneinfo(gmtoff, now);
+    }
     let mut timezone = String::new();
     #[cfg(not(windows))]
     let mut tz: Vec<&str> = requested_timezone.split("/").collect();
     #[cfg(windows)]
     let mut tz: Vec<&str> = requested_timezone.split("\\").collect();
     // To prevent crash (case of requested directory separator unmatching OS separator)
-    if tz.len() < 3 { return Err(TzError::InvalidTimezone)}
+    if tz.len() < 3 {
+        return Err(Error::new(ErrorKind::InvalidTimezone, "computing zoneinfo").with_zone(requested_timezone));
+    }
     for _ in 0..(tz.len()) - 2 {
         tz.remove(0);
     }
@@ -231,24 +803,35 @@ pub fn get_zoneinfo(requested_timezone: &str) -> Result<Tzinfo, TzError> {
         timezone.push_str("/");
     }
     timezone.push_str(tz[1]);
-    let parsedtimechanges = get_timechanges(requested_timezone, Some(0))?;
-    let d = Utc::now();
+    etc::warn_inverted_sign(&timezone);
+    // The year's timechanges are extrapolated with the footer rule past the recorded ones (ie. 2040)
+    let year = YearSpec::Year(now.year());
+    let tzif = tzif::Tzif::load(requested_timezone, limits)?;
+    let selected = select(requested_timezone, &tzif, &year, true, RangePolicy::Extrapolate)?;
+    let alltimechanges = select(requested_timezone, &tzif, &YearSpec::All, true, RangePolicy::Extrapolate)?;
+    Tzinfo::from_selected(&timezone, selected, &alltimechanges, now)
+}
+
+/// Builds the Tzinfo of a timezone at instant `d` from the timechanges of its year, without the recorded data summary.
+pub(crate) fn zoneinfo(timezone: String, parsedtimechanges: Vec<Timechange>, d: DateTime<Utc>) -> Result<Tzinfo, Error> {
+    let offset = |gmtoff: isize| {
+        i32::try_from(gmtoff)
+            .ok()
+            .and_then(FixedOffset::east_opt)
+            .ok_or_else(|| Error::new(ErrorKind::OutOfRange, "computing zoneinfo").with_zone(&timezone))
+    };
     if parsedtimechanges.len() == 2 {
         // 2 times changes the same year ? DST observed
         // Are we in a dst period ? true / false
         let dst = d > parsedtimechanges[0].time && d < parsedtimechanges[1].time;
         let utc_offset = if dst == true {
-            FixedOffset::east(parsedtimechanges[0].gmtoff as i32)
+            offset(parsedtimechanges[0].gmtoff)?
         } else {
-            FixedOffset::east(parsedtimechanges[1].gmtoff as i32)
+            offset(parsedtimechanges[1].gmtoff)?
         };
         Ok(Tzinfo {
             timezone: timezone,
-            week_number: d
-                .with_timezone(&utc_offset)
-                .format("%V")
-                .to_string()
-                .parse()?,
+            week_number: d.with_timezone(&utc_offset).iso_week().week() as i32,
             utc_datetime: d,
             datetime: d.with_timezone(&utc_offset),
             dst_from: Some(parsedtimechanges[0].time),
@@ -262,16 +845,15 @@ pub fn get_zoneinfo(requested_timezone: &str) -> Result<Tzinfo, TzError> {
             } else {
                 parsedtimechanges[1].abbreviation.clone()
             },
+            transition_count: None,
+            first_transition: None,
+            last_transition: None,
         })
     } else if parsedtimechanges.len() == 1 {
-        let utc_offset = FixedOffset::east(parsedtimechanges[0].gmtoff as i32);
+        let utc_offset = offset(parsedtimechanges[0].gmtoff)?;
         Ok(Tzinfo {
             timezone: timezone,
-            week_number: d
-                .with_timezone(&utc_offset)
-                .format("%V")
-                .to_string()
-                .parse()?,
+            week_number: d.with_timezone(&utc_offset).iso_week().week() as i32,
             utc_datetime: d,
             datetime: d.with_timezone(&utc_offset),
             dst_from: None,
@@ -281,9 +863,12 @@ pub fn get_zoneinfo(requested_timezone: &str) -> Result<Tzinfo, TzError> {
             dst_offset: 0,
             utc_offset: utc_offset,
             abbreviation: parsedtimechanges[0].abbreviation.clone(),
+            transition_count: None,
+            first_transition: None,
+            last_transition: None,
         })
     } else {
-        Err(TzError::NoData)
+        Err(Error::new(ErrorKind::NoData, "computing zoneinfo").with_zone(&timezone))
     }
 }
 
@@ -291,6 +876,7 @@ pub fn get_zoneinfo(requested_timezone: &str) -> Result<Tzinfo, TzError> {
 mod tests {
     use super::*;
     #[test]
+    #[allow(deprecated)]
     fn partial_timechanges() {
         let tz = vec![
             Timechange {
@@ -319,6 +905,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn total_timechanges() {
         let tz = vec![
             Timechange { time: Utc.ymd(1883, 11, 18).and_hms(19, 0, 0), gmtoff: -25200, isdst: false, abbreviation: String::from("MST") },
@@ -355,4 +942,297 @@ mod tests {
         assert_eq!(tztest.raw_offset, 3600);
         assert_eq!(tztest.dst_offset, 7200);
     }
+
+    #[test]
+    fn zoneinfo_summary() {
+        let paris = "/usr/share/zoneinfo/Europe/Paris";
+        let recorded = get_timechanges_for(paris, YearSpec::All).unwrap();
+        let tztest = get_zoneinfo(paris).unwrap();
+        assert_eq!(tztest.transition_count, Some(recorded.len()));
+        assert_eq!(tztest.first_transition, recorded.first().map(|tc| tc.time));
+        assert_eq!(tztest.first_transition.unwrap().year(), 1891);
+        assert_eq!(tztest.clone(), tztest);
+        // Past the recorded transitions, from the footer rule
+        let late = get_zoneinfo_at(paris, Utc.ymd(2040, 7, 1).and_hms(12, 0, 0)).unwrap();
+        assert_eq!((late.abbreviation.as_str(), late.dst_period), ("CEST", true));
+        assert_eq!(late.dst_from, Some(Utc.ymd(2040, 3, 25).and_hms(1, 0, 0)));
+        assert_eq!(late.last_transition, recorded.last().map(|tc| tc.time));
+    }
+
+    #[test]
+    fn year_spec() {
+        let paris = "/usr/share/zoneinfo/Europe/Paris";
+        let range = get_timechanges_for(paris, YearSpec::Range(2019..=2020)).unwrap();
+        assert_eq!(range.len(), 4);
+        assert_eq!(range[3].time, Utc.ymd(2020, 10, 25).and_hms(1, 0, 0));
+        assert_eq!(get_timechanges_for(paris, YearSpec::Current).unwrap().len(), 2);
+        // No transition in those years: the one in force is returned
+        let phoenix = get_timechanges_for("/usr/share/zoneinfo/America/Phoenix", YearSpec::Range(2000..=2010)).unwrap();
+        assert_eq!(phoenix[0].time, Utc.ymd(1967, 10, 29).and_hms(8, 0, 0));
+        // unless asking for exact timechanges
+        let phoenix = get_exact_timechanges("/usr/share/zoneinfo/America/Phoenix", YearSpec::Range(2000..=2010)).unwrap();
+        assert!(phoenix.is_empty());
+        assert!(get_exact_timechanges("/usr/share/zoneinfo/Etc/UTC", YearSpec::Year(2020)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn chronological() {
+        for zone in &["Europe/Paris", "America/Phoenix", "Australia/Lord_Howe"] {
+            let all = get_timechanges_for(&resolve_zone(zone).unwrap(), YearSpec::All).unwrap();
+            assert!(all.windows(2).all(|w| w[0].time < w[1].time));
+        }
+    }
+
+    #[test]
+    fn by_year() {
+        let years = get_timechanges_by_year("/usr/share/zoneinfo/America/Phoenix", YearSpec::Range(1940..=1970)).unwrap();
+        assert_eq!(years.keys().copied().collect::<Vec<_>>(), vec![1942, 1944, 1967]);
+        assert_eq!(years[&1944].len(), 3);
+    }
+
+    #[test]
+    fn from_timechanges() {
+        let timechanges = vec![
+            Timechange::new(Utc.ymd(2019, 3, 31).and_hms(1, 0, 0), 7200, true, "CEST"),
+            Timechange::new(Utc.ymd(2019, 10, 27).and_hms(1, 0, 0), 3600, false, "CET"),
+        ];
+        let summer = Tzinfo::from_timechanges("Test/Zone", &timechanges, Utc.ymd(2019, 7, 14).and_hms(12, 0, 0)).unwrap();
+        assert!(summer.dst_period);
+        assert_eq!(summer.abbreviation, "CEST");
+        assert_eq!(summer.datetime.to_rfc3339(), "2019-07-14T14:00:00+02:00");
+        // Later years keep the last timechange
+        let later = Tzinfo::from_timechanges("Test/Zone", &timechanges, Utc.ymd(2021, 7, 14).and_hms(12, 0, 0)).unwrap();
+        assert_eq!((later.raw_offset, later.dst_period), (3600, false));
+        assert_eq!(later.transition_count, Some(2));
+        assert!(Tzinfo::from_timechanges("Test/Zone", &[], Utc::now()).is_err());
+    }
+
+    #[test]
+    fn accessors() {
+        let tc = Timechange::new(Utc.ymd(2019, 3, 31).and_hms(1, 0, 0), 7200, true, "CEST");
+        assert_eq!(get_timechanges_for("/usr/share/zoneinfo/Europe/Paris", YearSpec::Year(2019)).unwrap()[0], tc);
+        assert_eq!((tc.gmtoff(), tc.isdst(), tc.abbreviation()), (7200, true, "CEST"));
+    }
+
+    #[test]
+    fn hashable() {
+        let paris = "/usr/share/zoneinfo/Europe/Paris";
+        let mut set = std::collections::HashSet::new();
+        set.extend(get_timechanges_for(paris, YearSpec::Range(2019..=2020)).unwrap());
+        set.extend(get_timechanges_for(paris, YearSpec::Year(2020)).unwrap());
+        assert_eq!(set.len(), 4);
+    }
+
+    #[test]
+    fn clock() {
+        let paris = "/usr/share/zoneinfo/Europe/Paris";
+        let clock = FixedClock(Utc.ymd(2019, 7, 14).and_hms(12, 0, 0));
+        let tzinfo = get_zoneinfo_with_clock(paris, &clock).unwrap();
+        assert_eq!(tzinfo.datetime.to_rfc3339(), "2019-07-14T14:00:00+02:00");
+        assert_eq!(tzinfo.dst_until, Some(Utc.ymd(2019, 10, 27).and_hms(1, 0, 0)));
+        assert_eq!(tzinfo, get_zoneinfo_with_clock(paris, &clock).unwrap());
+    }
+
+    #[test]
+    fn pure() {
+        let now = Utc.ymd(2019, 7, 14).and_hms(12, 0, 0);
+        let paris = "/usr/share/zoneinfo/Europe/Paris";
+        assert_eq!(get_zoneinfo_at(paris, now).unwrap(), get_zoneinfo_with_clock(paris, &FixedClock(now)).unwrap());
+        assert_eq!(YearSpec::Current.at(now), YearSpec::Year(2019));
+        assert_eq!(YearSpec::All.at(now), YearSpec::All);
+    }
+
+    #[test]
+    fn fingerprint() {
+        let paris = get_fingerprint("/usr/share/zoneinfo/Europe/Paris").unwrap();
+        assert_eq!(paris, get_fingerprint("/usr/share/zoneinfo/Europe/Paris").unwrap());
+        assert_ne!(paris, get_fingerprint("/usr/share/zoneinfo/America/Phoenix").unwrap());
+    }
+
+    #[test]
+    fn wide_timestamps() {
+        // Beyond the range of the 32-bit data block, both ways: compiled by zic from testdata/Wide.zi
+        let wide = concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/Wide");
+        let early = get_exact_timechanges(wide, YearSpec::Year(1849)).unwrap();
+        assert_eq!(early, vec![Timechange::new(Utc.ymd(1849, 12, 31).and_hms(23, 30, 0), 3600, false, "ONE")]);
+        let late = get_exact_timechanges(wide, YearSpec::Year(2045)).unwrap();
+        assert_eq!(late, vec![Timechange::new(Utc.ymd(2045, 5, 31).and_hms(23, 0, 0), 7200, false, "TWO")]);
+        let zone = Zone::from_tzfile(wide).unwrap();
+        assert_eq!(zone.offset_at(Utc.ymd(2040, 1, 1).and_hms(0, 0, 0)).unwrap().abbreviation, "ONE");
+        assert_eq!(zone.offset_at(Utc.ymd(2046, 1, 1).and_hms(0, 0, 0)).unwrap().abbreviation, "TWO");
+        assert_eq!(zone.initial().unwrap().abbreviation, "LMT");
+    }
+
+    #[test]
+    fn early_and_late_years() {
+        let paris = "/usr/share/zoneinfo/Europe/Paris";
+        // Projected from the footer rule
+        let late = get_timechanges_for(paris, YearSpec::Year(2150)).unwrap();
+        assert_eq!(
+            late,
+            vec![
+                Timechange::new(Utc.ymd(2150, 3, 29).and_hms(1, 0, 0), 7200, true, "CEST"),
+                Timechange::new(Utc.ymd(2150, 10, 25).and_hms(1, 0, 0), 3600, false, "CET"),
+            ]
+        );
+        assert_eq!(get_exact_timechanges(paris, YearSpec::Year(2150)).unwrap(), late);
+        // Recorded until 2037, then projected
+        let around = get_exact_timechanges(paris, YearSpec::Range(2036..=2039)).unwrap();
+        assert_eq!(around.len(), 8);
+        assert!(around.windows(2).all(|w| w[0] < w[1] && w[0].isdst != w[1].isdst));
+        // Local mean time, before the first transition in 1891
+        let early = get_timechanges_for(paris, YearSpec::Year(1880)).unwrap();
+        assert_eq!(early, vec![Timechange::new(Utc.ymd(1880, 1, 1).and_hms(0, 0, 0), 561, false, "LMT")]);
+        assert!(get_exact_timechanges(paris, YearSpec::Year(1880)).unwrap().is_empty());
+        // Without recorded transitions, from the footer rule
+        let now = Utc.ymd(2150, 7, 1).and_hms(12, 0, 0);
+        let minus_five = get_zoneinfo_at("/usr/share/zoneinfo/Etc/GMT+5", now).unwrap();
+        assert_eq!((minus_five.abbreviation.as_str(), minus_five.raw_offset), ("-05", -18000));
+        let utc = Zone::from_tzfile("/usr/share/zoneinfo/Etc/UTC").unwrap();
+        assert_eq!(utc.tzinfo(now).unwrap().abbreviation, "UTC");
+    }
+
+    #[test]
+    fn footer() {
+        let paris = "/usr/share/zoneinfo/Europe/Paris";
+        assert_eq!(get_footer(paris).unwrap().as_deref(), Some("CET-1CEST,M3.5.0,M10.5.0/3"));
+        assert_eq!(get_footer(paris).unwrap().as_deref(), Zone::from_tzfile(paris).unwrap().footer());
+        assert_eq!(get_footer("/usr/share/zoneinfo/Etc/UTC").unwrap().as_deref(), Some("UTC0"));
+        assert!(get_footer("/usr/share/zoneinfo/Nowhere").is_err());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn limits() {
+        let paris = "/usr/share/zoneinfo/Europe/Paris";
+        assert!(get_timechanges_with_limits(paris, Some(2019), &Limits::default()).is_ok());
+        let tight = Limits { max_transitions: 10, ..Limits::default() };
+        assert!(get_timechanges_with_limits(paris, Some(2019), &tight).is_err());
+        let tiny = Limits { max_file_size: 100, ..Limits::default() };
+        let err = get_zoneinfo_with_limits(paris, &tiny).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::LimitExceeded);
+        assert_eq!(err.zone(), Some(paris));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn error_context() {
+        let err = get_timechanges("/usr/share/zoneinfo/zone.tab", None).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidTzfile);
+        assert_eq!(err.zone(), Some("/usr/share/zoneinfo/zone.tab"));
+        assert!(err.tzfile_error().is_some());
+        let err = "CET-1CEST,M3.5.0,X10.5.0".parse::<PosixTz>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidPosixString);
+        assert_eq!(err.offset(), Some(17));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn no_panic() {
+        let paris = "/usr/share/zoneinfo/Europe/Paris";
+        assert_eq!(get_timechanges(paris, Some(i32::MAX)).unwrap_err().kind(), ErrorKind::OutOfRange);
+        assert_eq!(get_timechanges(paris, Some(-400_000)).unwrap_err().kind(), ErrorKind::OutOfRange);
+        let utc = get_timechanges("/usr/share/zoneinfo/Etc/UTC", Some(2020)).unwrap();
+        assert_eq!(utc, vec![Timechange::new(Utc.ymd(2020, 1, 1).and_hms(0, 0, 0), 0, false, "UTC")]);
+        let odd = PosixTz {
+            std_abbreviation: String::from("ODD"),
+            std_offset: isize::MIN,
+            dst: Some(PosixDst {
+                abbreviation: String::from("ODDS"),
+                offset: isize::MAX,
+                start: PosixRule { date: RuleDate::MonthWeekDay { month: 0, week: 0, weekday: 9 }, time: 0 },
+                end: PosixRule { date: RuleDate::MonthWeekDay { month: 3, week: 0, weekday: 9 }, time: 0 },
+            }),
+        };
+        assert!(odd.timechanges(2020).is_empty());
+    }
+
+    #[test]
+    fn from_str() {
+        let paris: Tzinfo = "Europe/Paris".parse().unwrap();
+        assert_eq!(paris.timezone, "Europe/Paris");
+        assert!(paris.abbreviation == "CET" || paris.abbreviation == "CEST");
+        let buenos_aires = Tzinfo::try_from("America/Argentina/Buenos_Aires").unwrap();
+        assert_eq!(buenos_aires.timezone, "America/Argentina/Buenos_Aires");
+        assert_eq!("/usr/share/zoneinfo/Asia/Tokyo".parse::<Tzinfo>().unwrap().timezone, "Asia/Tokyo");
+        assert_eq!("UTC+05:30".parse::<Tzinfo>().unwrap().raw_offset, 19800);
+        assert_eq!("Europe/Nowhere".parse::<Tzinfo>().unwrap_err().kind(), ErrorKind::InvalidTimezone);
+    }
+
+    #[test]
+    fn into_chrono() {
+        let now = Utc.ymd(2019, 7, 14).and_hms(12, 0, 0);
+        let paris = get_zoneinfo_at("/usr/share/zoneinfo/Europe/Paris", now).unwrap();
+        assert_eq!(FixedOffset::from(&paris), FixedOffset::east(7200));
+        let local: DateTime<FixedOffset> = paris.clone().into();
+        assert_eq!(local, now);
+        assert_eq!(local.hour(), 14);
+        let dst = Range::try_from(&paris).unwrap();
+        assert_eq!(dst, Utc.ymd(2019, 3, 31).and_hms(1, 0, 0)..Utc.ymd(2019, 10, 27).and_hms(1, 0, 0));
+        assert!(dst.contains(&now));
+        let tokyo = get_zoneinfo_at("/usr/share/zoneinfo/Asia/Tokyo", now).unwrap();
+        assert_eq!(Range::try_from(tokyo).unwrap_err().kind(), ErrorKind::NoData);
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn json_pretty() {
+        let paris = get_zoneinfo_at("/usr/share/zoneinfo/Europe/Paris", Utc.ymd(2019, 7, 14).and_hms(12, 0, 0)).unwrap();
+        let pretty = paris.to_json_pretty().unwrap();
+        assert!(pretty.starts_with("{\n  \"timezone\": \"Europe/Paris\",\n"));
+        let compact: String = pretty.lines().map(|l| l.trim_start().replacen("\": ", "\":", 1)).collect();
+        assert_eq!(compact, paris.to_json().unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn json_value() {
+        let paris = get_zoneinfo_at("/usr/share/zoneinfo/Europe/Paris", Utc.ymd(2019, 7, 14).and_hms(12, 0, 0)).unwrap();
+        let value = paris.to_json_value().unwrap();
+        assert_eq!(value["abbreviation"], "CEST");
+        assert_eq!(value["raw_offset"], 3600);
+        assert_eq!(value["dst_until"], "2019-10-27T01:00:00Z");
+        let payload = serde_json::json!({ "zones": [value] });
+        let parsed: serde_json::Value = serde_json::from_str(&paris.to_json().unwrap()).unwrap();
+        assert_eq!(payload["zones"][0], parsed);
+    }
+
+    #[test]
+    fn fields() {
+        let tokyo = get_zoneinfo_at("/usr/share/zoneinfo/Asia/Tokyo", Utc.ymd(2019, 7, 14).and_hms(12, 0, 0)).unwrap();
+        let fields: Vec<_> = tokyo.fields().collect();
+        assert_eq!(fields[0], ("timezone", String::from("Asia/Tokyo")));
+        assert_eq!(fields[2], ("datetime", String::from("2019-07-14 21:00:00 +09:00")));
+        assert!(fields.contains(&("dst_from", String::new())));
+        assert!(fields.contains(&("utc_offset", String::from("+09:00"))));
+        #[cfg(feature = "json")]
+        {
+            let json = tokyo.to_json().unwrap();
+            let mut keys = fields.iter().map(|(name, _)| json.find(&format!("\"{}\":", name)).unwrap());
+            let first = keys.next().unwrap();
+            assert!(keys.try_fold(first, |previous, key| if key > previous { Some(key) } else { None }).is_some());
+            assert_eq!(fields.len(), json.matches("\":").count());
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        let early = Timechange::new(Utc.ymd(2019, 3, 31).and_hms(1, 0, 0), 7200, true, "CEST");
+        let late = Timechange::new(Utc.ymd(2019, 10, 27).and_hms(1, 0, 0), 3600, false, "CET");
+        assert!(early < late);
+        assert_eq!(early.cmp(&early.clone()), Ordering::Equal);
+        let mut other = early.clone();
+        other.abbreviation = String::from("XXX");
+        assert_ne!(early.cmp(&other), Ordering::Equal);
+        let paris = "/usr/share/zoneinfo/Europe/Paris";
+        let sydney = "/usr/share/zoneinfo/Australia/Sydney";
+        for zone in &[paris, sydney] {
+            let all = get_timechanges_for(zone, YearSpec::All).unwrap();
+            assert!(all.windows(2).all(|w| w[0] < w[1]));
+            let year = get_exact_timechanges(zone, YearSpec::Year(2019)).unwrap();
+            assert_eq!(all.binary_search(&year[1]).map(|i| &all[i]), Ok(&year[1]));
+            let rule: PosixTz = Zone::from_tzfile(zone).unwrap().footer().unwrap().parse().unwrap();
+            assert!(rule.timechanges(2100).windows(2).all(|w| w[0] < w[1]));
+        }
+    }
 }
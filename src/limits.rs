@@ -0,0 +1,71 @@
+use crate::{
+    tzif::{self, HEADER_LEN},
+    Error, ErrorKind,
+};
+use std::{fs::File, io::Read};
+
+/// Sanity limits enforced before a tzfile is parsed, for services handling untrusted files.
+/// Default values are those of the reference tz code, which no genuine tzfile exceeds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Limits {
+    /// Maximum size of the tzfile, in bytes
+    pub max_file_size: u64,
+    /// Maximum number of transitions
+    pub max_transitions: u64,
+    /// Maximum length of the abbreviations table, in bytes
+    pub max_abbreviation_len: u64,
+}
+
+impl Default for Limits {
+    fn default() -> Limits {
+        Limits {
+            max_file_size: 64 * 1024,
+            max_transitions: 2000,
+            max_abbreviation_len: 256,
+        }
+    }
+}
+
+impl Limits {
+    /// No limit at all. Used by the functions which do not take limits.
+    pub(crate) const UNLIMITED: Limits = Limits {
+        max_file_size: u64::MAX,
+        max_transitions: u64::MAX,
+        max_abbreviation_len: u64::MAX,
+    };
+
+    /// Reads a tzfile, no further than the size limit, then checks its header counts against the limits.
+    /// The bytes returned are those checked: parse them rather than the file, which may have changed since.
+    pub(crate) fn read(&self, requested_timezone: &str) -> Result<Vec<u8>, Error> {
+        const OPERATION: &str = "checking limits";
+        let exceeded = || Error::new(ErrorKind::LimitExceeded, OPERATION).with_zone(requested_timezone);
+        let mut buf = Vec::new();
+        File::open(requested_timezone)
+            .and_then(|file| file.take(self.max_file_size.saturating_add(1)).read_to_end(&mut buf))
+            .map_err(|e| Error::io(e, "reading tzfile").with_zone(requested_timezone))?;
+        if buf.len() as u64 > self.max_file_size {
+            return Err(exceeded());
+        }
+        if *self == Limits::UNLIMITED {
+            return Ok(buf);
+        }
+        let (v1, v2) = tzif::headers(&buf).map_err(|e| e.with_zone(requested_timezone))?;
+        for header in Some(&v1).into_iter().chain(v2.as_ref()) {
+            if header.timecnt > self.max_transitions || header.charcnt > self.max_abbreviation_len {
+                return Err(exceeded());
+            }
+        }
+        let mut expected = HEADER_LEN + v1.data_len(4);
+        if let Some(v2) = v2 {
+            expected += HEADER_LEN + v2.data_len(8);
+        }
+        // Counts announcing more data than the file holds would make the parser allocate for nothing
+        let size = buf.len() as u64;
+        if expected > size {
+            return Err(Error::new(ErrorKind::InvalidTzfile, OPERATION)
+                .with_zone(requested_timezone)
+                .with_offset(size));
+        }
+        Ok(buf)
+    }
+}
@@ -0,0 +1,612 @@
+use crate::{Error, ErrorKind, Zone, ZoneOffset};
+use chrono::{prelude::*, Duration};
+use std::{convert::TryFrom, ops::Range};
+
+/// How to pick the instant of a local time which occurs twice (fold) or never (gap) because of a timechange.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Disambiguation {
+    /// Earlier instant in a fold, local time pushed forward by the gap length in a gap (as RFC 5545 and most calendars do)
+    Compatible,
+    /// Earlier instant in a fold, local time pushed backward by the gap length in a gap
+    Earlier,
+    /// Later instant in a fold, local time pushed forward by the gap length in a gap
+    Later,
+    /// Error in a fold or a gap
+    Reject,
+}
+
+/// How a local time maps to instants in a zone, as chrono's LocalResult but with the zone's local time types.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LocalResolution {
+    /// Occurs once, with this local time type
+    Unique(ZoneOffset),
+    /// Occurs twice, before and after a timechange setting clocks back
+    Ambiguous { earlier: ZoneOffset, later: ZoneOffset },
+    /// Skipped by a timechange setting clocks forward
+    Gap { before: ZoneOffset, after: ZoneOffset },
+}
+
+/// Whether a timechange skips or repeats local times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiscontinuityKind {
+    /// Clocks spring forward: local times in the interval never occur
+    Gap,
+    /// Clocks go back: local times in the interval occur twice
+    Fold,
+}
+
+/// Local times skipped or repeated by a timechange, ie. 02:00 to 03:00 on the last Sunday of March in Paris.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Discontinuity {
+    /// Gap or fold
+    pub kind: DiscontinuityKind,
+    /// First local time skipped or repeated
+    pub start: NaiveDateTime,
+    /// Local time following the interval, excluded
+    pub end: NaiveDateTime,
+    /// Instant of the timechange
+    pub time: DateTime<Utc>,
+}
+
+impl Discontinuity {
+    /// Length of the interval, ie. one hour for most DST timechanges
+    pub fn length(&self) -> Duration {
+        self.end - self.start
+    }
+}
+
+impl LocalResolution {
+    /// Local time type of a local time occurring once
+    pub fn single(&self) -> Option<&ZoneOffset> {
+        match self {
+            LocalResolution::Unique(offset) => Some(offset),
+            _ => None,
+        }
+    }
+
+    /// Local time type of the first occurrence, None in a gap
+    pub fn earliest(&self) -> Option<&ZoneOffset> {
+        match self {
+            LocalResolution::Unique(offset) | LocalResolution::Ambiguous { earlier: offset, .. } => Some(offset),
+            LocalResolution::Gap { .. } => None,
+        }
+    }
+
+    /// Local time type of the last occurrence, None in a gap
+    pub fn latest(&self) -> Option<&ZoneOffset> {
+        match self {
+            LocalResolution::Unique(offset) | LocalResolution::Ambiguous { later: offset, .. } => Some(offset),
+            LocalResolution::Gap { .. } => None,
+        }
+    }
+}
+
+/// A moment to convert: an instant, or a local time of the source zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Moment {
+    /// An instant
+    Instant(DateTime<Utc>),
+    /// A local time
+    Local(NaiveDateTime),
+}
+
+impl From<DateTime<Utc>> for Moment {
+    fn from(instant: DateTime<Utc>) -> Moment {
+        Moment::Instant(instant)
+    }
+}
+
+impl From<NaiveDateTime> for Moment {
+    fn from(local: NaiveDateTime) -> Moment {
+        Moment::Local(local)
+    }
+}
+
+/// Converts a moment to the local time of `to`. A local time is first resolved in `from` with the given policy.
+pub fn convert_between<M: Into<Moment>>(
+    moment: M,
+    from: &Zone,
+    to: &Zone,
+    policy: Disambiguation,
+) -> Result<DateTime<FixedOffset>, Error> {
+    let instant = match moment.into() {
+        Moment::Instant(instant) => instant,
+        Moment::Local(local) => from.to_utc(local, policy)?,
+    };
+    to.to_local(instant)
+}
+
+/// Time elapsed between a local time of `from` and a local time of `to`, ie. a flight duration from its
+/// departure and arrival times. Both local times are resolved with the given policy.
+pub fn elapsed(
+    departure: NaiveDateTime,
+    from: &Zone,
+    arrival: NaiveDateTime,
+    to: &Zone,
+    policy: Disambiguation,
+) -> Result<Duration, Error> {
+    Ok(to.to_utc(arrival, policy)? - from.to_utc(departure, policy)?)
+}
+
+/// Time elapsed between two local times of a zone, ie. 3 hours from midnight to 4 AM on a day clocks spring forward.
+/// Local times in a fold or a gap are resolved with Disambiguation::Compatible.
+pub fn wall_duration(zone: &Zone, local_start: NaiveDateTime, local_end: NaiveDateTime) -> Result<Duration, Error> {
+    elapsed(local_start, zone, local_end, zone, Disambiguation::Compatible)
+}
+
+/// Returns the instants at which a local calendar day of the zone starts (included) and ends (excluded).
+/// A day starting in a gap starts at the timechange; a day starting in a fold starts at its first midnight.
+pub fn day_bounds_utc(zone: &Zone, date: NaiveDate) -> Result<Range<DateTime<Utc>>, Error> {
+    let out_of_range = || Error::new(ErrorKind::OutOfRange, "computing day bounds").with_zone(zone.name());
+    let next = date.succ_opt().ok_or_else(out_of_range)?;
+    let midnight = |d: NaiveDate| d.and_hms_opt(0, 0, 0).ok_or_else(out_of_range);
+    let start = zone.to_utc(midnight(date)?, Disambiguation::Compatible)?;
+    let end = zone.to_utc(midnight(next)?, Disambiguation::Compatible)?;
+    Ok(start..end)
+}
+
+/// Returns the instants at which a local calendar month of the zone starts (included) and ends (excluded).
+pub fn month_bounds_utc(zone: &Zone, year: i32, month: u32) -> Result<Range<DateTime<Utc>>, Error> {
+    let out_of_range = || Error::new(ErrorKind::OutOfRange, "computing month bounds").with_zone(zone.name());
+    let first = NaiveDate::from_ymd_opt(year, month, 1).ok_or_else(out_of_range)?;
+    let next = match month {
+        12 => year.checked_add(1).and_then(|y| NaiveDate::from_ymd_opt(y, 1, 1)),
+        _ => NaiveDate::from_ymd_opt(year, month + 1, 1),
+    }
+    .ok_or_else(out_of_range)?;
+    Ok(day_bounds_utc(zone, first)?.start..day_bounds_utc(zone, next)?.start)
+}
+
+/// Returns the length in hours of a local calendar day of the zone: 23 or 25 on DST days,
+/// 24.5 on the day Lord Howe Island leaves its half hour DST.
+pub fn hours_in_day(zone: &Zone, date: NaiveDate) -> Result<f64, Error> {
+    let day = day_bounds_utc(zone, date)?;
+    Ok((day.end - day.start).num_seconds() as f64 / 3600.0)
+}
+
+/// A wall-clock hour of a local day, and when it elapses.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LocalHour {
+    /// Local time at the top of the hour
+    pub hour: NaiveDateTime,
+    /// Instants the hour is on the clock, start included and end excluded
+    pub utc: Range<DateTime<Utc>>,
+    /// Second occurrence of an hour repeated when clocks go back
+    pub fold: bool,
+}
+
+/// Iterator over the wall-clock hours of a local day, see Zone::local_hours.
+#[derive(Debug, Clone)]
+pub struct LocalHours<'a> {
+    zone: &'a Zone,
+    next: DateTime<Utc>,
+    end: DateTime<Utc>,
+    seen: Vec<NaiveDateTime>,
+}
+
+/// Part of a local hour without timechange: (top of the hour, local start, local end, utc end)
+type Piece = (NaiveDateTime, NaiveDateTime, NaiveDateTime, DateTime<Utc>);
+
+impl<'a> LocalHours<'a> {
+    fn piece(&self, start: DateTime<Utc>) -> Option<Piece> {
+        let gmtoff = self.zone.offset_at(start)?.gmtoff;
+        let local = start.naive_utc().checked_add_signed(Duration::seconds(i64::try_from(gmtoff).ok()?))?;
+        let hour = local.date().and_hms_opt(local.hour(), 0, 0)?;
+        let mut end = utc_of(hour.checked_add_signed(Duration::hours(1))?, gmtoff)?.min(self.end);
+        if let Some(tc) = self.zone.changes_between(start, end).first() {
+            end = end.min(tc.time);
+        }
+        Some((hour, local, local + (end - start), end))
+    }
+}
+
+impl<'a> Iterator for LocalHours<'a> {
+    type Item = LocalHour;
+
+    fn next(&mut self) -> Option<LocalHour> {
+        if self.next >= self.end {
+            return None;
+        }
+        let start = self.next;
+        let (hour, _, mut local_end, mut end) = self.piece(start)?;
+        // A timechange within the hour which does not set clocks back splits it, ie. Amsterdam's 28 seconds in 1937
+        while end < self.end {
+            match self.piece(end) {
+                Some((h, local_start, l, e)) if h == hour && local_start >= local_end => {
+                    local_end = l;
+                    end = e;
+                }
+                _ => break,
+            }
+        }
+        self.next = end;
+        let fold = self.seen.contains(&hour);
+        self.seen.push(hour);
+        Some(LocalHour { hour, utc: start..end, fold })
+    }
+}
+
+/// Tells whether a local time occurs twice in the zone, as clocks go back.
+pub fn is_ambiguous(zone: &Zone, local: NaiveDateTime) -> Result<bool, Error> {
+    Ok(matches!(zone.resolve_local(local)?, LocalResolution::Ambiguous { .. }))
+}
+
+/// Tells whether a local time never occurs in the zone, as clocks spring forward.
+pub fn is_nonexistent(zone: &Zone, local: NaiveDateTime) -> Result<bool, Error> {
+    Ok(matches!(zone.resolve_local(local)?, LocalResolution::Gap { .. }))
+}
+
+/// Local time at `gmtoff` converted to UTC, None if the offset is not sane
+pub(crate) fn utc_of(local: NaiveDateTime, gmtoff: isize) -> Option<DateTime<Utc>> {
+    // Offsets are checked against a week, so that Duration never overflows
+    const WEEK: i64 = 7 * 86400;
+    let gmtoff = i64::try_from(gmtoff).ok().filter(|o| (-WEEK..=WEEK).contains(o))?;
+    Some(Utc.from_utc_datetime(&local.checked_sub_signed(Duration::seconds(gmtoff))?))
+}
+
+impl Zone {
+    /// Classifies a local time of the zone: occurring once, twice, or never.
+    pub fn resolve_local(&self, local: NaiveDateTime) -> Result<LocalResolution, Error> {
+        const OPERATION: &str = "resolving local time";
+        let out_of_range = || Error::new(ErrorKind::OutOfRange, OPERATION).with_zone(self.name());
+        let start = utc_of(local, 2 * 86400).ok_or_else(out_of_range)?;
+        let end = utc_of(local, -2 * 86400).ok_or_else(out_of_range)?;
+        // Local time types in force around, from the instant they apply
+        let mut segments = vec![(
+            start,
+            self.offset_at(start)
+                .ok_or_else(|| Error::new(ErrorKind::NoData, OPERATION).with_zone(self.name()))?,
+        )];
+        segments.extend(self.changes_between(start, end).iter().map(|tc| (tc.time, ZoneOffset::from(tc))));
+        let mut valid: Vec<ZoneOffset> = Vec::new();
+        let mut gap = None;
+        for (i, (from, offset)) in segments.iter().enumerate() {
+            let until = segments.get(i + 1).map_or(end, |s| s.0);
+            let instant = utc_of(local, offset.gmtoff).ok_or_else(out_of_range)?;
+            if *from <= instant && instant < until {
+                valid.push(offset.clone());
+            } else if instant >= until && gap.is_none() {
+                // Past this segment: skipped if before the next one
+                if let Some((next_from, next)) = segments.get(i + 1) {
+                    if matches!(utc_of(local, next.gmtoff), Some(t) if t < *next_from) {
+                        gap = Some((offset.clone(), next.clone()));
+                    }
+                }
+            }
+        }
+        match (valid.as_slice(), gap) {
+            ([offset], _) => Ok(LocalResolution::Unique(offset.clone())),
+            ([earlier, .., later], _) => Ok(LocalResolution::Ambiguous {
+                earlier: earlier.clone(),
+                later: later.clone(),
+            }),
+            ([], Some((before, after))) => Ok(LocalResolution::Gap { before, after }),
+            ([], None) => Err(out_of_range()),
+        }
+    }
+
+    /// Returns the gap or fold a local time falls in, None if it occurs exactly once.
+    pub fn discontinuity(&self, local: NaiveDateTime) -> Result<Option<Discontinuity>, Error> {
+        const OPERATION: &str = "finding discontinuity";
+        let out_of_range = || Error::new(ErrorKind::OutOfRange, OPERATION).with_zone(self.name());
+        let start = utc_of(local, 2 * 86400).ok_or_else(out_of_range)?;
+        let end = utc_of(local, -2 * 86400).ok_or_else(out_of_range)?;
+        let mut previous = self
+            .offset_at(start)
+            .ok_or_else(|| Error::new(ErrorKind::NoData, OPERATION).with_zone(self.name()))?
+            .gmtoff;
+        for tc in self.changes_between(start, end) {
+            let wall = |gmtoff: isize| {
+                i64::try_from(gmtoff)
+                    .ok()
+                    .and_then(|o| tc.time.naive_utc().checked_add_signed(Duration::seconds(o)))
+                    .ok_or_else(out_of_range)
+            };
+            let (before, after) = (wall(previous)?, wall(tc.gmtoff)?);
+            previous = tc.gmtoff;
+            let (kind, start, end) = if before < after {
+                (DiscontinuityKind::Gap, before, after)
+            } else {
+                (DiscontinuityKind::Fold, after, before)
+            };
+            if start <= local && local < end {
+                return Ok(Some(Discontinuity { kind, start, end, time: tc.time }));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Converts a local time of the zone to UTC, picking the instant of a local time in a fold or a gap with the policy.
+    pub fn to_utc(&self, local: NaiveDateTime, policy: Disambiguation) -> Result<DateTime<Utc>, Error> {
+        const OPERATION: &str = "converting local time";
+        let gmtoff = match self.resolve_local(local)? {
+            LocalResolution::Unique(offset) => offset.gmtoff,
+            LocalResolution::Ambiguous { earlier, later } => match policy {
+                Disambiguation::Compatible | Disambiguation::Earlier => earlier.gmtoff,
+                Disambiguation::Later => later.gmtoff,
+                Disambiguation::Reject => {
+                    return Err(Error::new(ErrorKind::AmbiguousLocalTime, OPERATION).with_zone(self.name()))
+                }
+            },
+            // Read with the offset before the gap, a skipped local time lands after it, and conversely
+            LocalResolution::Gap { before, after } => match policy {
+                Disambiguation::Compatible | Disambiguation::Later => before.gmtoff,
+                Disambiguation::Earlier => after.gmtoff,
+                Disambiguation::Reject => {
+                    return Err(Error::new(ErrorKind::NonexistentLocalTime, OPERATION).with_zone(self.name()))
+                }
+            },
+        };
+        utc_of(local, gmtoff).ok_or_else(|| Error::new(ErrorKind::OutOfRange, OPERATION).with_zone(self.name()))
+    }
+
+    /// Iterates over the wall-clock hours of a local day: the hour skipped when clocks spring forward is missing,
+    /// the hour repeated when they go back appears twice, the second time marked as a fold.
+    pub fn local_hours(&self, date: NaiveDate) -> Result<LocalHours<'_>, Error> {
+        let day = day_bounds_utc(self, date)?;
+        Ok(LocalHours {
+            zone: self,
+            next: day.start,
+            end: day.end,
+            seen: Vec::new(),
+        })
+    }
+
+    /// Returns the first instant after `after` at which clocks of the zone show `time`, as a DST-safe scheduler would fire.
+    /// A wall time skipped or repeated by a timechange is resolved with the policy; with Disambiguation::Reject,
+    /// it does not occur on that day.
+    pub fn next_occurrence(
+        &self,
+        time: NaiveTime,
+        after: DateTime<Utc>,
+        policy: Disambiguation,
+    ) -> Result<DateTime<Utc>, Error> {
+        let out_of_range = || Error::new(ErrorKind::OutOfRange, "computing next occurrence").with_zone(self.name());
+        // Starting the day before, in case the policy moves an occurrence across midnight
+        let mut date = self.to_local(after)?.naive_local().date().pred_opt().ok_or_else(out_of_range)?;
+        for _ in 0..7 {
+            match self.to_utc(date.and_time(time), policy) {
+                Ok(instant) if instant > after => return Ok(instant),
+                Err(e) if !matches!(e.kind(), ErrorKind::AmbiguousLocalTime | ErrorKind::NonexistentLocalTime) => {
+                    return Err(e)
+                }
+                _ => {}
+            }
+            date = date.succ_opt().ok_or_else(out_of_range)?;
+        }
+        Err(out_of_range())
+    }
+
+    /// Adds calendar days to an instant while keeping its wall-clock time, ie. 09:00 the day before a timechange
+    /// plus one day is 09:00 again, 23 or 25 hours later. A local time skipped or repeated is resolved with the policy.
+    pub fn add_days(&self, instant: DateTime<Utc>, days: i64, policy: Disambiguation) -> Result<DateTime<Utc>, Error> {
+        let local = self.to_local(instant)?.naive_local();
+        // Far beyond chrono's range, checked so that Duration never overflows (abs of i64::MIN overflows too)
+        if !matches!(days.checked_abs(), Some(abs) if abs <= 1 << 32) {
+            return Err(self.calendar_error());
+        }
+        let shifted = local
+            .checked_add_signed(Duration::days(days))
+            .ok_or_else(|| self.calendar_error())?;
+        self.to_utc(shifted, policy)
+    }
+
+    /// Adds calendar months to an instant while keeping its wall-clock time. The day is clamped to the end of
+    /// shorter months, ie. January 31st plus one month is February 28th or 29th.
+    pub fn add_months(
+        &self,
+        instant: DateTime<Utc>,
+        months: i32,
+        policy: Disambiguation,
+    ) -> Result<DateTime<Utc>, Error> {
+        let local = self.to_local(instant)?.naive_local();
+        let index = i64::from(local.year()) * 12 + i64::from(local.month0()) + i64::from(months);
+        let year = i32::try_from(index.div_euclid(12)).map_err(|_| self.calendar_error())?;
+        let month = index.rem_euclid(12) as u32 + 1;
+        // Last valid day of the target month, down from the local one
+        let date = (28..=local.day())
+            .rev()
+            .find_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+            .or_else(|| NaiveDate::from_ymd_opt(year, month, local.day()))
+            .ok_or_else(|| self.calendar_error())?;
+        self.to_utc(date.and_time(local.time()), policy)
+    }
+
+    fn calendar_error(&self) -> Error {
+        Error::new(ErrorKind::OutOfRange, "adding calendar period").with_zone(self.name())
+    }
+
+    /// Converts an instant to the zone's local time.
+    pub fn to_local(&self, instant: DateTime<Utc>) -> Result<DateTime<FixedOffset>, Error> {
+        const OPERATION: &str = "converting to local time";
+        let offset = self
+            .offset_at(instant)
+            .ok_or_else(|| Error::new(ErrorKind::NoData, OPERATION).with_zone(self.name()))?;
+        let fixed = i32::try_from(offset.gmtoff)
+            .ok()
+            .and_then(FixedOffset::east_opt)
+            .ok_or_else(|| Error::new(ErrorKind::OutOfRange, OPERATION).with_zone(self.name()))?;
+        Ok(instant.with_timezone(&fixed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TzDatabase;
+    #[test]
+    fn convert() {
+        let db = TzDatabase::open("/usr/share/zoneinfo");
+        let paris = db.zone("Europe/Paris").unwrap();
+        let new_york = db.zone("America/New_York").unwrap();
+        let local = |d, h, m| NaiveDate::from_ymd(2019, 3, d).and_hms(h, m, 0);
+        let converted = convert_between(local(31, 12, 0), &paris, &new_york, Disambiguation::Reject).unwrap();
+        assert_eq!(converted.to_rfc3339(), "2019-03-31T06:00:00-04:00");
+        // 02:30 does not exist in Paris on March 31st
+        let gap = local(31, 2, 30);
+        assert_eq!(paris.to_utc(gap, Disambiguation::Compatible).unwrap(), Utc.ymd(2019, 3, 31).and_hms(1, 30, 0));
+        assert_eq!(paris.to_utc(gap, Disambiguation::Earlier).unwrap(), Utc.ymd(2019, 3, 31).and_hms(0, 30, 0));
+        let err = paris.to_utc(gap, Disambiguation::Reject).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NonexistentLocalTime);
+        // 02:30 occurs twice on October 27th
+        let fold = NaiveDate::from_ymd(2019, 10, 27).and_hms(2, 30, 0);
+        assert_eq!(paris.to_utc(fold, Disambiguation::Earlier).unwrap(), Utc.ymd(2019, 10, 27).and_hms(0, 30, 0));
+        assert_eq!(paris.to_utc(fold, Disambiguation::Later).unwrap(), Utc.ymd(2019, 10, 27).and_hms(1, 30, 0));
+        let instant = Utc.ymd(2019, 10, 27).and_hms(1, 30, 0);
+        let converted = convert_between(instant, &new_york, &paris, Disambiguation::Reject).unwrap();
+        assert_eq!(converted.to_rfc3339(), "2019-10-27T02:30:00+01:00");
+    }
+
+    #[test]
+    fn flight() {
+        let db = TzDatabase::open("/usr/share/zoneinfo");
+        let paris = db.zone("Europe/Paris").unwrap();
+        let new_york = db.zone("America/New_York").unwrap();
+        let departure = NaiveDate::from_ymd(2019, 7, 1).and_hms(10, 0, 0);
+        let arrival = NaiveDate::from_ymd(2019, 7, 1).and_hms(12, 30, 0);
+        let duration = elapsed(departure, &paris, arrival, &new_york, Disambiguation::Reject).unwrap();
+        assert_eq!(duration, Duration::minutes(8 * 60 + 30));
+        let back = elapsed(arrival, &new_york, departure, &paris, Disambiguation::Reject).unwrap();
+        assert_eq!(back, -duration);
+    }
+
+    #[test]
+    fn wall() {
+        let paris = TzDatabase::open("/usr/share/zoneinfo").zone("Europe/Paris").unwrap();
+        let hours = |m, d| {
+            let day = NaiveDate::from_ymd(2019, m, d);
+            wall_duration(&paris, day.and_hms(0, 0, 0), day.and_hms(4, 0, 0)).unwrap()
+        };
+        assert_eq!(hours(3, 31), Duration::hours(3));
+        assert_eq!(hours(7, 14), Duration::hours(4));
+        assert_eq!(hours(10, 27), Duration::hours(5));
+    }
+
+    #[test]
+    fn day_bounds() {
+        let paris = TzDatabase::open("/usr/share/zoneinfo").zone("Europe/Paris").unwrap();
+        let day = day_bounds_utc(&paris, NaiveDate::from_ymd(2019, 3, 31)).unwrap();
+        assert_eq!(day.start, Utc.ymd(2019, 3, 30).and_hms(23, 0, 0));
+        assert_eq!(day.end, Utc.ymd(2019, 3, 31).and_hms(22, 0, 0));
+        // Clocks went from midnight to 1 AM in Sao Paulo on November 4th, 2018
+        let sao_paulo = TzDatabase::open("/usr/share/zoneinfo").zone("America/Sao_Paulo").unwrap();
+        let day = day_bounds_utc(&sao_paulo, NaiveDate::from_ymd(2018, 11, 4)).unwrap();
+        assert_eq!(day.start, Utc.ymd(2018, 11, 4).and_hms(3, 0, 0));
+        assert_eq!(day.end - day.start, Duration::hours(23));
+    }
+
+    #[test]
+    fn day_length() {
+        let db = TzDatabase::open("/usr/share/zoneinfo");
+        let paris = db.zone("Europe/Paris").unwrap();
+        let hours = |zone, m, d| hours_in_day(zone, NaiveDate::from_ymd(2019, m, d)).unwrap();
+        assert_eq!(hours(&paris, 3, 31), 23.0);
+        assert_eq!(hours(&paris, 7, 14), 24.0);
+        assert_eq!(hours(&paris, 10, 27), 25.0);
+        assert_eq!(hours(&db.zone("Australia/Lord_Howe").unwrap(), 4, 7), 24.5);
+    }
+
+    #[test]
+    fn hours() {
+        let paris = TzDatabase::open("/usr/share/zoneinfo").zone("Europe/Paris").unwrap();
+        let hours = |m, d| paris.local_hours(NaiveDate::from_ymd(2019, m, d)).unwrap().collect::<Vec<_>>();
+        let spring = hours(3, 31);
+        assert_eq!(spring.len(), 23);
+        assert!(spring.iter().all(|h| h.hour.hour() != 2 && !h.fold));
+        let autumn = hours(10, 27);
+        let marks: Vec<_> = autumn.iter().take(4).map(|h| (h.hour.hour(), h.fold)).collect();
+        assert_eq!(marks, vec![(0, false), (1, false), (2, false), (2, true)]);
+        assert_eq!(autumn[3].utc, Utc.ymd(2019, 10, 27).and_hms(1, 0, 0)..Utc.ymd(2019, 10, 27).and_hms(2, 0, 0));
+        assert_eq!(autumn.len(), 25);
+    }
+
+    #[test]
+    fn month_bounds() {
+        let paris = TzDatabase::open("/usr/share/zoneinfo").zone("Europe/Paris").unwrap();
+        let march = month_bounds_utc(&paris, 2019, 3).unwrap();
+        assert_eq!(march.start, Utc.ymd(2019, 2, 28).and_hms(23, 0, 0));
+        assert_eq!(march.end, Utc.ymd(2019, 3, 31).and_hms(22, 0, 0));
+        assert_eq!(month_bounds_utc(&paris, 2019, 12).unwrap().end, Utc.ymd(2019, 12, 31).and_hms(23, 0, 0));
+        assert_eq!(month_bounds_utc(&paris, 2019, 13).unwrap_err().kind(), ErrorKind::OutOfRange);
+    }
+
+    #[test]
+    fn next_occurrence() {
+        let paris = TzDatabase::open("/usr/share/zoneinfo").zone("Europe/Paris").unwrap();
+        let half_past_two = NaiveTime::from_hms(2, 30, 0);
+        let next = |after, policy| paris.next_occurrence(half_past_two, after, policy).unwrap();
+        let before_spring = Utc.ymd(2019, 3, 30).and_hms(12, 0, 0);
+        assert_eq!(next(before_spring, Disambiguation::Compatible), Utc.ymd(2019, 3, 31).and_hms(1, 30, 0));
+        assert_eq!(next(before_spring, Disambiguation::Reject), Utc.ymd(2019, 4, 1).and_hms(0, 30, 0));
+        let before_autumn = Utc.ymd(2019, 10, 26).and_hms(12, 0, 0);
+        assert_eq!(next(before_autumn, Disambiguation::Compatible), Utc.ymd(2019, 10, 27).and_hms(0, 30, 0));
+        assert_eq!(next(before_autumn, Disambiguation::Later), Utc.ymd(2019, 10, 27).and_hms(1, 30, 0));
+        let summer = Utc.ymd(2019, 7, 14).and_hms(0, 30, 0);
+        assert_eq!(next(summer, Disambiguation::Reject), Utc.ymd(2019, 7, 15).and_hms(0, 30, 0));
+    }
+
+    #[test]
+    fn calendar() {
+        let paris = TzDatabase::open("/usr/share/zoneinfo").zone("Europe/Paris").unwrap();
+        let nine = Utc.ymd(2019, 3, 30).and_hms(8, 0, 0);
+        let tomorrow = paris.add_days(nine, 1, Disambiguation::Compatible).unwrap();
+        assert_eq!(tomorrow, Utc.ymd(2019, 3, 31).and_hms(7, 0, 0));
+        assert_eq!(tomorrow - nine, Duration::hours(23));
+        let last_week = paris.add_days(tomorrow, -7, Disambiguation::Compatible).unwrap();
+        assert_eq!(last_week, Utc.ymd(2019, 3, 24).and_hms(8, 0, 0));
+        for days in &[i64::MIN, i64::MAX] {
+            let error = paris.add_days(nine, *days, Disambiguation::Compatible).unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::OutOfRange);
+        }
+        let end_of_january = Utc.ymd(2020, 1, 31).and_hms(8, 0, 0);
+        let month = |n| paris.add_months(end_of_january, n, Disambiguation::Compatible).unwrap();
+        assert_eq!(month(1), Utc.ymd(2020, 2, 29).and_hms(8, 0, 0));
+        assert_eq!(month(3), Utc.ymd(2020, 4, 30).and_hms(7, 0, 0));
+        assert_eq!(month(-2), Utc.ymd(2019, 11, 30).and_hms(8, 0, 0));
+    }
+
+    #[test]
+    fn predicates() {
+        let paris = TzDatabase::open("/usr/share/zoneinfo").zone("Europe/Paris").unwrap();
+        let local = |m, d, h| NaiveDate::from_ymd(2019, m, d).and_hms(h, 30, 0);
+        assert!(is_nonexistent(&paris, local(3, 31, 2)).unwrap());
+        assert!(!is_ambiguous(&paris, local(3, 31, 2)).unwrap());
+        assert!(is_ambiguous(&paris, local(10, 27, 2)).unwrap());
+        assert!(!is_nonexistent(&paris, local(10, 27, 2)).unwrap());
+        assert!(!is_ambiguous(&paris, local(10, 27, 3)).unwrap());
+    }
+
+    #[test]
+    fn discontinuity() {
+        let paris = TzDatabase::open("/usr/share/zoneinfo").zone("Europe/Paris").unwrap();
+        let local = |m, d, h| NaiveDate::from_ymd(2019, m, d).and_hms(h, 30, 0);
+        let gap = paris.discontinuity(local(3, 31, 2)).unwrap().unwrap();
+        assert_eq!(gap.kind, DiscontinuityKind::Gap);
+        assert_eq!(gap.start, NaiveDate::from_ymd(2019, 3, 31).and_hms(2, 0, 0));
+        assert_eq!(gap.end, NaiveDate::from_ymd(2019, 3, 31).and_hms(3, 0, 0));
+        assert_eq!(gap.time, Utc.ymd(2019, 3, 31).and_hms(1, 0, 0));
+        assert_eq!(gap.length(), Duration::hours(1));
+        let fold = paris.discontinuity(local(10, 27, 2)).unwrap().unwrap();
+        assert_eq!(fold.kind, DiscontinuityKind::Fold);
+        assert_eq!(fold.start, NaiveDate::from_ymd(2019, 10, 27).and_hms(2, 0, 0));
+        assert!(paris.discontinuity(local(10, 27, 3)).unwrap().is_none());
+    }
+
+    #[test]
+    fn resolution() {
+        let paris = TzDatabase::open("/usr/share/zoneinfo").zone("Europe/Paris").unwrap();
+        let local = |m, d| NaiveDate::from_ymd(2019, m, d).and_hms(2, 30, 0);
+        match paris.resolve_local(local(10, 27)).unwrap() {
+            LocalResolution::Ambiguous { earlier, later } => {
+                assert_eq!((earlier.abbreviation.as_str(), later.abbreviation.as_str()), ("CEST", "CET"))
+            }
+            resolution => panic!("{:?}", resolution),
+        }
+        let gap = paris.resolve_local(local(3, 31)).unwrap();
+        assert!(matches!(&gap, LocalResolution::Gap { before, after } if before.gmtoff == 3600 && after.gmtoff == 7200));
+        assert!(gap.earliest().is_none());
+        let unique = paris.resolve_local(local(7, 14)).unwrap();
+        assert_eq!(unique.single().unwrap().abbreviation, "CEST");
+        assert_eq!(unique.earliest(), unique.latest());
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{get_zoneinfo_at, zoneinfo, Clock, Error, ErrorKind, PosixTz, Resolver, SystemClock, Timechange, Tzinfo};
+use chrono::prelude::*;
+use std::{env, fs, path::Path};
+
+/// Where the local timezone is defined.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LocalZone {
+    /// A tzfile
+    File(String),
+    /// A POSIX TZ rule string, with no tzfile
+    Posix(PosixTz),
+}
+
+/// Detects the local timezone, looking in order at:
+/// - the TZ environment variable, holding a zone name, a POSIX TZ string, or a tzfile path after a colon (ie. ":/etc/mytz"),
+/// - /etc/TZ, holding a POSIX TZ string (musl and BusyBox systems),
+/// - /etc/localtime.
+pub fn local_zone() -> Result<LocalZone, Error> {
+    if let Ok(tz) = env::var("TZ") {
+        if let Some(zone) = from_tz_variable(&tz) {
+            debug!("local zone from TZ={}: {:?}", tz, zone);
+            return zone;
+        }
+    }
+    if let Ok(tz) = fs::read_to_string("/etc/TZ") {
+        if let Ok(posix) = tz.trim().parse() {
+            debug!("local zone from /etc/TZ: {}", tz.trim());
+            return Ok(LocalZone::Posix(posix));
+        }
+    }
+    // /etc/localtime is usually a symlink into the zoneinfo tree, which gives the zone its name
+    match fs::canonicalize("/etc/localtime") {
+        Ok(path) => {
+            debug!("local zone from /etc/localtime: {}", path.display());
+            Ok(LocalZone::File(path.to_string_lossy().into_owned()))
+        }
+        Err(e) => Err(Error::io(e, "detecting local zone").with_zone("/etc/localtime")),
+    }
+}
+
+/// Interprets a TZ environment variable value; None if it does not designate a zone.
+fn from_tz_variable(tz: &str) -> Option<Result<LocalZone, Error>> {
+    if tz.is_empty() {
+        return None;
+    }
+    // POSIX: ":characters", resolved like glibc does, as a path if absolute or else a zone name
+    if let Some(file) = tz.strip_prefix(':') {
+        if file.is_empty() {
+            return None;
+        }
+        if Path::new(file).is_absolute() {
+            return Some(Ok(LocalZone::File(file.to_string())));
+        }
+        return Some(Resolver::system().resolve(file).map(LocalZone::File));
+    }
+    if let Ok(path) = Resolver::system().resolve(tz) {
+        return Some(Ok(LocalZone::File(path)));
+    }
+    Some(tz.parse().map(LocalZone::Posix))
+}
+
+/// Returns convenient data about the local timezone for current date and time.
+/// For a POSIX TZ string, the timezone name is the string itself.
+pub fn get_local_zoneinfo() -> Result<Tzinfo, Error> {
+    get_local_zoneinfo_with_clock(&SystemClock)
+}
+
+/// Same as get_local_zoneinfo, with the current date and time given by a clock.
+pub fn get_local_zoneinfo_with_clock<C: Clock + ?Sized>(clock: &C) -> Result<Tzinfo, Error> {
+    get_local_zoneinfo_at(clock.now())
+}
+
+/// Returns convenient data about the local timezone at the `now` instant.
+pub fn get_local_zoneinfo_at(now: DateTime<Utc>) -> Result<Tzinfo, Error> {
+    match local_zone()? {
+        LocalZone::File(path) => get_zoneinfo_at(&path, now),
+        LocalZone::Posix(posix) => {
+            let year = now.year();
+            let mut timechanges = posix.timechanges(year);
+            if timechanges.is_empty() {
+                let start = NaiveDate::from_yo_opt(year, 1)
+                    .and_then(|d| d.and_hms_opt(0, 0, 0))
+                    .ok_or_else(|| Error::new(ErrorKind::NoData, "computing zoneinfo"))?;
+                timechanges.push(Timechange {
+                    time: Utc.from_utc_datetime(&start),
+                    gmtoff: posix.std_offset,
+                    isdst: false,
+                    abbreviation: posix.std_abbreviation.clone(),
+                });
+            }
+            zoneinfo(posix.to_string(), timechanges, now)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn tz_variable() {
+        assert!(from_tz_variable("").is_none());
+        assert!(from_tz_variable(":").is_none());
+        assert_eq!(
+            from_tz_variable(":/usr/share/zoneinfo/Europe/Paris").unwrap().unwrap(),
+            LocalZone::File(String::from("/usr/share/zoneinfo/Europe/Paris"))
+        );
+        match from_tz_variable(":Europe/Paris").unwrap().unwrap() {
+            LocalZone::File(path) => assert!(path.ends_with("Europe/Paris")),
+            zone => panic!("{:?}", zone),
+        }
+        match from_tz_variable("CET-1CEST,M3.5.0,M10.5.0/3").unwrap().unwrap() {
+            LocalZone::Posix(posix) => assert_eq!(posix.std_offset, 3600),
+            zone => panic!("{:?}", zone),
+        }
+        assert!(from_tz_variable(":Europe/Nowhere").unwrap().is_err());
+    }
+}
@@ -0,0 +1,51 @@
+use crate::Zone;
+
+/// Approximate heap memory held by a zone, for memory budgets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MemoryUsage {
+    /// Timechanges held
+    pub transitions: usize,
+    /// Bytes of the abbreviation strings
+    pub abbreviation_bytes: usize,
+    /// Memoized timechanges held (see Zone::tzinfo)
+    pub cache_entries: usize,
+    /// Total heap bytes, abbreviations included
+    pub heap_bytes: usize,
+}
+
+impl Zone {
+    /// Returns the approximate heap memory held by the zone. The size of the Zone itself is not counted.
+    /// A TzDatabase loads zones on demand and keeps none: the zones an application holds are its whole budget.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let abbreviation_bytes: usize = self.timechanges().iter().map(|tc| tc.abbreviation.capacity()).sum::<usize>()
+            + self.initial().map_or(0, |initial| initial.abbreviation.capacity());
+        MemoryUsage {
+            transitions: self.timechanges().len(),
+            abbreviation_bytes,
+            cache_entries: self.cached_timechanges(),
+            heap_bytes: self.heap_bytes(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Timechange, TzDatabase};
+    use chrono::prelude::*;
+    use std::mem;
+    #[test]
+    fn memory_usage() {
+        let db = TzDatabase::open("/usr/share/zoneinfo");
+        let paris = db.zone("Europe/Paris").unwrap();
+        let usage = paris.memory_usage();
+        assert_eq!(usage.transitions, paris.timechanges().len());
+        assert!(usage.abbreviation_bytes >= 3 * usage.transitions);
+        assert!(usage.heap_bytes > usage.transitions * mem::size_of::<Timechange>());
+        assert!(db.zone("Etc/UTC").unwrap().memory_usage().heap_bytes < usage.heap_bytes);
+        paris.tzinfo(Utc.ymd(2019, 7, 14).and_hms(12, 0, 0)).unwrap();
+        let cached = paris.memory_usage();
+        assert_eq!(cached.cache_entries, 2);
+        // The memoized timechanges and their abbreviations ("CEST", "CET")
+        assert!(cached.heap_bytes >= usage.heap_bytes + 2 * mem::size_of::<Timechange>() + 7);
+    }
+}
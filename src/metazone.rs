@@ -0,0 +1,586 @@
+use crate::canonical_zone;
+use chrono::prelude::*;
+
+/// Current CLDR metazone of each zone, sorted by zone.
+const METAZONES: &[(&str, &str)] = &[
+    ("Africa/Abidjan", "GMT"),
+    ("Africa/Accra", "GMT"),
+    ("Africa/Addis_Ababa", "Africa_Eastern"),
+    ("Africa/Algiers", "Europe_Central"),
+    ("Africa/Asmara", "Africa_Eastern"),
+    ("Africa/Asmera", "Africa_Eastern"),
+    ("Africa/Bamako", "GMT"),
+    ("Africa/Bangui", "Africa_Western"),
+    ("Africa/Banjul", "GMT"),
+    ("Africa/Bissau", "GMT"),
+    ("Africa/Blantyre", "Africa_Central"),
+    ("Africa/Brazzaville", "Africa_Western"),
+    ("Africa/Bujumbura", "Africa_Central"),
+    ("Africa/Cairo", "Europe_Eastern"),
+    ("Africa/Ceuta", "Europe_Central"),
+    ("Africa/Conakry", "GMT"),
+    ("Africa/Dakar", "GMT"),
+    ("Africa/Dar_es_Salaam", "Africa_Eastern"),
+    ("Africa/Djibouti", "Africa_Eastern"),
+    ("Africa/Douala", "Africa_Western"),
+    ("Africa/Freetown", "GMT"),
+    ("Africa/Gaborone", "Africa_Central"),
+    ("Africa/Harare", "Africa_Central"),
+    ("Africa/Johannesburg", "Africa_Southern"),
+    ("Africa/Juba", "Africa_Central"),
+    ("Africa/Kampala", "Africa_Eastern"),
+    ("Africa/Khartoum", "Africa_Central"),
+    ("Africa/Kigali", "Africa_Central"),
+    ("Africa/Kinshasa", "Africa_Western"),
+    ("Africa/Lagos", "Africa_Western"),
+    ("Africa/Libreville", "Africa_Western"),
+    ("Africa/Lome", "GMT"),
+    ("Africa/Luanda", "Africa_Western"),
+    ("Africa/Lubumbashi", "Africa_Central"),
+    ("Africa/Lusaka", "Africa_Central"),
+    ("Africa/Malabo", "Africa_Western"),
+    ("Africa/Maputo", "Africa_Central"),
+    ("Africa/Maseru", "Africa_Southern"),
+    ("Africa/Mbabane", "Africa_Southern"),
+    ("Africa/Mogadishu", "Africa_Eastern"),
+    ("Africa/Monrovia", "GMT"),
+    ("Africa/Nairobi", "Africa_Eastern"),
+    ("Africa/Ndjamena", "Africa_Western"),
+    ("Africa/Niamey", "Africa_Western"),
+    ("Africa/Nouakchott", "GMT"),
+    ("Africa/Ouagadougou", "GMT"),
+    ("Africa/Porto-Novo", "Africa_Western"),
+    ("Africa/Sao_Tome", "GMT"),
+    ("Africa/Timbuktu", "GMT"),
+    ("Africa/Tripoli", "Europe_Eastern"),
+    ("Africa/Tunis", "Europe_Central"),
+    ("Africa/Windhoek", "Africa_Central"),
+    ("America/Adak", "Hawaii_Aleutian"),
+    ("America/Anchorage", "Alaska"),
+    ("America/Anguilla", "Atlantic"),
+    ("America/Antigua", "Atlantic"),
+    ("America/Araguaina", "Brasilia"),
+    ("America/Argentina/Buenos_Aires", "Argentina"),
+    ("America/Argentina/Catamarca", "Argentina"),
+    ("America/Argentina/ComodRivadavia", "Argentina"),
+    ("America/Argentina/Cordoba", "Argentina"),
+    ("America/Argentina/Jujuy", "Argentina"),
+    ("America/Argentina/La_Rioja", "Argentina"),
+    ("America/Argentina/Mendoza", "Argentina"),
+    ("America/Argentina/Rio_Gallegos", "Argentina"),
+    ("America/Argentina/Salta", "Argentina"),
+    ("America/Argentina/San_Juan", "Argentina"),
+    ("America/Argentina/San_Luis", "Argentina"),
+    ("America/Argentina/Tucuman", "Argentina"),
+    ("America/Argentina/Ushuaia", "Argentina"),
+    ("America/Aruba", "Atlantic"),
+    ("America/Asuncion", "Paraguay"),
+    ("America/Atikokan", "America_Eastern"),
+    ("America/Atka", "Hawaii_Aleutian"),
+    ("America/Bahia", "Brasilia"),
+    ("America/Bahia_Banderas", "America_Central"),
+    ("America/Barbados", "Atlantic"),
+    ("America/Belem", "Brasilia"),
+    ("America/Belize", "America_Central"),
+    ("America/Blanc-Sablon", "Atlantic"),
+    ("America/Boa_Vista", "Amazon"),
+    ("America/Bogota", "Colombia"),
+    ("America/Boise", "America_Mountain"),
+    ("America/Buenos_Aires", "Argentina"),
+    ("America/Cambridge_Bay", "America_Mountain"),
+    ("America/Campo_Grande", "Amazon"),
+    ("America/Cancun", "America_Eastern"),
+    ("America/Caracas", "Venezuela"),
+    ("America/Catamarca", "Argentina"),
+    ("America/Cayenne", "French_Guiana"),
+    ("America/Cayman", "America_Eastern"),
+    ("America/Chicago", "America_Central"),
+    ("America/Chihuahua", "America_Central"),
+    ("America/Ciudad_Juarez", "America_Mountain"),
+    ("America/Coral_Harbour", "America_Eastern"),
+    ("America/Cordoba", "Argentina"),
+    ("America/Costa_Rica", "America_Central"),
+    ("America/Creston", "America_Mountain"),
+    ("America/Cuiaba", "Amazon"),
+    ("America/Curacao", "Atlantic"),
+    ("America/Danmarkshavn", "GMT"),
+    ("America/Dawson", "America_Mountain"),
+    ("America/Dawson_Creek", "America_Mountain"),
+    ("America/Denver", "America_Mountain"),
+    ("America/Detroit", "America_Eastern"),
+    ("America/Dominica", "Atlantic"),
+    ("America/Edmonton", "America_Mountain"),
+    ("America/Eirunepe", "Acre"),
+    ("America/El_Salvador", "America_Central"),
+    ("America/Ensenada", "America_Pacific"),
+    ("America/Fort_Nelson", "America_Mountain"),
+    ("America/Fort_Wayne", "America_Eastern"),
+    ("America/Fortaleza", "Brasilia"),
+    ("America/Glace_Bay", "Atlantic"),
+    ("America/Godthab", "Greenland_Western"),
+    ("America/Goose_Bay", "Atlantic"),
+    ("America/Grand_Turk", "America_Eastern"),
+    ("America/Grenada", "Atlantic"),
+    ("America/Guadeloupe", "Atlantic"),
+    ("America/Guatemala", "America_Central"),
+    ("America/Guayaquil", "Ecuador"),
+    ("America/Guyana", "Guyana"),
+    ("America/Halifax", "Atlantic"),
+    ("America/Havana", "Cuba"),
+    ("America/Hermosillo", "Mexico_Pacific"),
+    ("America/Indiana/Indianapolis", "America_Eastern"),
+    ("America/Indiana/Knox", "America_Central"),
+    ("America/Indiana/Marengo", "America_Eastern"),
+    ("America/Indiana/Petersburg", "America_Eastern"),
+    ("America/Indiana/Tell_City", "America_Central"),
+    ("America/Indiana/Vevay", "America_Eastern"),
+    ("America/Indiana/Vincennes", "America_Eastern"),
+    ("America/Indiana/Winamac", "America_Eastern"),
+    ("America/Indianapolis", "America_Eastern"),
+    ("America/Inuvik", "America_Mountain"),
+    ("America/Iqaluit", "America_Eastern"),
+    ("America/Jamaica", "America_Eastern"),
+    ("America/Jujuy", "Argentina"),
+    ("America/Juneau", "Alaska"),
+    ("America/Kentucky/Louisville", "America_Eastern"),
+    ("America/Kentucky/Monticello", "America_Eastern"),
+    ("America/Knox_IN", "America_Central"),
+    ("America/Kralendijk", "Atlantic"),
+    ("America/La_Paz", "Bolivia"),
+    ("America/Lima", "Peru"),
+    ("America/Los_Angeles", "America_Pacific"),
+    ("America/Louisville", "America_Eastern"),
+    ("America/Lower_Princes", "Atlantic"),
+    ("America/Maceio", "Brasilia"),
+    ("America/Managua", "America_Central"),
+    ("America/Manaus", "Amazon"),
+    ("America/Marigot", "Atlantic"),
+    ("America/Martinique", "Atlantic"),
+    ("America/Matamoros", "America_Central"),
+    ("America/Mazatlan", "Mexico_Pacific"),
+    ("America/Mendoza", "Argentina"),
+    ("America/Menominee", "America_Central"),
+    ("America/Merida", "America_Central"),
+    ("America/Metlakatla", "Alaska"),
+    ("America/Mexico_City", "America_Central"),
+    ("America/Miquelon", "Pierre_Miquelon"),
+    ("America/Moncton", "Atlantic"),
+    ("America/Monterrey", "America_Central"),
+    ("America/Montevideo", "Uruguay"),
+    ("America/Montserrat", "Atlantic"),
+    ("America/Nassau", "America_Eastern"),
+    ("America/New_York", "America_Eastern"),
+    ("America/Nipigon", "America_Eastern"),
+    ("America/Nome", "Alaska"),
+    ("America/Noronha", "Noronha"),
+    ("America/North_Dakota/Beulah", "America_Central"),
+    ("America/North_Dakota/Center", "America_Central"),
+    ("America/North_Dakota/New_Salem", "America_Central"),
+    ("America/Nuuk", "Greenland_Western"),
+    ("America/Ojinaga", "America_Central"),
+    ("America/Panama", "America_Eastern"),
+    ("America/Pangnirtung", "America_Eastern"),
+    ("America/Paramaribo", "Suriname"),
+    ("America/Phoenix", "America_Mountain"),
+    ("America/Port-au-Prince", "America_Eastern"),
+    ("America/Port_of_Spain", "Atlantic"),
+    ("America/Porto_Acre", "Acre"),
+    ("America/Porto_Velho", "Amazon"),
+    ("America/Puerto_Rico", "Atlantic"),
+    ("America/Rainy_River", "America_Central"),
+    ("America/Rankin_Inlet", "America_Central"),
+    ("America/Recife", "Brasilia"),
+    ("America/Regina", "America_Central"),
+    ("America/Resolute", "America_Central"),
+    ("America/Rio_Branco", "Acre"),
+    ("America/Rosario", "Argentina"),
+    ("America/Santa_Isabel", "Mexico_Northwest"),
+    ("America/Santarem", "Brasilia"),
+    ("America/Santiago", "Chile"),
+    ("America/Santo_Domingo", "Atlantic"),
+    ("America/Sao_Paulo", "Brasilia"),
+    ("America/Scoresbysund", "Greenland_Eastern"),
+    ("America/Shiprock", "America_Mountain"),
+    ("America/Sitka", "Alaska"),
+    ("America/St_Barthelemy", "Atlantic"),
+    ("America/St_Johns", "Newfoundland"),
+    ("America/St_Kitts", "Atlantic"),
+    ("America/St_Lucia", "Atlantic"),
+    ("America/St_Thomas", "Atlantic"),
+    ("America/St_Vincent", "Atlantic"),
+    ("America/Swift_Current", "America_Central"),
+    ("America/Tegucigalpa", "America_Central"),
+    ("America/Thule", "Atlantic"),
+    ("America/Thunder_Bay", "America_Eastern"),
+    ("America/Tijuana", "America_Pacific"),
+    ("America/Toronto", "America_Eastern"),
+    ("America/Tortola", "Atlantic"),
+    ("America/Vancouver", "America_Pacific"),
+    ("America/Virgin", "Atlantic"),
+    ("America/Whitehorse", "America_Mountain"),
+    ("America/Winnipeg", "America_Central"),
+    ("America/Yakutat", "Alaska"),
+    ("America/Yellowknife", "America_Mountain"),
+    ("Antarctica/Casey", "Casey"),
+    ("Antarctica/Davis", "Davis"),
+    ("Antarctica/DumontDUrville", "DumontDUrville"),
+    ("Antarctica/Macquarie", "Australia_Eastern"),
+    ("Antarctica/Mawson", "Mawson"),
+    ("Antarctica/McMurdo", "New_Zealand"),
+    ("Antarctica/Rothera", "Rothera"),
+    ("Antarctica/South_Pole", "New_Zealand"),
+    ("Antarctica/Syowa", "Syowa"),
+    ("Antarctica/Troll", "GMT"),
+    ("Antarctica/Vostok", "Vostok"),
+    ("Arctic/Longyearbyen", "Europe_Central"),
+    ("Asia/Aden", "Arabian"),
+    ("Asia/Almaty", "Kazakhstan_Eastern"),
+    ("Asia/Amman", "Europe_Eastern"),
+    ("Asia/Anadyr", "Anadyr"),
+    ("Asia/Aqtau", "Kazakhstan_Western"),
+    ("Asia/Aqtobe", "Kazakhstan_Western"),
+    ("Asia/Ashgabat", "Turkmenistan"),
+    ("Asia/Ashkhabad", "Turkmenistan"),
+    ("Asia/Atyrau", "Kazakhstan_Western"),
+    ("Asia/Baghdad", "Arabian"),
+    ("Asia/Bahrain", "Arabian"),
+    ("Asia/Baku", "Azerbaijan"),
+    ("Asia/Bangkok", "Indochina"),
+    ("Asia/Beirut", "Europe_Eastern"),
+    ("Asia/Bishkek", "Kyrgystan"),
+    ("Asia/Brunei", "Brunei"),
+    ("Asia/Calcutta", "India"),
+    ("Asia/Chita", "Yakutsk"),
+    ("Asia/Choibalsan", "Mongolia"),
+    ("Asia/Chongqing", "China"),
+    ("Asia/Chungking", "China"),
+    ("Asia/Colombo", "India"),
+    ("Asia/Dacca", "Bangladesh"),
+    ("Asia/Damascus", "Europe_Eastern"),
+    ("Asia/Dhaka", "Bangladesh"),
+    ("Asia/Dili", "East_Timor"),
+    ("Asia/Dubai", "Gulf"),
+    ("Asia/Dushanbe", "Tajikistan"),
+    ("Asia/Gaza", "Europe_Eastern"),
+    ("Asia/Harbin", "China"),
+    ("Asia/Hebron", "Europe_Eastern"),
+    ("Asia/Ho_Chi_Minh", "Indochina"),
+    ("Asia/Hong_Kong", "Hong_Kong"),
+    ("Asia/Hovd", "Hovd"),
+    ("Asia/Irkutsk", "Irkutsk"),
+    ("Asia/Istanbul", "Turkey"),
+    ("Asia/Jakarta", "Indonesia_Western"),
+    ("Asia/Jayapura", "Indonesia_Eastern"),
+    ("Asia/Jerusalem", "Israel"),
+    ("Asia/Kabul", "Afghanistan"),
+    ("Asia/Kamchatka", "Kamchatka"),
+    ("Asia/Karachi", "Pakistan"),
+    ("Asia/Kashgar", "Urumqi"),
+    ("Asia/Kathmandu", "Nepal"),
+    ("Asia/Katmandu", "Nepal"),
+    ("Asia/Khandyga", "Yakutsk"),
+    ("Asia/Kolkata", "India"),
+    ("Asia/Krasnoyarsk", "Krasnoyarsk"),
+    ("Asia/Kuala_Lumpur", "Malaysia"),
+    ("Asia/Kuching", "Malaysia"),
+    ("Asia/Kuwait", "Arabian"),
+    ("Asia/Macao", "China"),
+    ("Asia/Macau", "China"),
+    ("Asia/Magadan", "Magadan"),
+    ("Asia/Makassar", "Indonesia_Central"),
+    ("Asia/Manila", "Philippines"),
+    ("Asia/Muscat", "Gulf"),
+    ("Asia/Nicosia", "Europe_Eastern"),
+    ("Asia/Novokuznetsk", "Krasnoyarsk"),
+    ("Asia/Novosibirsk", "Novosibirsk"),
+    ("Asia/Omsk", "Omsk"),
+    ("Asia/Oral", "Kazakhstan_Western"),
+    ("Asia/Phnom_Penh", "Indochina"),
+    ("Asia/Pontianak", "Indonesia_Western"),
+    ("Asia/Pyongyang", "Korea"),
+    ("Asia/Qatar", "Arabian"),
+    ("Asia/Qostanay", "Kazakhstan_Eastern"),
+    ("Asia/Qyzylorda", "Kazakhstan_Western"),
+    ("Asia/Rangoon", "Myanmar"),
+    ("Asia/Riyadh", "Arabian"),
+    ("Asia/Saigon", "Indochina"),
+    ("Asia/Sakhalin", "Sakhalin"),
+    ("Asia/Samarkand", "Uzbekistan"),
+    ("Asia/Seoul", "Korea"),
+    ("Asia/Shanghai", "China"),
+    ("Asia/Singapore", "Singapore"),
+    ("Asia/Taipei", "Taipei"),
+    ("Asia/Tashkent", "Uzbekistan"),
+    ("Asia/Tbilisi", "Georgia"),
+    ("Asia/Tehran", "Iran"),
+    ("Asia/Tel_Aviv", "Israel"),
+    ("Asia/Thimbu", "Bhutan"),
+    ("Asia/Thimphu", "Bhutan"),
+    ("Asia/Tokyo", "Japan"),
+    ("Asia/Ujung_Pandang", "Indonesia_Central"),
+    ("Asia/Ulaanbaatar", "Mongolia"),
+    ("Asia/Ulan_Bator", "Mongolia"),
+    ("Asia/Urumqi", "Urumqi"),
+    ("Asia/Ust-Nera", "Vladivostok"),
+    ("Asia/Vientiane", "Indochina"),
+    ("Asia/Vladivostok", "Vladivostok"),
+    ("Asia/Yakutsk", "Yakutsk"),
+    ("Asia/Yangon", "Myanmar"),
+    ("Asia/Yekaterinburg", "Yekaterinburg"),
+    ("Asia/Yerevan", "Armenia"),
+    ("Atlantic/Azores", "Azores"),
+    ("Atlantic/Bermuda", "Atlantic"),
+    ("Atlantic/Canary", "Europe_Western"),
+    ("Atlantic/Cape_Verde", "Cape_Verde"),
+    ("Atlantic/Faeroe", "Europe_Western"),
+    ("Atlantic/Faroe", "Europe_Western"),
+    ("Atlantic/Jan_Mayen", "Europe_Central"),
+    ("Atlantic/Madeira", "Europe_Western"),
+    ("Atlantic/Reykjavik", "GMT"),
+    ("Atlantic/South_Georgia", "South_Georgia"),
+    ("Atlantic/St_Helena", "GMT"),
+    ("Atlantic/Stanley", "Falkland"),
+    ("Australia/ACT", "Australia_Eastern"),
+    ("Australia/Adelaide", "Australia_Central"),
+    ("Australia/Brisbane", "Australia_Eastern"),
+    ("Australia/Broken_Hill", "Australia_Central"),
+    ("Australia/Canberra", "Australia_Eastern"),
+    ("Australia/Currie", "Australia_Eastern"),
+    ("Australia/Darwin", "Australia_Central"),
+    ("Australia/Eucla", "Australia_CentralWestern"),
+    ("Australia/Hobart", "Australia_Eastern"),
+    ("Australia/LHI", "Lord_Howe"),
+    ("Australia/Lindeman", "Australia_Eastern"),
+    ("Australia/Lord_Howe", "Lord_Howe"),
+    ("Australia/Melbourne", "Australia_Eastern"),
+    ("Australia/NSW", "Australia_Eastern"),
+    ("Australia/North", "Australia_Central"),
+    ("Australia/Perth", "Australia_Western"),
+    ("Australia/Queensland", "Australia_Eastern"),
+    ("Australia/South", "Australia_Central"),
+    ("Australia/Sydney", "Australia_Eastern"),
+    ("Australia/Tasmania", "Australia_Eastern"),
+    ("Australia/Victoria", "Australia_Eastern"),
+    ("Australia/West", "Australia_Western"),
+    ("Australia/Yancowinna", "Australia_Central"),
+    ("Brazil/Acre", "Acre"),
+    ("Brazil/DeNoronha", "Noronha"),
+    ("Brazil/East", "Brasilia"),
+    ("Brazil/West", "Amazon"),
+    ("CST6CDT", "America_Central"),
+    ("Canada/Atlantic", "Atlantic"),
+    ("Canada/Central", "America_Central"),
+    ("Canada/Eastern", "America_Eastern"),
+    ("Canada/Mountain", "America_Mountain"),
+    ("Canada/Newfoundland", "Newfoundland"),
+    ("Canada/Pacific", "America_Pacific"),
+    ("Canada/Saskatchewan", "America_Central"),
+    ("Canada/Yukon", "America_Mountain"),
+    ("Chile/Continental", "Chile"),
+    ("Chile/EasterIsland", "Easter"),
+    ("Cuba", "Cuba"),
+    ("EST5EDT", "America_Eastern"),
+    ("Egypt", "Europe_Eastern"),
+    ("Eire", "GMT"),
+    ("Etc/GMT", "GMT"),
+    ("Etc/GMT+0", "GMT"),
+    ("Etc/GMT-0", "GMT"),
+    ("Etc/GMT0", "GMT"),
+    ("Etc/Greenwich", "GMT"),
+    ("Europe/Amsterdam", "Europe_Central"),
+    ("Europe/Andorra", "Europe_Central"),
+    ("Europe/Athens", "Europe_Eastern"),
+    ("Europe/Belfast", "GMT"),
+    ("Europe/Belgrade", "Europe_Central"),
+    ("Europe/Berlin", "Europe_Central"),
+    ("Europe/Bratislava", "Europe_Central"),
+    ("Europe/Brussels", "Europe_Central"),
+    ("Europe/Bucharest", "Europe_Eastern"),
+    ("Europe/Budapest", "Europe_Central"),
+    ("Europe/Busingen", "Europe_Central"),
+    ("Europe/Chisinau", "Europe_Eastern"),
+    ("Europe/Copenhagen", "Europe_Central"),
+    ("Europe/Dublin", "GMT"),
+    ("Europe/Gibraltar", "Europe_Central"),
+    ("Europe/Guernsey", "GMT"),
+    ("Europe/Helsinki", "Europe_Eastern"),
+    ("Europe/Isle_of_Man", "GMT"),
+    ("Europe/Istanbul", "Turkey"),
+    ("Europe/Jersey", "GMT"),
+    ("Europe/Kaliningrad", "Europe_Eastern"),
+    ("Europe/Kiev", "Europe_Eastern"),
+    ("Europe/Kyiv", "Europe_Eastern"),
+    ("Europe/Lisbon", "Europe_Western"),
+    ("Europe/Ljubljana", "Europe_Central"),
+    ("Europe/London", "GMT"),
+    ("Europe/Luxembourg", "Europe_Central"),
+    ("Europe/Madrid", "Europe_Central"),
+    ("Europe/Malta", "Europe_Central"),
+    ("Europe/Mariehamn", "Europe_Eastern"),
+    ("Europe/Minsk", "Moscow"),
+    ("Europe/Monaco", "Europe_Central"),
+    ("Europe/Moscow", "Moscow"),
+    ("Europe/Nicosia", "Europe_Eastern"),
+    ("Europe/Oslo", "Europe_Central"),
+    ("Europe/Paris", "Europe_Central"),
+    ("Europe/Podgorica", "Europe_Central"),
+    ("Europe/Prague", "Europe_Central"),
+    ("Europe/Riga", "Europe_Eastern"),
+    ("Europe/Rome", "Europe_Central"),
+    ("Europe/Samara", "Samara"),
+    ("Europe/San_Marino", "Europe_Central"),
+    ("Europe/Sarajevo", "Europe_Central"),
+    ("Europe/Simferopol", "Moscow"),
+    ("Europe/Skopje", "Europe_Central"),
+    ("Europe/Sofia", "Europe_Eastern"),
+    ("Europe/Stockholm", "Europe_Central"),
+    ("Europe/Tallinn", "Europe_Eastern"),
+    ("Europe/Tirane", "Europe_Central"),
+    ("Europe/Tiraspol", "Europe_Eastern"),
+    ("Europe/Uzhgorod", "Europe_Eastern"),
+    ("Europe/Vaduz", "Europe_Central"),
+    ("Europe/Vatican", "Europe_Central"),
+    ("Europe/Vienna", "Europe_Central"),
+    ("Europe/Vilnius", "Europe_Eastern"),
+    ("Europe/Volgograd", "Volgograd"),
+    ("Europe/Warsaw", "Europe_Central"),
+    ("Europe/Zagreb", "Europe_Central"),
+    ("Europe/Zaporozhye", "Europe_Eastern"),
+    ("Europe/Zurich", "Europe_Central"),
+    ("GB", "GMT"),
+    ("GB-Eire", "GMT"),
+    ("GMT", "GMT"),
+    ("GMT0", "GMT"),
+    ("Greenwich", "GMT"),
+    ("Hongkong", "Hong_Kong"),
+    ("Iceland", "GMT"),
+    ("Indian/Antananarivo", "Africa_Eastern"),
+    ("Indian/Chagos", "Indian_Ocean"),
+    ("Indian/Christmas", "Christmas"),
+    ("Indian/Cocos", "Cocos"),
+    ("Indian/Comoro", "Africa_Eastern"),
+    ("Indian/Kerguelen", "French_Southern"),
+    ("Indian/Mahe", "Seychelles"),
+    ("Indian/Maldives", "Maldives"),
+    ("Indian/Mauritius", "Mauritius"),
+    ("Indian/Mayotte", "Africa_Eastern"),
+    ("Indian/Reunion", "Reunion"),
+    ("Iran", "Iran"),
+    ("Israel", "Israel"),
+    ("Jamaica", "America_Eastern"),
+    ("Japan", "Japan"),
+    ("Kwajalein", "Marshall_Islands"),
+    ("Libya", "Europe_Eastern"),
+    ("MST7MDT", "America_Mountain"),
+    ("Mexico/BajaNorte", "America_Pacific"),
+    ("Mexico/BajaSur", "Mexico_Pacific"),
+    ("Mexico/General", "America_Central"),
+    ("NZ", "New_Zealand"),
+    ("NZ-CHAT", "Chatham"),
+    ("Navajo", "America_Mountain"),
+    ("PRC", "China"),
+    ("PST8PDT", "America_Pacific"),
+    ("Pacific/Apia", "Apia"),
+    ("Pacific/Auckland", "New_Zealand"),
+    ("Pacific/Chatham", "Chatham"),
+    ("Pacific/Chuuk", "Truk"),
+    ("Pacific/Easter", "Easter"),
+    ("Pacific/Efate", "Vanuatu"),
+    ("Pacific/Enderbury", "Phoenix_Islands"),
+    ("Pacific/Fakaofo", "Tokelau"),
+    ("Pacific/Fiji", "Fiji"),
+    ("Pacific/Funafuti", "Tuvalu"),
+    ("Pacific/Galapagos", "Galapagos"),
+    ("Pacific/Gambier", "Gambier"),
+    ("Pacific/Guadalcanal", "Solomon"),
+    ("Pacific/Guam", "Chamorro"),
+    ("Pacific/Honolulu", "Hawaii_Aleutian"),
+    ("Pacific/Johnston", "Hawaii_Aleutian"),
+    ("Pacific/Kiritimati", "Line_Islands"),
+    ("Pacific/Kosrae", "Kosrae"),
+    ("Pacific/Kwajalein", "Marshall_Islands"),
+    ("Pacific/Majuro", "Marshall_Islands"),
+    ("Pacific/Marquesas", "Marquesas"),
+    ("Pacific/Midway", "Samoa"),
+    ("Pacific/Nauru", "Nauru"),
+    ("Pacific/Niue", "Niue"),
+    ("Pacific/Norfolk", "Norfolk"),
+    ("Pacific/Noumea", "New_Caledonia"),
+    ("Pacific/Pago_Pago", "Samoa"),
+    ("Pacific/Palau", "Palau"),
+    ("Pacific/Pitcairn", "Pitcairn"),
+    ("Pacific/Pohnpei", "Ponape"),
+    ("Pacific/Ponape", "Ponape"),
+    ("Pacific/Port_Moresby", "Papua_New_Guinea"),
+    ("Pacific/Rarotonga", "Cook"),
+    ("Pacific/Saipan", "Chamorro"),
+    ("Pacific/Samoa", "Samoa"),
+    ("Pacific/Tahiti", "Tahiti"),
+    ("Pacific/Tarawa", "Gilbert_Islands"),
+    ("Pacific/Tongatapu", "Tonga"),
+    ("Pacific/Truk", "Truk"),
+    ("Pacific/Wake", "Wake"),
+    ("Pacific/Wallis", "Wallis"),
+    ("Pacific/Yap", "Truk"),
+    ("Poland", "Europe_Central"),
+    ("Portugal", "Europe_Western"),
+    ("ROK", "Korea"),
+    ("Singapore", "Singapore"),
+    ("Turkey", "Turkey"),
+    ("US/Alaska", "Alaska"),
+    ("US/Aleutian", "Hawaii_Aleutian"),
+    ("US/Arizona", "America_Mountain"),
+    ("US/Central", "America_Central"),
+    ("US/East-Indiana", "America_Eastern"),
+    ("US/Eastern", "America_Eastern"),
+    ("US/Hawaii", "Hawaii_Aleutian"),
+    ("US/Indiana-Starke", "America_Central"),
+    ("US/Michigan", "America_Eastern"),
+    ("US/Mountain", "America_Mountain"),
+    ("US/Pacific", "America_Pacific"),
+    ("US/Samoa", "Samoa"),
+    ("W-SU", "Moscow"),
+];
+
+/// Earlier metazones of zones which changed, as (zone, from, to, metazone), times in seconds since the epoch.
+const HISTORY: &[(&str, i64, i64, &str)] = &[
+    // 2018-01-01 01:00 to 2019-01-01 01:00 UTC
+    ("Africa/Sao_Tome", 1514768400, 1546304400, "Africa_Western"),
+    // Until 2017-10-31 21:00 UTC
+    ("Africa/Khartoum", i64::MIN, 1509483600, "Africa_Eastern"),
+    // 1992-09-27 01:00 to 1996-03-31 01:00 UTC
+    ("Europe/Lisbon", 717555600, 828234000, "Europe_Central"),
+];
+
+/// Returns the CLDR metazone of a zone at an instant (ie. "Europe_Central" for Europe/Paris), which keys
+/// the localized names of CLDR data ("Central European Time"). Deprecated zone names are accepted.
+/// Before the recorded changes of metazone, the current one applies.
+pub fn metazone(zone: &str, time: DateTime<Utc>) -> Option<&'static str> {
+    let find = |zone: &str| METAZONES.binary_search_by_key(&zone, |(zone, _)| zone).ok().map(|i| METAZONES[i]);
+    let (zone, current) = find(zone).or_else(|| find(canonical_zone(zone)?))?;
+    let time = time.timestamp();
+    Some(match HISTORY.iter().find(|(z, from, to, _)| *z == zone && *from <= time && time < *to) {
+        Some((_, _, _, metazone)) => metazone,
+        None => current,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn metazones() {
+        let now = Utc.ymd(2019, 7, 1).and_hms(0, 0, 0);
+        assert_eq!(metazone("Europe/Paris", now), Some("Europe_Central"));
+        assert_eq!(metazone("US/Eastern", now), Some("America_Eastern"));
+        assert_eq!(metazone("Europe/Nowhere", now), None);
+        assert_eq!(metazone("Europe/Lisbon", now), Some("Europe_Western"));
+        assert_eq!(metazone("Europe/Lisbon", Utc.ymd(1995, 1, 1).and_hms(0, 0, 0)), Some("Europe_Central"));
+        assert_eq!(metazone("Africa/Sao_Tome", Utc.ymd(2018, 6, 1).and_hms(0, 0, 0)), Some("Africa_Western"));
+        assert_eq!(metazone("Africa/Sao_Tome", Utc.ymd(2019, 1, 1).and_hms(1, 0, 0)), Some("GMT"));
+        assert!(METAZONES.windows(2).all(|w| w[0].0 < w[1].0));
+        for (zone, _, _, _) in HISTORY {
+            assert!(METAZONES.iter().any(|(z, _)| z == zone));
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{Error, TzDatabase};
+use chrono::prelude::*;
+
+/// Returns the offset to GMT in seconds of a military time zone letter (case insensitive):
+/// Z is UTC, A to M (skipping J) are 1 to 12 hours ahead, N to Y 1 to 12 hours behind.
+/// J, the observer's local time, has no fixed offset.
+pub fn military_offset(letter: char) -> Option<isize> {
+    let letter = letter.to_ascii_uppercase();
+    let hours = match letter {
+        'Z' => 0,
+        'A'..='I' => letter as isize - 'A' as isize + 1,
+        'K'..='M' => letter as isize - 'K' as isize + 10,
+        'N'..='Y' => -(letter as isize - 'N' as isize + 1),
+        _ => return None,
+    };
+    Some(hours * 3600)
+}
+
+/// Returns the military time zone letter of an offset to GMT in seconds, if it is a whole number of hours
+/// within 12 hours of GMT.
+pub fn military_letter(gmtoff: isize) -> Option<char> {
+    if gmtoff % 3600 != 0 {
+        return None;
+    }
+    let hours = gmtoff / 3600;
+    let letter = match hours {
+        0 => 'Z',
+        1..=9 => (b'A' + hours as u8 - 1) as char,
+        10..=12 => (b'K' + hours as u8 - 10) as char,
+        -12..=-1 => (b'N' + (-hours) as u8 - 1) as char,
+        _ => return None,
+    };
+    Some(letter)
+}
+
+impl TzDatabase {
+    /// Returns the zones of the tree whose offset to GMT at `now` is the one of a military time zone letter, sorted.
+    pub fn military_zones(&self, letter: char, now: DateTime<Utc>) -> Result<Vec<String>, Error> {
+        let gmtoff = match military_offset(letter) {
+            Some(gmtoff) => gmtoff,
+            None => return Ok(Vec::new()),
+        };
+        let mut zones = Vec::new();
+        for name in self.zones()? {
+            if matches!(self.zone(&name)?.offset_at(now), Some(offset) if offset.gmtoff == gmtoff) {
+                zones.push(name);
+            }
+        }
+        Ok(zones)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn letters() {
+        assert_eq!(military_offset('Z'), Some(0));
+        assert_eq!(military_offset('a'), Some(3600));
+        assert_eq!(military_offset('M'), Some(12 * 3600));
+        assert_eq!(military_offset('R'), Some(-5 * 3600));
+        assert_eq!(military_offset('J'), None);
+        for letter in "ABCDEFGHIKLMNOPQRSTUVWXYZ".chars() {
+            assert_eq!(military_letter(military_offset(letter).unwrap()), Some(letter));
+        }
+        assert_eq!(military_letter(19800), None);
+        assert_eq!(military_letter(13 * 3600), None);
+        let db = TzDatabase::open("/usr/share/zoneinfo");
+        let winter = db.military_zones('Z', Utc.ymd(2019, 1, 15).and_hms(12, 0, 0)).unwrap();
+        assert!(winter.contains(&"Europe/London".to_string()));
+        let summer = db.military_zones('Z', Utc.ymd(2019, 7, 15).and_hms(12, 0, 0)).unwrap();
+        assert!(!summer.contains(&"Europe/London".to_string()));
+        assert!(db.military_zones('J', Utc.ymd(2019, 7, 15).and_hms(12, 0, 0)).unwrap().is_empty());
+    }
+}
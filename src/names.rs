@@ -0,0 +1,1756 @@
+use crate::{canonical_zone, metazone, Tzinfo, Zone};
+use chrono::prelude::*;
+use std::fmt::{self, Write};
+
+/// Locales of the embedded CLDR names
+pub const NAME_LOCALES: &[&str] = &["de", "en", "es", "fr", "it", "pt"];
+
+/// Long names of metazones as (locale, metazone, standard, daylight, generic), sorted. Empty when CLDR has none.
+const METAZONE_NAMES: &[(&str, &str, &str, &str, &str)] = &[
+    ("de", "Acre", "Acre-Normalzeit", "Acre-Sommerzeit", "Acre-Zeit"),
+    ("de", "Afghanistan", "Afghanistan-Zeit", "", ""),
+    ("de", "Africa_Central", "Zentralafrikanische Zeit", "", ""),
+    ("de", "Africa_Eastern", "Ostafrikanische Zeit", "", ""),
+    ("de", "Africa_Southern", "Südafrikanische Zeit", "", ""),
+    ("de", "Africa_Western", "Westafrikanische Normalzeit", "Westafrikanische Sommerzeit", "Westafrikanische Zeit"),
+    ("de", "Alaska", "Alaska-Normalzeit", "Alaska-Sommerzeit", "Alaska-Zeit"),
+    ("de", "Amazon", "Amazonas-Normalzeit", "Amazonas-Sommerzeit", "Amazonas-Zeit"),
+    ("de", "America_Central", "Nordamerikanische Inland-Normalzeit", "Nordamerikanische Inland-Sommerzeit", "Nordamerikanische Inlandzeit"),
+    ("de", "America_Eastern", "Nordamerikanische Ostküsten-Normalzeit", "Nordamerikanische Ostküsten-Sommerzeit", "Nordamerikanische Ostküstenzeit"),
+    ("de", "America_Mountain", "Rocky Mountain-Normalzeit", "Rocky-Mountain-Sommerzeit", "Rocky-Mountain-Zeit"),
+    ("de", "America_Pacific", "Nordamerikanische Westküsten-Normalzeit", "Nordamerikanische Westküsten-Sommerzeit", "Nordamerikanische Westküstenzeit"),
+    ("de", "Anadyr", "Anadyr Normalzeit", "Anadyr Sommerzeit", "Anadyr Zeit"),
+    ("de", "Apia", "Apia-Normalzeit", "Apia-Sommerzeit", "Apia-Zeit"),
+    ("de", "Arabian", "Arabische Normalzeit", "Arabische Sommerzeit", "Arabische Zeit"),
+    ("de", "Argentina", "Argentinische Normalzeit", "Argentinische Sommerzeit", "Argentinische Zeit"),
+    ("de", "Armenia", "Armenische Normalzeit", "Armenische Sommerzeit", "Armenische Zeit"),
+    ("de", "Atlantic", "Atlantik-Normalzeit", "Atlantik-Sommerzeit", "Atlantik-Zeit"),
+    ("de", "Australia_Central", "Zentralaustralische Normalzeit", "Zentralaustralische Sommerzeit", "Zentralaustralische Zeit"),
+    ("de", "Australia_CentralWestern", "Zentral-/Westaustralische Normalzeit", "Zentral-/Westaustralische Sommerzeit", "Zentral-/Westaustralische Zeit"),
+    ("de", "Australia_Eastern", "Ostaustralische Normalzeit", "Ostaustralische Sommerzeit", "Ostaustralische Zeit"),
+    ("de", "Australia_Western", "Westaustralische Normalzeit", "Westaustralische Sommerzeit", "Westaustralische Zeit"),
+    ("de", "Azerbaijan", "Aserbeidschanische Normalzeit", "Aserbaidschanische Sommerzeit", "Aserbaidschanische Zeit"),
+    ("de", "Azores", "Azoren-Normalzeit", "Azoren-Sommerzeit", "Azoren-Zeit"),
+    ("de", "Bangladesh", "Bangladesch-Normalzeit", "Bangladesch-Sommerzeit", "Bangladesch-Zeit"),
+    ("de", "Bhutan", "Bhutan-Zeit", "", ""),
+    ("de", "Bolivia", "Bolivianische Zeit", "", ""),
+    ("de", "Brasilia", "Brasília-Normalzeit", "Brasília-Sommerzeit", "Brasília-Zeit"),
+    ("de", "Brunei", "Brunei-Darussalam-Zeit", "", ""),
+    ("de", "Cape_Verde", "Cabo-Verde-Normalzeit", "Cabo-Verde-Sommerzeit", "Cabo-Verde-Zeit"),
+    ("de", "Casey", "Casey-Zeit", "", ""),
+    ("de", "Chamorro", "Chamorro-Zeit", "", ""),
+    ("de", "Chatham", "Chatham-Normalzeit", "Chatham-Sommerzeit", "Chatham-Zeit"),
+    ("de", "Chile", "Chilenische Normalzeit", "Chilenische Sommerzeit", "Chilenische Zeit"),
+    ("de", "China", "Chinesische Normalzeit", "Chinesische Sommerzeit", "Chinesische Zeit"),
+    ("de", "Christmas", "Weihnachtsinsel-Zeit", "", ""),
+    ("de", "Cocos", "Kokosinseln-Zeit", "", ""),
+    ("de", "Colombia", "Kolumbianische Normalzeit", "Kolumbianische Sommerzeit", "Kolumbianische Zeit"),
+    ("de", "Cook", "Cookinseln-Normalzeit", "Cookinseln-Sommerzeit", "Cookinseln-Zeit"),
+    ("de", "Cuba", "Kubanische Normalzeit", "Kubanische Sommerzeit", "Kubanische Zeit"),
+    ("de", "Davis", "Davis-Zeit", "", ""),
+    ("de", "DumontDUrville", "Dumont-d’Urville-Zeit", "", ""),
+    ("de", "East_Timor", "Osttimor-Zeit", "", ""),
+    ("de", "Easter", "Osterinsel-Normalzeit", "Osterinsel-Sommerzeit", "Osterinsel-Zeit"),
+    ("de", "Ecuador", "Ecuadorianische Zeit", "", ""),
+    ("de", "Europe_Central", "Mitteleuropäische Normalzeit", "Mitteleuropäische Sommerzeit", "Mitteleuropäische Zeit"),
+    ("de", "Europe_Eastern", "Osteuropäische Normalzeit", "Osteuropäische Sommerzeit", "Osteuropäische Zeit"),
+    ("de", "Europe_Western", "Westeuropäische Normalzeit", "Westeuropäische Sommerzeit", "Westeuropäische Zeit"),
+    ("de", "Falkland", "Falklandinseln-Normalzeit", "Falklandinseln-Sommerzeit", "Falklandinseln-Zeit"),
+    ("de", "Fiji", "Fidschi-Normalzeit", "Fidschi-Sommerzeit", "Fidschi-Zeit"),
+    ("de", "French_Guiana", "Französisch-Guayana-Zeit", "", ""),
+    ("de", "French_Southern", "Französische-Süd-und-Antarktisgebiete-Zeit", "", ""),
+    ("de", "GMT", "Mittlere Greenwich-Zeit", "", ""),
+    ("de", "Galapagos", "Galapagos-Zeit", "", ""),
+    ("de", "Gambier", "Gambier-Zeit", "", ""),
+    ("de", "Georgia", "Georgische Normalzeit", "Georgische Sommerzeit", "Georgische Zeit"),
+    ("de", "Gilbert_Islands", "Gilbert-Inseln-Zeit", "", ""),
+    ("de", "Greenland_Eastern", "Ostgrönland-Normalzeit", "Ostgrönland-Sommerzeit", "Ostgrönland-Zeit"),
+    ("de", "Greenland_Western", "Westgrönland-Normalzeit", "Westgrönland-Sommerzeit", "Westgrönland-Zeit"),
+    ("de", "Gulf", "Golf-Zeit", "", ""),
+    ("de", "Guyana", "Guyana-Zeit", "", ""),
+    ("de", "Hawaii_Aleutian", "Hawaii-Aleuten-Normalzeit", "Hawaii-Aleuten-Sommerzeit", "Hawaii-Aleuten-Zeit"),
+    ("de", "Hong_Kong", "Hongkong-Normalzeit", "Hongkong-Sommerzeit", "Hongkong-Zeit"),
+    ("de", "Hovd", "Chowd-Normalzeit", "Chowd-Sommerzeit", "Chowd-Zeit"),
+    ("de", "India", "Indische Normalzeit", "", ""),
+    ("de", "Indian_Ocean", "Indischer-Ozean-Zeit", "", ""),
+    ("de", "Indochina", "Indochina-Zeit", "", ""),
+    ("de", "Indonesia_Central", "Zentralindonesische Zeit", "", ""),
+    ("de", "Indonesia_Eastern", "Ostindonesische Zeit", "", ""),
+    ("de", "Indonesia_Western", "Westindonesische Zeit", "", ""),
+    ("de", "Iran", "Iranische Normalzeit", "Iranische Sommerzeit", "Iranische Zeit"),
+    ("de", "Irkutsk", "Irkutsk-Normalzeit", "Irkutsk-Sommerzeit", "Irkutsk-Zeit"),
+    ("de", "Israel", "Israelische Normalzeit", "Israelische Sommerzeit", "Israelische Zeit"),
+    ("de", "Japan", "Japanische Normalzeit", "Japanische Sommerzeit", "Japanische Zeit"),
+    ("de", "Kamchatka", "Kamtschatka-Normalzeit", "Kamtschatka-Sommerzeit", "Kamtschatka-Zeit"),
+    ("de", "Kazakhstan_Eastern", "Ostkasachische Zeit", "", ""),
+    ("de", "Kazakhstan_Western", "Westkasachische Zeit", "", ""),
+    ("de", "Korea", "Koreanische Normalzeit", "Koreanische Sommerzeit", "Koreanische Zeit"),
+    ("de", "Kosrae", "Kosrae-Zeit", "", ""),
+    ("de", "Krasnoyarsk", "Krasnojarsk-Normalzeit", "Krasnojarsk-Sommerzeit", "Krasnojarsk-Zeit"),
+    ("de", "Kyrgystan", "Kirgisistan-Zeit", "", ""),
+    ("de", "Line_Islands", "Linieninseln-Zeit", "", ""),
+    ("de", "Lord_Howe", "Lord-Howe-Normalzeit", "Lord-Howe-Sommerzeit", "Lord-Howe-Zeit"),
+    ("de", "Magadan", "Magadan-Normalzeit", "Magadan-Sommerzeit", "Magadan-Zeit"),
+    ("de", "Malaysia", "Malaysische Zeit", "", ""),
+    ("de", "Maldives", "Malediven-Zeit", "", ""),
+    ("de", "Marquesas", "Marquesas-Zeit", "", ""),
+    ("de", "Marshall_Islands", "Marshallinseln-Zeit", "", ""),
+    ("de", "Mauritius", "Mauritius-Normalzeit", "Mauritius-Sommerzeit", "Mauritius-Zeit"),
+    ("de", "Mawson", "Mawson-Zeit", "", ""),
+    ("de", "Mexico_Northwest", "Mexiko Nordwestliche Zone-Normalzeit", "Mexiko Nordwestliche Zone-Sommerzeit", "Mexiko Nordwestliche Zone-Zeit"),
+    ("de", "Mexico_Pacific", "Mexiko Pazifikzone-Normalzeit", "Mexiko Pazifikzone-Sommerzeit", "Mexiko Pazifikzone-Zeit"),
+    ("de", "Mongolia", "Ulaanbaatar-Normalzeit", "Ulaanbaatar-Sommerzeit", "Ulaanbaatar-Zeit"),
+    ("de", "Moscow", "Moskauer Normalzeit", "Moskauer Sommerzeit", "Moskauer Zeit"),
+    ("de", "Myanmar", "Myanmar-Zeit", "", ""),
+    ("de", "Nauru", "Nauru-Zeit", "", ""),
+    ("de", "Nepal", "Nepalesische Zeit", "", ""),
+    ("de", "New_Caledonia", "Neukaledonische Normalzeit", "Neukaledonische Sommerzeit", "Neukaledonische Zeit"),
+    ("de", "New_Zealand", "Neuseeland-Normalzeit", "Neuseeland-Sommerzeit", "Neuseeland-Zeit"),
+    ("de", "Newfoundland", "Neufundland-Normalzeit", "Neufundland-Sommerzeit", "Neufundland-Zeit"),
+    ("de", "Niue", "Niue-Zeit", "", ""),
+    ("de", "Norfolk", "Norfolkinsel-Normalzeit", "Norfolkinsel-Sommerzeit", "Norfolkinsel-Zeit"),
+    ("de", "Noronha", "Fernando-de-Noronha-Normalzeit", "Fernando-de-Noronha-Sommerzeit", "Fernando-de-Noronha-Zeit"),
+    ("de", "Novosibirsk", "Nowosibirsk-Normalzeit", "Nowosibirsk-Sommerzeit", "Nowosibirsk-Zeit"),
+    ("de", "Omsk", "Omsker Normalzeit", "Omsker Sommerzeit", "Omsker Zeit"),
+    ("de", "Pakistan", "Pakistanische Normalzeit", "Pakistanische Sommerzeit", "Pakistanische Zeit"),
+    ("de", "Palau", "Palau-Zeit", "", ""),
+    ("de", "Papua_New_Guinea", "Papua-Neuguinea-Zeit", "", ""),
+    ("de", "Paraguay", "Paraguayanische Normalzeit", "Paraguayanische Sommerzeit", "Paraguayanische Zeit"),
+    ("de", "Peru", "Peruanische Normalzeit", "Peruanische Sommerzeit", "Peruanische Zeit"),
+    ("de", "Philippines", "Philippinische Normalzeit", "Philippinische Sommerzeit", "Philippinische Zeit"),
+    ("de", "Phoenix_Islands", "Phoenixinseln-Zeit", "", ""),
+    ("de", "Pierre_Miquelon", "St.-Pierre-und-Miquelon-Normalzeit", "St.-Pierre-und-Miquelon-Sommerzeit", "St.-Pierre-und-Miquelon-Zeit"),
+    ("de", "Pitcairn", "Pitcairninseln-Zeit", "", ""),
+    ("de", "Ponape", "Ponape-Zeit", "", ""),
+    ("de", "Reunion", "Réunion-Zeit", "", ""),
+    ("de", "Rothera", "Rothera-Zeit", "", ""),
+    ("de", "Sakhalin", "Sachalin-Normalzeit", "Sachalin-Sommerzeit", "Sachalin-Zeit"),
+    ("de", "Samara", "Samara-Normalzeit", "Samara-Sommerzeit", "Samara-Zeit"),
+    ("de", "Samoa", "Samoa-Normalzeit", "Samoa-Sommerzeit", "Samoa-Zeit"),
+    ("de", "Seychelles", "Seychellen-Zeit", "", ""),
+    ("de", "Singapore", "Singapurische Normalzeit", "", ""),
+    ("de", "Solomon", "Salomonen-Zeit", "", ""),
+    ("de", "South_Georgia", "Südgeorgische Zeit", "", ""),
+    ("de", "Suriname", "Suriname-Zeit", "", ""),
+    ("de", "Syowa", "Syowa-Zeit", "", ""),
+    ("de", "Tahiti", "Tahiti-Zeit", "", ""),
+    ("de", "Taipei", "Taipeh-Normalzeit", "Taipeh-Sommerzeit", "Taipeh-Zeit"),
+    ("de", "Tajikistan", "Tadschikistan-Zeit", "", ""),
+    ("de", "Tokelau", "Tokelau-Zeit", "", ""),
+    ("de", "Tonga", "Tonganische Normalzeit", "Tonganische Sommerzeit", "Tonganische Zeit"),
+    ("de", "Truk", "Chuuk-Zeit", "", ""),
+    ("de", "Turkmenistan", "Turkmenistan-Normalzeit", "Turkmenistan-Sommerzeit", "Turkmenistan-Zeit"),
+    ("de", "Tuvalu", "Tuvalu-Zeit", "", ""),
+    ("de", "Uruguay", "Uruguyanische Normalzeit", "Uruguayanische Sommerzeit", "Uruguayanische Zeit"),
+    ("de", "Uzbekistan", "Usbekistan-Normalzeit", "Usbekistan-Sommerzeit", "Usbekistan-Zeit"),
+    ("de", "Vanuatu", "Vanuatu-Normalzeit", "Vanuatu-Sommerzeit", "Vanuatu-Zeit"),
+    ("de", "Venezuela", "Venezuela-Zeit", "", ""),
+    ("de", "Vladivostok", "Wladiwostok-Normalzeit", "Wladiwostok-Sommerzeit", "Wladiwostok-Zeit"),
+    ("de", "Volgograd", "Wolgograd-Normalzeit", "Wolgograd-Sommerzeit", "Wolgograd-Zeit"),
+    ("de", "Vostok", "Wostok-Zeit", "", ""),
+    ("de", "Wake", "Wake-Insel-Zeit", "", ""),
+    ("de", "Wallis", "Wallis-und-Futuna-Zeit", "", ""),
+    ("de", "Yakutsk", "Jakutsker Normalzeit", "Jakutsker Sommerzeit", "Jakutsker Zeit"),
+    ("de", "Yekaterinburg", "Jekaterinburg-Normalzeit", "Jekaterinburg-Sommerzeit", "Jekaterinburg-Zeit"),
+    ("en", "Acre", "Acre Standard Time", "Acre Summer Time", "Acre Time"),
+    ("en", "Afghanistan", "Afghanistan Time", "", ""),
+    ("en", "Africa_Central", "Central Africa Time", "", ""),
+    ("en", "Africa_Eastern", "East Africa Time", "", ""),
+    ("en", "Africa_Southern", "South Africa Standard Time", "", ""),
+    ("en", "Africa_Western", "West Africa Standard Time", "West Africa Summer Time", "West Africa Time"),
+    ("en", "Alaska", "Alaska Standard Time", "Alaska Daylight Time", "Alaska Time"),
+    ("en", "Amazon", "Amazon Standard Time", "Amazon Summer Time", "Amazon Time"),
+    ("en", "America_Central", "Central Standard Time", "Central Daylight Time", "Central Time"),
+    ("en", "America_Eastern", "Eastern Standard Time", "Eastern Daylight Time", "Eastern Time"),
+    ("en", "America_Mountain", "Mountain Standard Time", "Mountain Daylight Time", "Mountain Time"),
+    ("en", "America_Pacific", "Pacific Standard Time", "Pacific Daylight Time", "Pacific Time"),
+    ("en", "Anadyr", "Anadyr Standard Time", "Anadyr Summer Time", "Anadyr Time"),
+    ("en", "Apia", "Apia Standard Time", "Apia Daylight Time", "Apia Time"),
+    ("en", "Arabian", "Arabian Standard Time", "Arabian Daylight Time", "Arabian Time"),
+    ("en", "Argentina", "Argentina Standard Time", "Argentina Summer Time", "Argentina Time"),
+    ("en", "Armenia", "Armenia Standard Time", "Armenia Summer Time", "Armenia Time"),
+    ("en", "Atlantic", "Atlantic Standard Time", "Atlantic Daylight Time", "Atlantic Time"),
+    ("en", "Australia_Central", "Australian Central Standard Time", "Australian Central Daylight Time", "Central Australia Time"),
+    ("en", "Australia_CentralWestern", "Australian Central Western Standard Time", "Australian Central Western Daylight Time", "Australian Central Western Time"),
+    ("en", "Australia_Eastern", "Australian Eastern Standard Time", "Australian Eastern Daylight Time", "Eastern Australia Time"),
+    ("en", "Australia_Western", "Australian Western Standard Time", "Australian Western Daylight Time", "Western Australia Time"),
+    ("en", "Azerbaijan", "Azerbaijan Standard Time", "Azerbaijan Summer Time", "Azerbaijan Time"),
+    ("en", "Azores", "Azores Standard Time", "Azores Summer Time", "Azores Time"),
+    ("en", "Bangladesh", "Bangladesh Standard Time", "Bangladesh Summer Time", "Bangladesh Time"),
+    ("en", "Bhutan", "Bhutan Time", "", ""),
+    ("en", "Bolivia", "Bolivia Time", "", ""),
+    ("en", "Brasilia", "Brasilia Standard Time", "Brasilia Summer Time", "Brasilia Time"),
+    ("en", "Brunei", "Brunei Darussalam Time", "", ""),
+    ("en", "Cape_Verde", "Cape Verde Standard Time", "Cape Verde Summer Time", "Cape Verde Time"),
+    ("en", "Casey", "Casey Time", "", ""),
+    ("en", "Chamorro", "Chamorro Standard Time", "", ""),
+    ("en", "Chatham", "Chatham Standard Time", "Chatham Daylight Time", "Chatham Time"),
+    ("en", "Chile", "Chile Standard Time", "Chile Summer Time", "Chile Time"),
+    ("en", "China", "China Standard Time", "China Daylight Time", "China Time"),
+    ("en", "Christmas", "Christmas Island Time", "", ""),
+    ("en", "Cocos", "Cocos Islands Time", "", ""),
+    ("en", "Colombia", "Colombia Standard Time", "Colombia Summer Time", "Colombia Time"),
+    ("en", "Cook", "Cook Islands Standard Time", "Cook Islands Half Summer Time", "Cook Islands Time"),
+    ("en", "Cuba", "Cuba Standard Time", "Cuba Daylight Time", "Cuba Time"),
+    ("en", "Davis", "Davis Time", "", ""),
+    ("en", "DumontDUrville", "Dumont-d’Urville Time", "", ""),
+    ("en", "East_Timor", "East Timor Time", "", ""),
+    ("en", "Easter", "Easter Island Standard Time", "Easter Island Summer Time", "Easter Island Time"),
+    ("en", "Ecuador", "Ecuador Time", "", ""),
+    ("en", "Europe_Central", "Central European Standard Time", "Central European Summer Time", "Central European Time"),
+    ("en", "Europe_Eastern", "Eastern European Standard Time", "Eastern European Summer Time", "Eastern European Time"),
+    ("en", "Europe_Western", "Western European Standard Time", "Western European Summer Time", "Western European Time"),
+    ("en", "Falkland", "Falkland Islands Standard Time", "Falkland Islands Summer Time", "Falkland Islands Time"),
+    ("en", "Fiji", "Fiji Standard Time", "Fiji Summer Time", "Fiji Time"),
+    ("en", "French_Guiana", "French Guiana Time", "", ""),
+    ("en", "French_Southern", "French Southern & Antarctic Time", "", ""),
+    ("en", "GMT", "Greenwich Mean Time", "", ""),
+    ("en", "Galapagos", "Galapagos Time", "", ""),
+    ("en", "Gambier", "Gambier Time", "", ""),
+    ("en", "Georgia", "Georgia Standard Time", "Georgia Summer Time", "Georgia Time"),
+    ("en", "Gilbert_Islands", "Gilbert Islands Time", "", ""),
+    ("en", "Greenland_Eastern", "East Greenland Standard Time", "East Greenland Summer Time", "East Greenland Time"),
+    ("en", "Greenland_Western", "West Greenland Standard Time", "West Greenland Summer Time", "West Greenland Time"),
+    ("en", "Gulf", "Gulf Standard Time", "", ""),
+    ("en", "Guyana", "Guyana Time", "", ""),
+    ("en", "Hawaii_Aleutian", "Hawaii-Aleutian Standard Time", "Hawaii-Aleutian Daylight Time", "Hawaii-Aleutian Time"),
+    ("en", "Hong_Kong", "Hong Kong Standard Time", "Hong Kong Summer Time", "Hong Kong Time"),
+    ("en", "Hovd", "Hovd Standard Time", "Hovd Summer Time", "Hovd Time"),
+    ("en", "India", "India Standard Time", "", ""),
+    ("en", "Indian_Ocean", "Indian Ocean Time", "", ""),
+    ("en", "Indochina", "Indochina Time", "", ""),
+    ("en", "Indonesia_Central", "Central Indonesia Time", "", ""),
+    ("en", "Indonesia_Eastern", "Eastern Indonesia Time", "", ""),
+    ("en", "Indonesia_Western", "Western Indonesia Time", "", ""),
+    ("en", "Iran", "Iran Standard Time", "Iran Daylight Time", "Iran Time"),
+    ("en", "Irkutsk", "Irkutsk Standard Time", "Irkutsk Summer Time", "Irkutsk Time"),
+    ("en", "Israel", "Israel Standard Time", "Israel Daylight Time", "Israel Time"),
+    ("en", "Japan", "Japan Standard Time", "Japan Daylight Time", "Japan Time"),
+    ("en", "Kamchatka", "Petropavlovsk-Kamchatski Standard Time", "Petropavlovsk-Kamchatski Summer Time", "Petropavlovsk-Kamchatski Time"),
+    ("en", "Kazakhstan_Eastern", "East Kazakhstan Time", "", ""),
+    ("en", "Kazakhstan_Western", "West Kazakhstan Time", "", ""),
+    ("en", "Korea", "Korean Standard Time", "Korean Daylight Time", "Korean Time"),
+    ("en", "Kosrae", "Kosrae Time", "", ""),
+    ("en", "Krasnoyarsk", "Krasnoyarsk Standard Time", "Krasnoyarsk Summer Time", "Krasnoyarsk Time"),
+    ("en", "Kyrgystan", "Kyrgyzstan Time", "", ""),
+    ("en", "Line_Islands", "Line Islands Time", "", ""),
+    ("en", "Lord_Howe", "Lord Howe Standard Time", "Lord Howe Daylight Time", "Lord Howe Time"),
+    ("en", "Magadan", "Magadan Standard Time", "Magadan Summer Time", "Magadan Time"),
+    ("en", "Malaysia", "Malaysia Time", "", ""),
+    ("en", "Maldives", "Maldives Time", "", ""),
+    ("en", "Marquesas", "Marquesas Time", "", ""),
+    ("en", "Marshall_Islands", "Marshall Islands Time", "", ""),
+    ("en", "Mauritius", "Mauritius Standard Time", "Mauritius Summer Time", "Mauritius Time"),
+    ("en", "Mawson", "Mawson Time", "", ""),
+    ("en", "Mexico_Northwest", "Northwest Mexico Standard Time", "Northwest Mexico Daylight Time", "Northwest Mexico Time"),
+    ("en", "Mexico_Pacific", "Mexican Pacific Standard Time", "Mexican Pacific Daylight Time", "Mexican Pacific Time"),
+    ("en", "Mongolia", "Ulaanbaatar Standard Time", "Ulaanbaatar Summer Time", "Ulaanbaatar Time"),
+    ("en", "Moscow", "Moscow Standard Time", "Moscow Summer Time", "Moscow Time"),
+    ("en", "Myanmar", "Myanmar Time", "", ""),
+    ("en", "Nauru", "Nauru Time", "", ""),
+    ("en", "Nepal", "Nepal Time", "", ""),
+    ("en", "New_Caledonia", "New Caledonia Standard Time", "New Caledonia Summer Time", "New Caledonia Time"),
+    ("en", "New_Zealand", "New Zealand Standard Time", "New Zealand Daylight Time", "New Zealand Time"),
+    ("en", "Newfoundland", "Newfoundland Standard Time", "Newfoundland Daylight Time", "Newfoundland Time"),
+    ("en", "Niue", "Niue Time", "", ""),
+    ("en", "Norfolk", "Norfolk Island Standard Time", "Norfolk Island Daylight Time", "Norfolk Island Time"),
+    ("en", "Noronha", "Fernando de Noronha Standard Time", "Fernando de Noronha Summer Time", "Fernando de Noronha Time"),
+    ("en", "Novosibirsk", "Novosibirsk Standard Time", "Novosibirsk Summer Time", "Novosibirsk Time"),
+    ("en", "Omsk", "Omsk Standard Time", "Omsk Summer Time", "Omsk Time"),
+    ("en", "Pakistan", "Pakistan Standard Time", "Pakistan Summer Time", "Pakistan Time"),
+    ("en", "Palau", "Palau Time", "", ""),
+    ("en", "Papua_New_Guinea", "Papua New Guinea Time", "", ""),
+    ("en", "Paraguay", "Paraguay Standard Time", "Paraguay Summer Time", "Paraguay Time"),
+    ("en", "Peru", "Peru Standard Time", "Peru Summer Time", "Peru Time"),
+    ("en", "Philippines", "Philippine Standard Time", "Philippine Summer Time", "Philippine Time"),
+    ("en", "Phoenix_Islands", "Phoenix Islands Time", "", ""),
+    ("en", "Pierre_Miquelon", "St. Pierre & Miquelon Standard Time", "St. Pierre & Miquelon Daylight Time", "St. Pierre & Miquelon Time"),
+    ("en", "Pitcairn", "Pitcairn Time", "", ""),
+    ("en", "Ponape", "Ponape Time", "", ""),
+    ("en", "Reunion", "Réunion Time", "", ""),
+    ("en", "Rothera", "Rothera Time", "", ""),
+    ("en", "Sakhalin", "Sakhalin Standard Time", "Sakhalin Summer Time", "Sakhalin Time"),
+    ("en", "Samara", "Samara Standard Time", "Samara Summer Time", "Samara Time"),
+    ("en", "Samoa", "Samoa Standard Time", "Samoa Daylight Time", "Samoa Time"),
+    ("en", "Seychelles", "Seychelles Time", "", ""),
+    ("en", "Singapore", "Singapore Standard Time", "", ""),
+    ("en", "Solomon", "Solomon Islands Time", "", ""),
+    ("en", "South_Georgia", "South Georgia Time", "", ""),
+    ("en", "Suriname", "Suriname Time", "", ""),
+    ("en", "Syowa", "Syowa Time", "", ""),
+    ("en", "Tahiti", "Tahiti Time", "", ""),
+    ("en", "Taipei", "Taipei Standard Time", "Taipei Daylight Time", "Taipei Time"),
+    ("en", "Tajikistan", "Tajikistan Time", "", ""),
+    ("en", "Tokelau", "Tokelau Time", "", ""),
+    ("en", "Tonga", "Tonga Standard Time", "Tonga Summer Time", "Tonga Time"),
+    ("en", "Truk", "Chuuk Time", "", ""),
+    ("en", "Turkmenistan", "Turkmenistan Standard Time", "Turkmenistan Summer Time", "Turkmenistan Time"),
+    ("en", "Tuvalu", "Tuvalu Time", "", ""),
+    ("en", "Uruguay", "Uruguay Standard Time", "Uruguay Summer Time", "Uruguay Time"),
+    ("en", "Uzbekistan", "Uzbekistan Standard Time", "Uzbekistan Summer Time", "Uzbekistan Time"),
+    ("en", "Vanuatu", "Vanuatu Standard Time", "Vanuatu Summer Time", "Vanuatu Time"),
+    ("en", "Venezuela", "Venezuela Time", "", ""),
+    ("en", "Vladivostok", "Vladivostok Standard Time", "Vladivostok Summer Time", "Vladivostok Time"),
+    ("en", "Volgograd", "Volgograd Standard Time", "Volgograd Summer Time", "Volgograd Time"),
+    ("en", "Vostok", "Vostok Time", "", ""),
+    ("en", "Wake", "Wake Island Time", "", ""),
+    ("en", "Wallis", "Wallis & Futuna Time", "", ""),
+    ("en", "Yakutsk", "Yakutsk Standard Time", "Yakutsk Summer Time", "Yakutsk Time"),
+    ("en", "Yekaterinburg", "Yekaterinburg Standard Time", "Yekaterinburg Summer Time", "Yekaterinburg Time"),
+    ("es", "Acre", "Hora estándar de Acre", "Hora de verano de Acre", "Hora de Acre"),
+    ("es", "Afghanistan", "hora de Afganistán", "", ""),
+    ("es", "Africa_Central", "hora de África central", "", ""),
+    ("es", "Africa_Eastern", "hora de África oriental", "", ""),
+    ("es", "Africa_Southern", "hora de Sudáfrica", "", ""),
+    ("es", "Africa_Western", "hora estándar de África occidental", "hora de verano de África occidental", "hora de África occidental"),
+    ("es", "Alaska", "hora estándar de Alaska", "hora de verano de Alaska", "hora de Alaska"),
+    ("es", "Amazon", "hora estándar del Amazonas", "hora de verano del Amazonas", "hora del Amazonas"),
+    ("es", "America_Central", "hora estándar central", "hora de verano central", "hora central"),
+    ("es", "America_Eastern", "hora estándar oriental", "hora de verano oriental", "hora oriental"),
+    ("es", "America_Mountain", "hora estándar de las Montañas Rocosas", "hora de verano de las Montañas Rocosas", "hora de las Montañas Rocosas"),
+    ("es", "America_Pacific", "hora estándar del Pacífico", "hora de verano del Pacífico", "hora del Pacífico"),
+    ("es", "Anadyr", "hora estándar de Anadyr", "hora de verano de Anadyr", "hora de Anadyr"),
+    ("es", "Apia", "hora estándar de Apia", "horario de verano de Apia", "hora de Apia"),
+    ("es", "Arabian", "hora estándar de Arabia", "hora de verano de Arabia", "hora de Arabia"),
+    ("es", "Argentina", "hora estándar de Argentina", "hora de verano de Argentina", "hora de Argentina"),
+    ("es", "Armenia", "hora estándar de Armenia", "hora de verano de Armenia", "hora de Armenia"),
+    ("es", "Atlantic", "hora estándar del Atlántico", "hora de verano del Atlántico", "hora del Atlántico"),
+    ("es", "Australia_Central", "hora estándar de Australia central", "hora de verano de Australia central", "hora de Australia central"),
+    ("es", "Australia_CentralWestern", "hora estándar de Australia centroccidental", "hora de verano de Australia centroccidental", "hora de Australia centroccidental"),
+    ("es", "Australia_Eastern", "hora estándar de Australia oriental", "hora de verano de Australia oriental", "hora de Australia oriental"),
+    ("es", "Australia_Western", "hora estándar de Australia occidental", "hora de verano de Australia occidental", "hora de Australia occidental"),
+    ("es", "Azerbaijan", "hora estándar de Azerbaiyán", "hora de verano de Azerbaiyán", "hora de Azerbaiyán"),
+    ("es", "Azores", "hora estándar de las Azores", "hora de verano de las Azores", "hora de las Azores"),
+    ("es", "Bangladesh", "hora estándar de Bangladés", "hora de verano de Bangladés", "hora de Bangladés"),
+    ("es", "Bhutan", "hora de Bután", "", ""),
+    ("es", "Bolivia", "hora de Bolivia", "", ""),
+    ("es", "Brasilia", "hora estándar de Brasilia", "hora de verano de Brasilia", "hora de Brasilia"),
+    ("es", "Brunei", "hora de Brunéi", "", ""),
+    ("es", "Cape_Verde", "hora estándar de Cabo Verde", "hora de verano de Cabo Verde", "hora de Cabo Verde"),
+    ("es", "Chamorro", "hora estándar de Chamorro", "", ""),
+    ("es", "Chatham", "hora estándar de Chatham", "hora de verano de Chatham", "hora de Chatham"),
+    ("es", "Chile", "hora estándar de Chile", "hora de verano de Chile", "hora de Chile"),
+    ("es", "China", "hora estándar de China", "hora de verano de China", "hora de China"),
+    ("es", "Christmas", "hora de la Isla de Navidad", "", ""),
+    ("es", "Cocos", "hora de las Islas Cocos", "", ""),
+    ("es", "Colombia", "hora estándar de Colombia", "hora de verano de Colombia", "hora de Colombia"),
+    ("es", "Cook", "hora estándar de las Islas Cook", "hora de verano media de las Islas Cook", "hora de las Islas Cook"),
+    ("es", "Cuba", "hora estándar de Cuba", "hora de verano de Cuba", "hora de Cuba"),
+    ("es", "Davis", "hora de Davis", "", ""),
+    ("es", "DumontDUrville", "hora de Dumont-d’Urville", "", ""),
+    ("es", "East_Timor", "hora de Timor Oriental", "", ""),
+    ("es", "Easter", "hora estándar de la isla de Pascua", "hora de verano de la isla de Pascua", "hora de la isla de Pascua"),
+    ("es", "Ecuador", "hora de Ecuador", "", ""),
+    ("es", "Europe_Central", "hora estándar de Europa central", "hora de verano de Europa central", "hora de Europa central"),
+    ("es", "Europe_Eastern", "hora estándar de Europa oriental", "hora de verano de Europa oriental", "hora de Europa oriental"),
+    ("es", "Europe_Western", "hora estándar de Europa occidental", "hora de verano de Europa occidental", "hora de Europa occidental"),
+    ("es", "Falkland", "hora estándar de las islas Malvinas", "hora de verano de las islas Malvinas", "hora de las islas Malvinas"),
+    ("es", "Fiji", "hora estándar de Fiyi", "hora de verano de Fiyi", "hora de Fiyi"),
+    ("es", "French_Guiana", "hora de la Guayana Francesa", "", ""),
+    ("es", "French_Southern", "hora de Antártida y Territorios Australes Franceses", "", ""),
+    ("es", "GMT", "hora del meridiano de Greenwich", "", ""),
+    ("es", "Galapagos", "hora de Galápagos", "", ""),
+    ("es", "Gambier", "hora de Gambier", "", ""),
+    ("es", "Georgia", "hora estándar de Georgia", "hora de verano de Georgia", "hora de Georgia"),
+    ("es", "Gilbert_Islands", "hora de las islas Gilbert", "", ""),
+    ("es", "Greenland_Eastern", "hora estándar de Groenlandia oriental", "hora de verano de Groenlandia oriental", "hora de Groenlandia oriental"),
+    ("es", "Greenland_Western", "hora estándar de Groenlandia occidental", "hora de verano de Groenlandia occidental", "hora de Groenlandia occidental"),
+    ("es", "Gulf", "hora estándar del Golfo", "", ""),
+    ("es", "Guyana", "hora de Guyana", "", ""),
+    ("es", "Hawaii_Aleutian", "hora estándar de Hawái-Aleutianas", "hora de verano de Hawái-Aleutianas", "hora de Hawái-Aleutianas"),
+    ("es", "Hong_Kong", "hora estándar de Hong Kong", "hora de verano de Hong Kong", "hora de Hong Kong"),
+    ("es", "Hovd", "hora estándar de Hovd", "hora de verano de Hovd", "hora de Hovd"),
+    ("es", "India", "hora estándar de la India", "", ""),
+    ("es", "Indian_Ocean", "hora del océano Índico", "", ""),
+    ("es", "Indochina", "hora de Indochina", "", ""),
+    ("es", "Indonesia_Central", "hora de Indonesia central", "", ""),
+    ("es", "Indonesia_Eastern", "hora de Indonesia oriental", "", ""),
+    ("es", "Indonesia_Western", "hora de Indonesia occidental", "", ""),
+    ("es", "Iran", "hora estándar de Irán", "hora de verano de Irán", "hora de Irán"),
+    ("es", "Irkutsk", "hora estándar de Irkutsk", "hora de verano de Irkutsk", "hora de Irkutsk"),
+    ("es", "Israel", "hora estándar de Israel", "hora de verano de Israel", "hora de Israel"),
+    ("es", "Japan", "hora estándar de Japón", "hora de verano de Japón", "hora de Japón"),
+    ("es", "Kamchatka", "hora estándar de Kamchatka", "hora de verano de Kamchatka", "hora de Kamchatka"),
+    ("es", "Kazakhstan_Eastern", "hora de Kazajistán oriental", "", ""),
+    ("es", "Kazakhstan_Western", "hora de Kazajistán occidental", "", ""),
+    ("es", "Korea", "hora estándar de Corea", "hora de verano de Corea", "hora de Corea"),
+    ("es", "Kosrae", "hora de Kosrae", "", ""),
+    ("es", "Krasnoyarsk", "hora estándar de Krasnoyarsk", "hora de verano de Krasnoyarsk", "hora de Krasnoyarsk"),
+    ("es", "Kyrgystan", "hora de Kirguistán", "", ""),
+    ("es", "Line_Islands", "hora de las Espóradas Ecuatoriales", "", ""),
+    ("es", "Lord_Howe", "hora estándar de Lord Howe", "hora de verano de Lord Howe", "hora de Lord Howe"),
+    ("es", "Magadan", "hora estándar de Magadán", "hora de verano de Magadán", "hora de Magadán"),
+    ("es", "Malaysia", "hora de Malasia", "", ""),
+    ("es", "Maldives", "hora de Maldivas", "", ""),
+    ("es", "Marquesas", "hora de Marquesas", "", ""),
+    ("es", "Marshall_Islands", "hora de las Islas Marshall", "", ""),
+    ("es", "Mauritius", "hora estándar de Mauricio", "hora de verano de Mauricio", "hora de Mauricio"),
+    ("es", "Mawson", "hora de Mawson", "", ""),
+    ("es", "Mexico_Northwest", "hora estándar del noroeste de México", "hora de verano del noroeste de México", "hora del noroeste de México"),
+    ("es", "Mexico_Pacific", "hora estándar del Pacífico de México", "hora de verano del Pacífico de México", "hora del Pacífico de México"),
+    ("es", "Mongolia", "hora estándar de Ulán Bator", "hora de verano de Ulán Bator", "hora de Ulán Bator"),
+    ("es", "Moscow", "hora estándar de Moscú", "hora de verano de Moscú", "hora de Moscú"),
+    ("es", "Myanmar", "hora de Myanmar", "", ""),
+    ("es", "Nauru", "hora de Nauru", "", ""),
+    ("es", "Nepal", "hora de Nepal", "", ""),
+    ("es", "New_Caledonia", "hora estándar de Nueva Caledonia", "hora de verano de Nueva Caledonia", "hora de Nueva Caledonia"),
+    ("es", "New_Zealand", "hora estándar de Nueva Zelanda", "hora de verano de Nueva Zelanda", "hora de Nueva Zelanda"),
+    ("es", "Newfoundland", "hora estándar de Terranova", "hora de verano de Terranova", "hora de Terranova"),
+    ("es", "Niue", "hora de Niue", "", ""),
+    ("es", "Norfolk", "hora estándar de la isla Norfolk", "hora de verano de la isla Norfolk", "hora de la isla Norfolk"),
+    ("es", "Noronha", "hora estándar de Fernando de Noronha", "hora de verano de Fernando de Noronha", "hora de Fernando de Noronha"),
+    ("es", "Novosibirsk", "hora estándar de Novosibirsk", "hora de verano de Novosibirsk", "hora de Novosibirsk"),
+    ("es", "Omsk", "hora estándar de Omsk", "hora de verano de Omsk", "hora de Omsk"),
+    ("es", "Pakistan", "hora estándar de Pakistán", "hora de verano de Pakistán", "hora de Pakistán"),
+    ("es", "Palau", "hora de Palaos", "", ""),
+    ("es", "Papua_New_Guinea", "hora de Papúa Nueva Guinea", "", ""),
+    ("es", "Paraguay", "hora estándar de Paraguay", "hora de verano de Paraguay", "hora de Paraguay"),
+    ("es", "Peru", "hora estándar de Perú", "hora de verano de Perú", "hora de Perú"),
+    ("es", "Philippines", "hora estándar de Filipinas", "hora de verano de Filipinas", "hora de Filipinas"),
+    ("es", "Phoenix_Islands", "hora de las Islas Fénix", "", ""),
+    ("es", "Pierre_Miquelon", "hora estándar de San Pedro y Miquelón", "hora de verano de San Pedro y Miquelón", "hora de San Pedro y Miquelón"),
+    ("es", "Pitcairn", "hora de Pitcairn", "", ""),
+    ("es", "Ponape", "hora de Pohnpei", "", ""),
+    ("es", "Reunion", "hora de Reunión", "", ""),
+    ("es", "Rothera", "hora de Rothera", "", ""),
+    ("es", "Sakhalin", "hora estándar de Sajalín", "hora de verano de Sajalín", "hora de Sajalín"),
+    ("es", "Samara", "hora estándar de Samara", "hora de verano de Samara", "hora de Samara"),
+    ("es", "Samoa", "hora estándar de Samoa", "hora de verano de Samoa", "hora de Samoa"),
+    ("es", "Seychelles", "hora de Seychelles", "", ""),
+    ("es", "Singapore", "hora de Singapur", "", ""),
+    ("es", "Solomon", "hora de las Islas Salomón", "", ""),
+    ("es", "South_Georgia", "hora de Georgia del Sur", "", ""),
+    ("es", "Suriname", "hora de Surinam", "", ""),
+    ("es", "Syowa", "hora de Syowa", "", ""),
+    ("es", "Tahiti", "hora de Tahití", "", ""),
+    ("es", "Taipei", "hora estándar de Taipéi", "hora de verano de Taipéi", "hora de Taipéi"),
+    ("es", "Tajikistan", "hora de Tayikistán", "", ""),
+    ("es", "Tokelau", "hora de Tokelau", "", ""),
+    ("es", "Tonga", "hora estándar de Tonga", "hora de verano de Tonga", "hora de Tonga"),
+    ("es", "Truk", "hora de Chuuk", "", ""),
+    ("es", "Turkmenistan", "hora estándar de Turkmenistán", "hora de verano de Turkmenistán", "hora de Turkmenistán"),
+    ("es", "Tuvalu", "hora de Tuvalu", "", ""),
+    ("es", "Uruguay", "hora estándar de Uruguay", "hora de verano de Uruguay", "hora de Uruguay"),
+    ("es", "Uzbekistan", "hora estándar de Uzbekistán", "hora de verano de Uzbekistán", "hora de Uzbekistán"),
+    ("es", "Vanuatu", "hora estándar de Vanuatu", "hora de verano de Vanuatu", "hora de Vanuatu"),
+    ("es", "Venezuela", "hora de Venezuela", "", ""),
+    ("es", "Vladivostok", "hora estándar de Vladivostok", "hora de verano de Vladivostok", "hora de Vladivostok"),
+    ("es", "Volgograd", "hora estándar de Volgogrado", "hora de verano de Volgogrado", "hora de Volgogrado"),
+    ("es", "Vostok", "hora de Vostok", "", ""),
+    ("es", "Wake", "hora de la isla Wake", "", ""),
+    ("es", "Wallis", "hora de Wallis y Futuna", "", ""),
+    ("es", "Yakutsk", "hora estándar de Yakutsk", "hora de verano de Yakutsk", "hora de Yakutsk"),
+    ("es", "Yekaterinburg", "hora estándar de Ekaterimburgo", "hora de verano de Ekaterimburgo", "hora de Ekaterimburgo"),
+    ("fr", "Acre", "heure normale de l’Acre", "heure d’été de l’Acre", "heure de l’Acre"),
+    ("fr", "Afghanistan", "heure de l’Afghanistan", "", ""),
+    ("fr", "Africa_Central", "heure normale d’Afrique centrale", "", ""),
+    ("fr", "Africa_Eastern", "heure normale d’Afrique de l’Est", "", ""),
+    ("fr", "Africa_Southern", "heure normale d’Afrique méridionale", "", ""),
+    ("fr", "Africa_Western", "heure normale d’Afrique de l’Ouest", "heure d’été d’Afrique de l’Ouest", "heure d’Afrique de l’Ouest"),
+    ("fr", "Alaska", "heure normale de l’Alaska", "heure d’été de l’Alaska", "heure de l’Alaska"),
+    ("fr", "Amazon", "heure normale de l’Amazonie", "heure d’été de l’Amazonie", "heure de l’Amazonie"),
+    ("fr", "America_Central", "heure normale du centre nord-américain", "heure d’été du Centre", "heure du centre nord-américain"),
+    ("fr", "America_Eastern", "heure normale de l’Est nord-américain", "heure d’été de l’Est", "heure de l’Est nord-américain"),
+    ("fr", "America_Mountain", "heure normale des Rocheuses", "heure d’été des Rocheuses", "heure des Rocheuses"),
+    ("fr", "America_Pacific", "heure normale du Pacifique nord-américain", "heure d’été du Pacifique", "heure du Pacifique nord-américain"),
+    ("fr", "Anadyr", "heure normale d’Anadyr", "heure d’été d’Anadyr", "heure d’Anadyr"),
+    ("fr", "Apia", "heure normale d’Apia", "heure d’été d’Apia", "heure d’Apia"),
+    ("fr", "Arabian", "heure normale de l’Arabie", "heure d’été de l’Arabie", "heure de l’Arabie"),
+    ("fr", "Argentina", "heure normale d’Argentine", "heure d’été de l’Argentine", "heure de l’Argentine"),
+    ("fr", "Armenia", "heure normale de l’Arménie", "heure d’été d’Arménie", "heure de l’Arménie"),
+    ("fr", "Atlantic", "heure normale de l’Atlantique", "heure d’été de l’Atlantique", "heure de l’Atlantique"),
+    ("fr", "Australia_Central", "heure normale du centre de l’Australie", "heure d’été du centre de l’Australie", "heure du centre de l’Australie"),
+    ("fr", "Australia_CentralWestern", "heure normale du centre-ouest de l’Australie", "heure d’été du centre-ouest de l’Australie", "heure du centre-ouest de l’Australie"),
+    ("fr", "Australia_Eastern", "heure normale de l’Est de l’Australie", "heure d’été de l’Est de l’Australie", "heure de l’Est de l’Australie"),
+    ("fr", "Australia_Western", "heure normale de l’Ouest de l’Australie", "heure d’été de l’Ouest de l’Australie", "heure de l’Ouest de l’Australie"),
+    ("fr", "Azerbaijan", "heure normale de l’Azerbaïdjan", "heure d’été d’Azerbaïdjan", "heure de l’Azerbaïdjan"),
+    ("fr", "Azores", "heure normale des Açores", "heure d’été des Açores", "heure des Açores"),
+    ("fr", "Bangladesh", "heure normale du Bangladesh", "heure d’été du Bangladesh", "heure du Bangladesh"),
+    ("fr", "Bhutan", "heure du Bhoutan", "", ""),
+    ("fr", "Bolivia", "heure de Bolivie", "", ""),
+    ("fr", "Brasilia", "heure normale de Brasilia", "heure d’été de Brasilia", "heure de Brasilia"),
+    ("fr", "Brunei", "heure du Brunéi", "", ""),
+    ("fr", "Cape_Verde", "heure normale du Cap-Vert", "heure d’été du Cap-Vert", "heure du Cap-Vert"),
+    ("fr", "Chamorro", "heure des Chamorro", "", ""),
+    ("fr", "Chatham", "heure normale des îles Chatham", "heure d’été des îles Chatham", "heure des îles Chatham"),
+    ("fr", "Chile", "heure normale du Chili", "heure d’été du Chili", "heure du Chili"),
+    ("fr", "China", "heure normale de la Chine", "heure d’été de Chine", "heure de la Chine"),
+    ("fr", "Christmas", "heure de l’île Christmas", "", ""),
+    ("fr", "Cocos", "heure des îles Cocos", "", ""),
+    ("fr", "Colombia", "heure normale de Colombie", "heure d’été de Colombie", "heure de Colombie"),
+    ("fr", "Cook", "heure normale des îles Cook", "heure d’été des îles Cook", "heure des îles Cook"),
+    ("fr", "Cuba", "heure normale de Cuba", "heure d’été de Cuba", "heure de Cuba"),
+    ("fr", "Davis", "heure de Davis", "", ""),
+    ("fr", "DumontDUrville", "heure de Dumont-d’Urville", "", ""),
+    ("fr", "East_Timor", "heure du Timor oriental", "", ""),
+    ("fr", "Easter", "heure normale de l’île de Pâques", "heure d’été de l’île de Pâques", "heure de l’île de Pâques"),
+    ("fr", "Ecuador", "heure de l’Équateur", "", ""),
+    ("fr", "Europe_Central", "heure normale d’Europe centrale", "heure d’été d’Europe centrale", "heure d’Europe centrale"),
+    ("fr", "Europe_Eastern", "heure normale d’Europe de l’Est", "heure d’été d’Europe de l’Est", "heure d’Europe de l’Est"),
+    ("fr", "Europe_Western", "heure normale d’Europe de l’Ouest", "heure d’été d’Europe de l’Ouest", "heure d’Europe de l’Ouest"),
+    ("fr", "Falkland", "heure normale des îles Malouines", "heure d’été des îles Malouines", "heure des îles Malouines"),
+    ("fr", "Fiji", "heure normale des îles Fidji", "heure d’été des îles Fidji", "heure des îles Fidji"),
+    ("fr", "French_Guiana", "heure de la Guyane française", "", ""),
+    ("fr", "French_Southern", "heure des Terres australes et antarctiques françaises", "", ""),
+    ("fr", "GMT", "heure moyenne de Greenwich", "", ""),
+    ("fr", "Galapagos", "heure des îles Galápagos", "", ""),
+    ("fr", "Gambier", "heure des îles Gambier", "", ""),
+    ("fr", "Georgia", "heure normale de la Géorgie", "heure d’été de Géorgie", "heure de la Géorgie"),
+    ("fr", "Gilbert_Islands", "heure des îles Gilbert", "", ""),
+    ("fr", "Greenland_Eastern", "heure normale de l’Est du Groenland", "heure d’été de l’Est du Groenland", "heure de l’Est du Groenland"),
+    ("fr", "Greenland_Western", "heure normale de l’Ouest du Groenland", "heure d’été de l’Ouest du Groenland", "heure de l’Ouest du Groenland"),
+    ("fr", "Gulf", "heure du Golfe", "", ""),
+    ("fr", "Guyana", "heure du Guyana", "", ""),
+    ("fr", "Hawaii_Aleutian", "heure normale d’Hawaii - Aléoutiennes", "heure d’été d’Hawaii - Aléoutiennes", "heure d’Hawaii - Aléoutiennes"),
+    ("fr", "Hong_Kong", "heure normale de Hong Kong", "heure d’été de Hong Kong", "heure de Hong Kong"),
+    ("fr", "Hovd", "heure normale de Hovd", "heure d’été de Hovd", "heure de Hovd"),
+    ("fr", "India", "heure de l’Inde", "", ""),
+    ("fr", "Indian_Ocean", "heure de l’Océan Indien", "", ""),
+    ("fr", "Indochina", "heure d’Indochine", "", ""),
+    ("fr", "Indonesia_Central", "heure du Centre indonésien", "", ""),
+    ("fr", "Indonesia_Eastern", "heure de l’Est indonésien", "", ""),
+    ("fr", "Indonesia_Western", "heure de l’Ouest indonésien", "", ""),
+    ("fr", "Iran", "heure normale d’Iran", "heure d’été d’Iran", "heure de l’Iran"),
+    ("fr", "Irkutsk", "heure normale d’Irkoutsk", "heure d’été d’Irkoutsk", "heure d’Irkoutsk"),
+    ("fr", "Israel", "heure normale d’Israël", "heure d’été d’Israël", "heure d’Israël"),
+    ("fr", "Japan", "heure normale du Japon", "heure d’été du Japon", "heure du Japon"),
+    ("fr", "Kamchatka", "heure normale de Petropavlovsk-Kamchatski", "heure d’été de Petropavlovsk-Kamchatski", "heure de Petropavlovsk-Kamchatski"),
+    ("fr", "Kazakhstan_Eastern", "heure de l’Est du Kazakhstan", "", ""),
+    ("fr", "Kazakhstan_Western", "heure de l’Ouest du Kazakhstan", "", ""),
+    ("fr", "Korea", "heure normale de la Corée", "heure d’été de Corée", "heure de la Corée"),
+    ("fr", "Kosrae", "heure de Kosrae", "", ""),
+    ("fr", "Krasnoyarsk", "heure normale de Krasnoïarsk", "heure d’été de Krasnoïarsk", "heure de Krasnoïarsk"),
+    ("fr", "Kyrgystan", "heure du Kirghizistan", "", ""),
+    ("fr", "Line_Islands", "heure des îles de la Ligne", "", ""),
+    ("fr", "Lord_Howe", "heure normale de Lord Howe", "heure d’été de Lord Howe", "heure de Lord Howe"),
+    ("fr", "Magadan", "heure normale de Magadan", "heure d’été de Magadan", "heure de Magadan"),
+    ("fr", "Malaysia", "heure de la Malaisie", "", ""),
+    ("fr", "Maldives", "heure des Maldives", "", ""),
+    ("fr", "Marquesas", "heure des îles Marquises", "", ""),
+    ("fr", "Marshall_Islands", "heure des îles Marshall", "", ""),
+    ("fr", "Mauritius", "heure normale de Maurice", "heure d’été de Maurice", "heure de Maurice"),
+    ("fr", "Mawson", "heure de Mawson", "", ""),
+    ("fr", "Mexico_Northwest", "heure normale du Nord-Ouest du Mexique", "heure d’été du Nord-Ouest du Mexique", "heure du Nord-Ouest du Mexique"),
+    ("fr", "Mexico_Pacific", "heure normale du Pacifique mexicain", "heure d’été du Pacifique mexicain", "heure du Pacifique mexicain"),
+    ("fr", "Mongolia", "heure normale d’Oulan-Bator", "heure d’été d’Oulan-Bator", "heure d’Oulan-Bator"),
+    ("fr", "Moscow", "heure normale de Moscou", "heure d’été de Moscou", "heure de Moscou"),
+    ("fr", "Myanmar", "heure du Myanmar", "", ""),
+    ("fr", "Nauru", "heure de Nauru", "", ""),
+    ("fr", "Nepal", "heure du Népal", "", ""),
+    ("fr", "New_Caledonia", "heure normale de la Nouvelle-Calédonie", "heure d’été de Nouvelle-Calédonie", "heure de la Nouvelle-Calédonie"),
+    ("fr", "New_Zealand", "heure normale de la Nouvelle-Zélande", "heure d’été de la Nouvelle-Zélande", "heure de la Nouvelle-Zélande"),
+    ("fr", "Newfoundland", "heure normale de Terre-Neuve", "heure d’été de Terre-Neuve", "heure de Terre-Neuve"),
+    ("fr", "Niue", "heure de Nioué", "", ""),
+    ("fr", "Norfolk", "heure normale de l’île Norfolk", "heure d’été de l’île Norfolk", "heure de l’île Norfolk"),
+    ("fr", "Noronha", "heure normale de Fernando de Noronha", "heure d’été de Fernando de Noronha", "heure de Fernando de Noronha"),
+    ("fr", "Novosibirsk", "heure normale de Novossibirsk", "heure d’été de Novossibirsk", "heure de Novossibirsk"),
+    ("fr", "Omsk", "heure normale de Omsk", "heure d’été de Omsk", "heure de Omsk"),
+    ("fr", "Pakistan", "heure normale du Pakistan", "heure d’été du Pakistan", "heure du Pakistan"),
+    ("fr", "Palau", "heure des Palaos", "", ""),
+    ("fr", "Papua_New_Guinea", "heure de la Papouasie-Nouvelle-Guinée", "", ""),
+    ("fr", "Paraguay", "heure normale du Paraguay", "heure d’été du Paraguay", "heure du Paraguay"),
+    ("fr", "Peru", "heure normale du Pérou", "heure d’été du Pérou", "heure du Pérou"),
+    ("fr", "Philippines", "heure normale des Philippines", "heure d’été des Philippines", "heure des Philippines"),
+    ("fr", "Phoenix_Islands", "heure des îles Phoenix", "", ""),
+    ("fr", "Pierre_Miquelon", "heure normale de Saint-Pierre-et-Miquelon", "heure d’été de Saint-Pierre-et-Miquelon", "heure de Saint-Pierre-et-Miquelon"),
+    ("fr", "Pitcairn", "heure des îles Pitcairn", "", ""),
+    ("fr", "Ponape", "heure de l’île de Pohnpei", "", ""),
+    ("fr", "Reunion", "heure de La Réunion", "", ""),
+    ("fr", "Rothera", "heure de Rothera", "", ""),
+    ("fr", "Sakhalin", "heure normale de Sakhaline", "heure d’été de Sakhaline", "heure de Sakhaline"),
+    ("fr", "Samara", "heure normale de Samara", "heure d’été de Samara", "heure de Samara"),
+    ("fr", "Samoa", "heure normale des Samoa", "heure d’été des Samoa", "heure des Samoa"),
+    ("fr", "Seychelles", "heure des Seychelles", "", ""),
+    ("fr", "Singapore", "heure de Singapour", "", ""),
+    ("fr", "Solomon", "heure des îles Salomon", "", ""),
+    ("fr", "South_Georgia", "heure de Géorgie du Sud", "", ""),
+    ("fr", "Suriname", "heure du Suriname", "", ""),
+    ("fr", "Syowa", "heure de Syowa", "", ""),
+    ("fr", "Tahiti", "heure de Tahiti", "", ""),
+    ("fr", "Taipei", "heure normale de Taipei", "heure d’été de Taipei", "heure de Taipei"),
+    ("fr", "Tajikistan", "heure du Tadjikistan", "", ""),
+    ("fr", "Tokelau", "heure de Tokelau", "", ""),
+    ("fr", "Tonga", "heure normale des Tonga", "heure d’été de Tonga", "heure des Tonga"),
+    ("fr", "Truk", "heure de Chuuk", "", ""),
+    ("fr", "Turkmenistan", "heure normale du Turkménistan", "heure d’été du Turkménistan", "heure du Turkménistan"),
+    ("fr", "Tuvalu", "heure des Tuvalu", "", ""),
+    ("fr", "Uruguay", "heure normale de l’Uruguay", "heure d’été de l’Uruguay", "heure de l’Uruguay"),
+    ("fr", "Uzbekistan", "heure normale de l’Ouzbékistan", "heure d’été de l’Ouzbékistan", "heure de l’Ouzbékistan"),
+    ("fr", "Vanuatu", "heure normale du Vanuatu", "heure d’été de Vanuatu", "heure du Vanuatu"),
+    ("fr", "Venezuela", "heure du Venezuela", "", ""),
+    ("fr", "Vladivostok", "heure normale de Vladivostok", "heure d’été de Vladivostok", "heure de Vladivostok"),
+    ("fr", "Volgograd", "heure normale de Volgograd", "heure d’été de Volgograd", "heure de Volgograd"),
+    ("fr", "Vostok", "heure de Vostok", "", ""),
+    ("fr", "Wake", "heure de l’île Wake", "", ""),
+    ("fr", "Wallis", "heure de Wallis-et-Futuna", "", ""),
+    ("fr", "Yakutsk", "heure normale de Iakoutsk", "heure d’été de Iakoutsk", "heure de Iakoutsk"),
+    ("fr", "Yekaterinburg", "heure normale d’Ekaterinbourg", "heure d’été d’Ekaterinbourg", "heure d’Ekaterinbourg"),
+    ("it", "Afghanistan", "Ora dell’Afghanistan", "", ""),
+    ("it", "Africa_Central", "Ora dell’Africa centrale", "", ""),
+    ("it", "Africa_Eastern", "Ora dell’Africa orientale", "", ""),
+    ("it", "Africa_Southern", "Ora dell’Africa meridionale", "", ""),
+    ("it", "Africa_Western", "Ora standard dell’Africa occidentale", "Ora legale dell’Africa occidentale", "Ora dell’Africa occidentale"),
+    ("it", "Alaska", "Ora standard dell’Alaska", "Ora legale dell’Alaska", "Ora dell’Alaska"),
+    ("it", "Amazon", "Ora standard dell’Amazzonia", "Ora legale dell’Amazzonia", "Ora dell’Amazzonia"),
+    ("it", "America_Central", "Ora standard centrale USA", "Ora legale centrale USA", "Ora centrale USA"),
+    ("it", "America_Eastern", "Ora standard orientale USA", "Ora legale orientale USA", "Ora orientale USA"),
+    ("it", "America_Mountain", "Ora standard Montagne Rocciose USA", "Ora legale Montagne Rocciose USA", "Ora Montagne Rocciose USA"),
+    ("it", "America_Pacific", "Ora standard del Pacifico USA", "Ora legale del Pacifico USA", "Ora del Pacifico USA"),
+    ("it", "Anadyr", "Ora standard di Anadyr", "Ora legale di Anadyr", "Ora di Anadyr"),
+    ("it", "Apia", "Ora standard di Apia", "Ora legale di Apia", "Ora di Apia"),
+    ("it", "Arabian", "Ora standard araba", "Ora legale araba", "Ora araba"),
+    ("it", "Argentina", "Ora standard dell’Argentina", "Ora legale dell’Argentina", "Ora dell’Argentina"),
+    ("it", "Armenia", "Ora standard dell’Armenia", "Ora legale dell’Armenia", "Ora dell’Armenia"),
+    ("it", "Atlantic", "Ora standard dell’Atlantico", "Ora legale dell’Atlantico", "Ora dell’Atlantico"),
+    ("it", "Australia_Central", "Ora standard dell’Australia centrale", "Ora legale dell’Australia centrale", "Ora dell’Australia centrale"),
+    ("it", "Australia_CentralWestern", "Ora standard dell’Australia centroccidentale", "Ora legale dell’Australia centroccidentale", "Ora dell’Australia centroccidentale"),
+    ("it", "Australia_Eastern", "Ora standard dell’Australia orientale", "Ora legale dell’Australia orientale", "Ora dell’Australia orientale"),
+    ("it", "Australia_Western", "Ora standard dell’Australia occidentale", "Ora legale dell’Australia occidentale", "Ora dell’Australia occidentale"),
+    ("it", "Azerbaijan", "Ora standard dell’Azerbaigian", "Ora legale dell’Azerbaigian", "Ora dell’Azerbaigian"),
+    ("it", "Azores", "Ora standard delle Azzorre", "Ora legale delle Azzorre", "Ora delle Azzorre"),
+    ("it", "Bangladesh", "Ora standard del Bangladesh", "Ora legale del Bangladesh", "Ora del Bangladesh"),
+    ("it", "Bhutan", "Ora del Bhutan", "", ""),
+    ("it", "Bolivia", "Ora della Bolivia", "", ""),
+    ("it", "Brasilia", "Ora standard di Brasilia", "Ora legale di Brasilia", "Ora di Brasilia"),
+    ("it", "Brunei", "Ora del Brunei Darussalam", "", ""),
+    ("it", "Cape_Verde", "Ora standard di Capo Verde", "Ora legale di Capo Verde", "Ora di Capo Verde"),
+    ("it", "Chamorro", "Ora di Chamorro", "", ""),
+    ("it", "Chatham", "Ora standard delle Chatham", "Ora legale delle Chatham", "Ora delle Chatham"),
+    ("it", "Chile", "Ora standard del Cile", "Ora legale del Cile", "Ora del Cile"),
+    ("it", "China", "Ora standard della Cina", "Ora legale della Cina", "Ora della Cina"),
+    ("it", "Christmas", "Ora dell’Isola Christmas", "", ""),
+    ("it", "Cocos", "Ora delle Isole Cocos", "", ""),
+    ("it", "Colombia", "Ora standard della Colombia", "Ora legale della Colombia", "Ora della Colombia"),
+    ("it", "Cook", "Ora standard delle isole Cook", "Ora legale media delle isole Cook", "Ora delle isole Cook"),
+    ("it", "Cuba", "Ora standard di Cuba", "Ora legale di Cuba", "Ora di Cuba"),
+    ("it", "Davis", "Ora di Davis", "", ""),
+    ("it", "DumontDUrville", "Ora di Dumont-d’Urville", "", ""),
+    ("it", "East_Timor", "Ora di Timor Est", "", ""),
+    ("it", "Easter", "Ora standard dell’Isola di Pasqua", "Ora legale dell’Isola di Pasqua", "Ora dell’Isola di Pasqua"),
+    ("it", "Ecuador", "Ora dell’Ecuador", "", ""),
+    ("it", "Europe_Central", "Ora standard dell’Europa centrale", "Ora legale dell’Europa centrale", "Ora dell’Europa centrale"),
+    ("it", "Europe_Eastern", "Ora standard dell’Europa orientale", "Ora legale dell’Europa orientale", "Ora dell’Europa orientale"),
+    ("it", "Europe_Western", "Ora standard dell’Europa occidentale", "Ora legale dell’Europa occidentale", "Ora dell’Europa occidentale"),
+    ("it", "Falkland", "Ora standard delle Isole Falkland", "Ora legale delle Isole Falkland", "Ora delle Isole Falkland"),
+    ("it", "Fiji", "Ora standard delle Figi", "Ora legale delle Figi", "Ora delle Figi"),
+    ("it", "French_Guiana", "Ora della Guiana francese", "", ""),
+    ("it", "French_Southern", "Ora delle Terre australi e antartiche francesi", "", ""),
+    ("it", "GMT", "Ora del meridiano di Greenwich", "", ""),
+    ("it", "Galapagos", "Ora delle Galapagos", "", ""),
+    ("it", "Gambier", "Ora di Gambier", "", ""),
+    ("it", "Georgia", "Ora standard della Georgia", "Ora legale della Georgia", "Ora della Georgia"),
+    ("it", "Gilbert_Islands", "Ora delle isole Gilbert", "", ""),
+    ("it", "Greenland_Eastern", "Ora standard della Groenlandia orientale", "Ora legale della Groenlandia orientale", "Ora della Groenlandia orientale"),
+    ("it", "Greenland_Western", "Ora standard della Groenlandia occidentale", "Ora legale della Groenlandia occidentale", "Ora della Groenlandia occidentale"),
+    ("it", "Gulf", "Ora del Golfo", "", ""),
+    ("it", "Guyana", "Ora della Guyana", "", ""),
+    ("it", "Hawaii_Aleutian", "Ora standard delle Isole Hawaii-Aleutine", "Ora legale delle Isole Hawaii-Aleutine", "Ora delle isole Hawaii-Aleutine"),
+    ("it", "Hong_Kong", "Ora standard di Hong Kong", "Ora legale di Hong Kong", "Ora di Hong Kong"),
+    ("it", "Hovd", "Ora standard di Hovd", "Ora legale di Hovd", "Ora di Hovd"),
+    ("it", "India", "Ora standard dell’India", "", ""),
+    ("it", "Indian_Ocean", "Ora dell’Oceano Indiano", "", ""),
+    ("it", "Indochina", "Ora dell’Indocina", "", ""),
+    ("it", "Indonesia_Central", "Ora dell’Indonesia centrale", "", ""),
+    ("it", "Indonesia_Eastern", "Ora dell’Indonesia orientale", "", ""),
+    ("it", "Indonesia_Western", "Ora dell’Indonesia occidentale", "", ""),
+    ("it", "Iran", "Ora standard dell’Iran", "Ora legale dell’Iran", "Ora dell’Iran"),
+    ("it", "Irkutsk", "Ora standard di Irkutsk", "Ora legale di Irkutsk", "Ora di Irkutsk"),
+    ("it", "Israel", "Ora standard di Israele", "Ora legale di Israele", "Ora di Israele"),
+    ("it", "Japan", "Ora standard del Giappone", "Ora legale del Giappone", "Ora del Giappone"),
+    ("it", "Kamchatka", "Ora standard di Petropavlovsk-Kamchatski", "Ora legale di Petropavlovsk-Kamchatski", "Ora di Petropavlovsk-Kamchatski"),
+    ("it", "Kazakhstan_Eastern", "Ora del Kazakistan orientale", "", ""),
+    ("it", "Kazakhstan_Western", "Ora del Kazakistan occidentale", "", ""),
+    ("it", "Korea", "Ora standard coreana", "Ora legale coreana", "Ora coreana"),
+    ("it", "Kosrae", "Ora del Kosrae", "", ""),
+    ("it", "Krasnoyarsk", "Ora standard di Krasnoyarsk", "Ora legale di Krasnoyarsk", "Ora di Krasnoyarsk"),
+    ("it", "Kyrgystan", "Ora del Kirghizistan", "", ""),
+    ("it", "Line_Islands", "Ora delle Sporadi equatoriali", "", ""),
+    ("it", "Lord_Howe", "Ora standard di Lord Howe", "Ora legale di Lord Howe", "Ora di Lord Howe"),
+    ("it", "Magadan", "Ora standard di Magadan", "Ora legale di Magadan", "Ora di Magadan"),
+    ("it", "Malaysia", "Ora della Malesia", "", ""),
+    ("it", "Maldives", "Ora delle Maldive", "", ""),
+    ("it", "Marquesas", "Ora delle Marchesi", "", ""),
+    ("it", "Marshall_Islands", "Ora delle Isole Marshall", "", ""),
+    ("it", "Mauritius", "Ora standard delle Mauritius", "Ora legale delle Mauritius", "Ora delle Mauritius"),
+    ("it", "Mawson", "Ora di Mawson", "", ""),
+    ("it", "Mexico_Northwest", "Ora standard del Messico nord-occidentale", "Ora legale del Messico nord-occidentale", "Ora del Messico nord-occidentale"),
+    ("it", "Mexico_Pacific", "Ora standard del Pacifico (Messico)", "Ora legale del Pacifico (Messico)", "Ora del Pacifico (Messico)"),
+    ("it", "Mongolia", "Ora standard di Ulan Bator", "Ora legale di Ulan Bator", "Ora di Ulan Bator"),
+    ("it", "Moscow", "Ora standard di Mosca", "Ora legale di Mosca", "Ora di Mosca"),
+    ("it", "Myanmar", "Ora della Birmania", "", ""),
+    ("it", "Nauru", "Ora di Nauru", "", ""),
+    ("it", "Nepal", "Ora del Nepal", "", ""),
+    ("it", "New_Caledonia", "Ora standard della Nuova Caledonia", "Ora legale della Nuova Caledonia", "Ora della Nuova Caledonia"),
+    ("it", "New_Zealand", "Ora standard della Nuova Zelanda", "Ora legale della Nuova Zelanda", "Ora della Nuova Zelanda"),
+    ("it", "Newfoundland", "Ora standard di Terranova", "Ora legale di Terranova", "Ora di Terranova"),
+    ("it", "Niue", "Ora di Niue", "", ""),
+    ("it", "Norfolk", "Ora standard delle Isole Norfolk", "Ora legale delle Isole Norfolk", "Ora delle Isole Norfolk"),
+    ("it", "Noronha", "Ora standard di Fernando de Noronha", "Ora legale di Fernando de Noronha", "Ora di Fernando de Noronha"),
+    ("it", "Novosibirsk", "Ora standard di Novosibirsk", "Ora legale di Novosibirsk", "Ora di Novosibirsk"),
+    ("it", "Omsk", "Ora standard di Omsk", "Ora legale di Omsk", "Ora di Omsk"),
+    ("it", "Pakistan", "Ora standard del Pakistan", "Ora legale del Pakistan", "Ora del Pakistan"),
+    ("it", "Palau", "Ora di Palau", "", ""),
+    ("it", "Papua_New_Guinea", "Ora della Papua Nuova Guinea", "", ""),
+    ("it", "Paraguay", "Ora standard del Paraguay", "Ora legale del Paraguay", "Ora del Paraguay"),
+    ("it", "Peru", "Ora standard del Perù", "Ora legale del Perù", "Ora del Perù"),
+    ("it", "Philippines", "Ora standard delle Filippine", "Ora legale delle Filippine", "Ora delle Filippine"),
+    ("it", "Phoenix_Islands", "Ora delle Isole della Fenice", "", ""),
+    ("it", "Pierre_Miquelon", "Ora standard di Saint-Pierre e Miquelon", "Ora legale di Saint-Pierre e Miquelon", "Ora di Saint-Pierre e Miquelon"),
+    ("it", "Pitcairn", "Ora delle Pitcairn", "", ""),
+    ("it", "Ponape", "Ora di Pohnpei", "", ""),
+    ("it", "Reunion", "Ora di Riunione", "", ""),
+    ("it", "Rothera", "Ora di Rothera", "", ""),
+    ("it", "Sakhalin", "Ora standard di Sakhalin", "Ora legale di Sakhalin", "Ora di Sakhalin"),
+    ("it", "Samara", "Ora standard di Samara", "Ora legale di Samara", "Ora di Samara"),
+    ("it", "Samoa", "Ora standard di Samoa", "Ora legale di Samoa", "Ora di Samoa"),
+    ("it", "Seychelles", "Ora delle Seychelles", "", ""),
+    ("it", "Singapore", "Ora di Singapore", "", ""),
+    ("it", "Solomon", "Ora delle Isole Salomone", "", ""),
+    ("it", "South_Georgia", "Ora della Georgia del Sud", "", ""),
+    ("it", "Suriname", "Ora del Suriname", "", ""),
+    ("it", "Syowa", "Ora di Syowa", "", ""),
+    ("it", "Tahiti", "Ora di Tahiti", "", ""),
+    ("it", "Taipei", "Ora standard di Taipei", "Ora legale di Taipei", "Ora di Taipei"),
+    ("it", "Tajikistan", "Ora del Tagikistan", "", ""),
+    ("it", "Tokelau", "Ora di Tokelau", "", ""),
+    ("it", "Tonga", "Ora standard di Tonga", "Ora legale di Tonga", "Ora di Tonga"),
+    ("it", "Truk", "Ora del Chuuk", "", ""),
+    ("it", "Turkmenistan", "Ora standard del Turkmenistan", "Ora legale del Turkmenistan", "Ora del Turkmenistan"),
+    ("it", "Tuvalu", "Ora di Tuvalu", "", ""),
+    ("it", "Uruguay", "Ora standard dell’Uruguay", "Ora legale dell’Uruguay", "Ora dell’Uruguay"),
+    ("it", "Uzbekistan", "Ora standard dell’Uzbekistan", "Ora legale dell’Uzbekistan", "Ora dell’Uzbekistan"),
+    ("it", "Vanuatu", "Ora standard del Vanuatu", "Ora legale del Vanuatu", "Ora del Vanuatu"),
+    ("it", "Venezuela", "Ora del Venezuela", "", ""),
+    ("it", "Vladivostok", "Ora standard di Vladivostok", "Ora legale di Vladivostok", "Ora di Vladivostok"),
+    ("it", "Volgograd", "Ora standard di Volgograd", "Ora legale di Volgograd", "Ora di Volgograd"),
+    ("it", "Vostok", "Ora di Vostok", "", ""),
+    ("it", "Wake", "Ora dell’Isola di Wake", "", ""),
+    ("it", "Wallis", "Ora di Wallis e Futuna", "", ""),
+    ("it", "Yakutsk", "Ora standard di Yakutsk", "Ora legale di Yakutsk", "Ora di Yakutsk"),
+    ("it", "Yekaterinburg", "Ora standard di Ekaterinburg", "Ora legale di Ekaterinburg", "Ora di Ekaterinburg"),
+    ("pt", "Acre", "Horário Padrão do Acre", "Horário de Verão do Acre", "Horário do Acre"),
+    ("pt", "Afghanistan", "Horário do Afeganistão", "", ""),
+    ("pt", "Africa_Central", "Horário da África Central", "", ""),
+    ("pt", "Africa_Eastern", "Horário da África Oriental", "", ""),
+    ("pt", "Africa_Southern", "Horário da África do Sul", "", ""),
+    ("pt", "Africa_Western", "Horário Padrão da África Ocidental", "Horário de Verão da África Ocidental", "Horário da África Ocidental"),
+    ("pt", "Alaska", "Horário Padrão do Alasca", "Horário de Verão do Alasca", "Horário do Alasca"),
+    ("pt", "Amazon", "Horário Padrão do Amazonas", "Horário de Verão do Amazonas", "Horário do Amazonas"),
+    ("pt", "America_Central", "Horário Padrão Central", "Horário de Verão Central", "Horário Central"),
+    ("pt", "America_Eastern", "Horário Padrão do Leste", "Horário de Verão do Leste", "Horário do Leste"),
+    ("pt", "America_Mountain", "Horário Padrão das Montanhas", "Horário de Verão das Montanhas", "Horário das Montanhas"),
+    ("pt", "America_Pacific", "Horário Padrão do Pacífico", "Horário de Verão do Pacífico", "Horário do Pacífico"),
+    ("pt", "Anadyr", "Horário Padrão do Anadyr", "Horário de Verão do Anadyr", "Horário de Anadyr"),
+    ("pt", "Apia", "Horário Padrão de Apia", "Horário de Verão de Apia", "Horário de Apia"),
+    ("pt", "Arabian", "Horário Padrão da Arábia", "Horário de Verão da Arábia", "Horário da Arábia"),
+    ("pt", "Argentina", "Horário Padrão da Argentina", "Horário de Verão da Argentina", "Horário da Argentina"),
+    ("pt", "Armenia", "Horário Padrão da Armênia", "Horário de Verão da Armênia", "Horário da Armênia"),
+    ("pt", "Atlantic", "Horário Padrão do Atlântico", "Horário de Verão do Atlântico", "Horário do Atlântico"),
+    ("pt", "Australia_Central", "Horário Padrão da Austrália Central", "Horário de Verão da Austrália Central", "Horário da Austrália Central"),
+    ("pt", "Australia_CentralWestern", "Horário Padrão da Austrália Centro-Ocidental", "Horário de Verão da Austrália Centro-Ocidental", "Horário da Austrália Centro-Ocidental"),
+    ("pt", "Australia_Eastern", "Horário Padrão da Austrália Oriental", "Horário de Verão da Austrália Oriental", "Horário da Austrália Oriental"),
+    ("pt", "Australia_Western", "Horário Padrão da Austrália Ocidental", "Horário de Verão da Austrália Ocidental", "Horário da Austrália Ocidental"),
+    ("pt", "Azerbaijan", "Horário Padrão do Arzeibaijão", "Horário de Verão do Arzeibaijão", "Horário do Arzeibaijão"),
+    ("pt", "Azores", "Horário Padrão dos Açores", "Horário de Verão dos Açores", "Horário dos Açores"),
+    ("pt", "Bangladesh", "Horário Padrão de Bangladesh", "Horário de Verão de Bangladesh", "Horário de Bangladesh"),
+    ("pt", "Bhutan", "Horário do Butão", "", ""),
+    ("pt", "Bolivia", "Horário da Bolívia", "", ""),
+    ("pt", "Brasilia", "Horário Padrão de Brasília", "Horário de Verão de Brasília", "Horário de Brasília"),
+    ("pt", "Brunei", "Horário de Brunei Darussalam", "", ""),
+    ("pt", "Cape_Verde", "Horário Padrão de Cabo Verde", "Horário de Verão de Cabo Verde", "Horário de Cabo Verde"),
+    ("pt", "Chamorro", "Horário de Chamorro", "", ""),
+    ("pt", "Chatham", "Horário Padrão de Chatham", "Horário de Verão de Chatham", "Horário de Chatham"),
+    ("pt", "Chile", "Horário Padrão do Chile", "Horário de Verão do Chile", "Horário do Chile"),
+    ("pt", "China", "Horário Padrão da China", "Horário de Verão da China", "Horário da China"),
+    ("pt", "Christmas", "Horário da Ilha Christmas", "", ""),
+    ("pt", "Cocos", "Horário das Ilhas Coco", "", ""),
+    ("pt", "Colombia", "Horário Padrão da Colômbia", "Horário de Verão da Colômbia", "Horário da Colômbia"),
+    ("pt", "Cook", "Horário Padrão das Ilhas Cook", "Meio Horário de Verão das Ilhas Cook", "Horário das Ilhas Cook"),
+    ("pt", "Cuba", "Horário Padrão de Cuba", "Horário de Verão de Cuba", "Horário de Cuba"),
+    ("pt", "Davis", "Horário de Davis", "", ""),
+    ("pt", "DumontDUrville", "Horário de Dumont-d’Urville", "", ""),
+    ("pt", "East_Timor", "Horário do Timor-Leste", "", ""),
+    ("pt", "Easter", "Horário Padrão da Ilha de Páscoa", "Horário de Verão da Ilha de Páscoa", "Horário da Ilha de Páscoa"),
+    ("pt", "Ecuador", "Horário do Equador", "", ""),
+    ("pt", "Europe_Central", "Horário Padrão da Europa Central", "Horário de Verão da Europa Central", "Horário da Europa Central"),
+    ("pt", "Europe_Eastern", "Horário Padrão da Europa Oriental", "Horário de Verão da Europa Oriental", "Horário da Europa Oriental"),
+    ("pt", "Europe_Western", "Horário Padrão da Europa Ocidental", "Horário de Verão da Europa Ocidental", "Horário da Europa Ocidental"),
+    ("pt", "Falkland", "Horário Padrão das Ilhas Malvinas", "Horário de Verão das Ilhas Malvinas", "Horário das Ilhas Malvinas"),
+    ("pt", "Fiji", "Horário Padrão de Fiji", "Horário de Verão de Fiji", "Horário de Fiji"),
+    ("pt", "French_Guiana", "Horário da Guiana Francesa", "", ""),
+    ("pt", "French_Southern", "Horário dos Territórios Franceses do Sul e Antártida", "", ""),
+    ("pt", "GMT", "Horário do Meridiano de Greenwich", "", ""),
+    ("pt", "Galapagos", "Horário de Galápagos", "", ""),
+    ("pt", "Gambier", "Horário de Gambier", "", ""),
+    ("pt", "Georgia", "Horário Padrão da Geórgia", "Horário de Verão da Geórgia", "Horário da Geórgia"),
+    ("pt", "Gilbert_Islands", "Horário das Ilhas Gilberto", "", ""),
+    ("pt", "Greenland_Eastern", "Horário Padrão da Groelândia Oriental", "Horário de Verão da Groelândia Oriental", "Horário da Groelândia Oriental"),
+    ("pt", "Greenland_Western", "Horário Padrão da Groenlândia Ocidental", "Horário de Verão da Groenlândia Ocidental", "Horário da Groenlândia Ocidental"),
+    ("pt", "Gulf", "Horário do Golfo", "", ""),
+    ("pt", "Guyana", "Horário da Guiana", "", ""),
+    ("pt", "Hawaii_Aleutian", "Horário Padrão do Havaí e Ilhas Aleutas", "Horário de Verão do Havaí e Ilhas Aleutas", "Horário do Havaí e Ilhas Aleutas"),
+    ("pt", "Hong_Kong", "Horário Padrão de Hong Kong", "Horário de Verão de Hong Kong", "Horário de Hong Kong"),
+    ("pt", "Hovd", "Horário Padrão de Hovd", "Horário de Verão de Hovd", "Horário de Hovd"),
+    ("pt", "India", "Horário Padrão da Índia", "", ""),
+    ("pt", "Indian_Ocean", "Horário do Oceano Índico", "", ""),
+    ("pt", "Indochina", "Horário da Indochina", "", ""),
+    ("pt", "Indonesia_Central", "Horário da Indonésia Central", "", ""),
+    ("pt", "Indonesia_Eastern", "Horário da Indonésia Oriental", "", ""),
+    ("pt", "Indonesia_Western", "Horário da Indonésia Ocidental", "", ""),
+    ("pt", "Iran", "Horário Padrão do Irã", "Horário de Verão do Irã", "Horário do Irã"),
+    ("pt", "Irkutsk", "Horário Padrão de Irkutsk", "Horário de Verão de Irkutsk", "Horário de Irkutsk"),
+    ("pt", "Israel", "Horário Padrão de Israel", "Horário de Verão de Israel", "Horário de Israel"),
+    ("pt", "Japan", "Horário Padrão do Japão", "Horário de Verão do Japão", "Horário do Japão"),
+    ("pt", "Kamchatka", "Horário Padrão de Petropavlovsk-Kamchatski", "Horário de Verão de Petropavlovsk-Kamchatski", "Horário de Petropavlovsk-Kamchatski"),
+    ("pt", "Kazakhstan_Eastern", "Horário do Casaquistão Oriental", "", ""),
+    ("pt", "Kazakhstan_Western", "Horário do Casaquistão Ocidental", "", ""),
+    ("pt", "Korea", "Horário Padrão da Coreia", "Horário de Verão da Coreia", "Horário da Coreia"),
+    ("pt", "Kosrae", "Horário de Kosrae", "", ""),
+    ("pt", "Krasnoyarsk", "Horário Padrão de Krasnoyarsk", "Horário de Verão de Krasnoyarsk", "Horário de Krasnoyarsk"),
+    ("pt", "Kyrgystan", "Horário do Quirguistão", "", ""),
+    ("pt", "Line_Islands", "Horário das Ilhas da Linha", "", ""),
+    ("pt", "Lord_Howe", "Horário Padrão de Lord Howe", "Horário de Verão de Lord Howe", "Horário de Lord Howe"),
+    ("pt", "Magadan", "Horário Padrão de Magadan", "Horário de Verão de Magadan", "Horário de Magadan"),
+    ("pt", "Malaysia", "Horário da Malásia", "", ""),
+    ("pt", "Maldives", "Horário das Ilhas Maldivas", "", ""),
+    ("pt", "Marquesas", "Horário das Marquesas", "", ""),
+    ("pt", "Marshall_Islands", "Horário das Ilhas Marshall", "", ""),
+    ("pt", "Mauritius", "Horário Padrão de Maurício", "Horário de Verão de Maurício", "Horário de Maurício"),
+    ("pt", "Mawson", "Horário de Mawson", "", ""),
+    ("pt", "Mexico_Northwest", "Horário Padrão do Noroeste do México", "Horário de Verão do Noroeste do México", "Horário do Noroeste do México"),
+    ("pt", "Mexico_Pacific", "Horário Padrão do Pacífico Mexicano", "Horário de Verão do Pacífico Mexicano", "Horário do Pacífico Mexicano"),
+    ("pt", "Mongolia", "Horário Padrão de Ulan Bator", "Horário de Verão de Ulan Bator", "Horário de Ulan Bator"),
+    ("pt", "Moscow", "Horário Padrão de Moscou", "Horário de Verão de Moscou", "Horário de Moscou"),
+    ("pt", "Myanmar", "Horário de Mianmar", "", ""),
+    ("pt", "Nauru", "Horário de Nauru", "", ""),
+    ("pt", "Nepal", "Horário do Nepal", "", ""),
+    ("pt", "New_Caledonia", "Horário Padrão da Nova Caledônia", "Horário de Verão da Nova Caledônia", "Horário da Nova Caledônia"),
+    ("pt", "New_Zealand", "Horário Padrão da Nova Zelândia", "Horário de Verão da Nova Zelândia", "Horário da Nova Zelândia"),
+    ("pt", "Newfoundland", "Horário Padrão da Terra Nova", "Horário de Verão da Terra Nova", "Horário da Terra Nova"),
+    ("pt", "Niue", "Horário de Niue", "", ""),
+    ("pt", "Norfolk", "Horário Padrão da Ilha Norfolk", "Horário de Verão da Ilha Norfolk", "Horário da Ilha Norfolk"),
+    ("pt", "Noronha", "Horário Padrão de Fernando de Noronha", "Horário de Verão de Fernando de Noronha", "Horário de Fernando de Noronha"),
+    ("pt", "Novosibirsk", "Horário Padrão de Novosibirsk", "Horário de Verão de Novosibirsk", "Horário de Novosibirsk"),
+    ("pt", "Omsk", "Horário Padrão de Omsk", "Horário de Verão de Omsk", "Horário de Omsk"),
+    ("pt", "Pakistan", "Horário Padrão do Paquistão", "Horário de Verão do Paquistão", "Horário do Paquistão"),
+    ("pt", "Palau", "Horário de Palau", "", ""),
+    ("pt", "Papua_New_Guinea", "Horário de Papua-Nova Guiné", "", ""),
+    ("pt", "Paraguay", "Horário Padrão do Paraguai", "Horário de Verão do Paraguai", "Horário do Paraguai"),
+    ("pt", "Peru", "Horário Padrão do Peru", "Horário de Verão do Peru", "Horário do Peru"),
+    ("pt", "Philippines", "Horário Padrão das Filipinas", "Horário de Verão das Filipinas", "Horário das Filipinas"),
+    ("pt", "Phoenix_Islands", "Horário das Ilhas Fênix", "", ""),
+    ("pt", "Pierre_Miquelon", "Horário Padrão de São Pedro e Miquelão", "Horário Verão de São Pedro e Miquelão", "Horário de São Pedro e Miquelão"),
+    ("pt", "Pitcairn", "Horário de Pitcairn", "", ""),
+    ("pt", "Ponape", "Horário de Ponape", "", ""),
+    ("pt", "Reunion", "Horário de Reunião", "", ""),
+    ("pt", "Rothera", "Horário de Rothera", "", ""),
+    ("pt", "Sakhalin", "Horário Padrão de Sacalina", "Horário de Verão de Sacalina", "Horário de Sacalina"),
+    ("pt", "Samara", "Horário Padrão de Samara", "Horário de Verão de Samara", "Horário de Samara"),
+    ("pt", "Samoa", "Horário Padrão de Samoa", "Horário de Verão de Samoa", "Horário de Samoa"),
+    ("pt", "Seychelles", "Horário de Seicheles", "", ""),
+    ("pt", "Singapore", "Horário Padrão de Singapura", "", ""),
+    ("pt", "Solomon", "Horário das Ilhas Salomão", "", ""),
+    ("pt", "South_Georgia", "Horário da Geórgia do Sul", "", ""),
+    ("pt", "Suriname", "Horário do Suriname", "", ""),
+    ("pt", "Syowa", "Horário de Syowa", "", ""),
+    ("pt", "Tahiti", "Horário do Taiti", "", ""),
+    ("pt", "Taipei", "Horário Padrão de Taipei", "Horário de Verão de Taipei", "Horário de Taipei"),
+    ("pt", "Tajikistan", "Horário do Tajiquistão", "", ""),
+    ("pt", "Tokelau", "Horário de Tokelau", "", ""),
+    ("pt", "Tonga", "Horário Padrão de Tonga", "Horário de Verão de Tonga", "Horário de Tonga"),
+    ("pt", "Truk", "Horário de Chuuk", "", ""),
+    ("pt", "Turkmenistan", "Horário Padrão do Turcomenistão", "Horário de Verão do Turcomenistão", "Horário do Turcomenistão"),
+    ("pt", "Tuvalu", "Horário de Tuvalu", "", ""),
+    ("pt", "Uruguay", "Horário Padrão do Uruguai", "Horário de Verão do Uruguai", "Horário do Uruguai"),
+    ("pt", "Uzbekistan", "Horário Padrão do Uzbequistão", "Horário de Verão do Uzbequistão", "Horário do Uzbequistão"),
+    ("pt", "Vanuatu", "Horário Padrão de Vanuatu", "Horário de Verão de Vanuatu", "Horário de Vanuatu"),
+    ("pt", "Venezuela", "Horário da Venezuela", "", ""),
+    ("pt", "Vladivostok", "Horário Padrão de Vladivostok", "Horário de Verão de Vladivostok", "Horário de Vladivostok"),
+    ("pt", "Volgograd", "Horário Padrão de Volgogrado", "Horário de Verão de Volgogrado", "Horário de Volgogrado"),
+    ("pt", "Vostok", "Horário de Vostok", "", ""),
+    ("pt", "Wake", "Horário das Ilhas Wake", "", ""),
+    ("pt", "Wallis", "Horário de Wallis e Futuna", "", ""),
+    ("pt", "Yakutsk", "Horário Padrão de Yakutsk", "Horário de Verão de Yakutsk", "Horário de Yakutsk"),
+    ("pt", "Yekaterinburg", "Horário Padrão de Ecaterimburgo", "Horário de Verão de Ecaterimburgo", "Horário de Ecaterimburgo"),
+];
+
+/// Zones with names of their own (ie. "British Summer Time"), as (locale, zone, standard, daylight, generic), sorted
+const ZONE_NAMES: &[(&str, &str, &str, &str, &str)] = &[
+    ("de", "Europe/Dublin", "Mittlere Greenwich-Zeit", "Irische Sommerzeit", ""),
+    ("de", "Europe/London", "Mittlere Greenwich-Zeit", "Britische Sommerzeit", ""),
+    ("en", "Europe/Dublin", "Greenwich Mean Time", "Irish Standard Time", ""),
+    ("en", "Europe/London", "Greenwich Mean Time", "British Summer Time", ""),
+    ("es", "Europe/Dublin", "hora del meridiano de Greenwich", "hora de verano de Irlanda", ""),
+    ("es", "Europe/London", "hora del meridiano de Greenwich", "hora de verano británica", ""),
+    ("fr", "Europe/Dublin", "heure moyenne de Greenwich", "heure d’été irlandaise", ""),
+    ("fr", "Europe/London", "heure moyenne de Greenwich", "heure d’été britannique", ""),
+    ("it", "Europe/Dublin", "Ora del meridiano di Greenwich", "Ora legale dell’Irlanda", ""),
+    ("it", "Europe/London", "Ora del meridiano di Greenwich", "Ora legale del Regno Unito", ""),
+    ("pt", "Europe/Dublin", "Horário do Meridiano de Greenwich", "Horário Padrão Irlandês", ""),
+    ("pt", "Europe/London", "Horário do Meridiano de Greenwich", "Horário de Verão Britânico", ""),
+];
+
+/// Exemplar cities as (locale, zone, city), sorted. The others are the last part of the zone name, without underscores.
+const CITIES: &[(&str, &str, &str)] = &[
+    ("de", "Africa/Addis_Ababa", "Addis Abeba"),
+    ("de", "Africa/Algiers", "Algier"),
+    ("de", "Africa/Cairo", "Kairo"),
+    ("de", "Africa/Dar_es_Salaam", "Daressalam"),
+    ("de", "Africa/Djibouti", "Dschibuti"),
+    ("de", "Africa/El_Aaiun", "El Aaiún"),
+    ("de", "Africa/Khartoum", "Khartum"),
+    ("de", "Africa/Lome", "Lomé"),
+    ("de", "Africa/Mogadishu", "Mogadischu"),
+    ("de", "Africa/Ndjamena", "N’Djamena"),
+    ("de", "Africa/Porto-Novo", "Porto Novo"),
+    ("de", "Africa/Sao_Tome", "São Tomé"),
+    ("de", "Africa/Tripoli", "Tripolis"),
+    ("de", "America/Argentina/Cordoba", "Córdoba"),
+    ("de", "America/Asuncion", "Asunción"),
+    ("de", "America/Bogota", "Bogotá"),
+    ("de", "America/Cayman", "Kaimaninseln"),
+    ("de", "America/Curacao", "Curaçao"),
+    ("de", "America/Havana", "Havanna"),
+    ("de", "America/Jamaica", "Jamaika"),
+    ("de", "America/Mexico_City", "Mexiko-Stadt"),
+    ("de", "America/Sao_Paulo", "São Paulo"),
+    ("de", "America/St_Barthelemy", "Saint-Barthélemy"),
+    ("de", "Antarctica/Vostok", "Wostok"),
+    ("de", "Asia/Aqtobe", "Aktobe"),
+    ("de", "Asia/Ashgabat", "Aşgabat"),
+    ("de", "Asia/Baghdad", "Bagdad"),
+    ("de", "Asia/Bishkek", "Bischkek"),
+    ("de", "Asia/Brunei", "Brunei Darussalam"),
+    ("de", "Asia/Chita", "Tschita"),
+    ("de", "Asia/Damascus", "Damaskus"),
+    ("de", "Asia/Dushanbe", "Duschanbe"),
+    ("de", "Asia/Ho_Chi_Minh", "Ho-Chi-Minh-Stadt"),
+    ("de", "Asia/Hong_Kong", "Hongkong"),
+    ("de", "Asia/Hovd", "Chowd"),
+    ("de", "Asia/Kamchatka", "Kamtschatka"),
+    ("de", "Asia/Karachi", "Karatschi"),
+    ("de", "Asia/Khandyga", "Chandyga"),
+    ("de", "Asia/Kolkata", "Kalkutta"),
+    ("de", "Asia/Krasnoyarsk", "Krasnojarsk"),
+    ("de", "Asia/Muscat", "Maskat"),
+    ("de", "Asia/Nicosia", "Nikosia"),
+    ("de", "Asia/Novokuznetsk", "Nowokuznetsk"),
+    ("de", "Asia/Novosibirsk", "Nowosibirsk"),
+    ("de", "Asia/Pyongyang", "Pjöngjang"),
+    ("de", "Asia/Qatar", "Katar"),
+    ("de", "Asia/Qostanay", "Qostanai"),
+    ("de", "Asia/Qyzylorda", "Qysylorda"),
+    ("de", "Asia/Riyadh", "Riad"),
+    ("de", "Asia/Sakhalin", "Sachalin"),
+    ("de", "Asia/Singapore", "Singapur"),
+    ("de", "Asia/Taipei", "Taipeh"),
+    ("de", "Asia/Tashkent", "Taschkent"),
+    ("de", "Asia/Tbilisi", "Tiflis"),
+    ("de", "Asia/Tehran", "Teheran"),
+    ("de", "Asia/Tokyo", "Tokio"),
+    ("de", "Asia/Ulaanbaatar", "Tschoibalsan"),
+    ("de", "Asia/Urumqi", "Ürümqi"),
+    ("de", "Asia/Vladivostok", "Wladiwostok"),
+    ("de", "Asia/Yakutsk", "Jakutsk"),
+    ("de", "Asia/Yangon", "Rangun"),
+    ("de", "Asia/Yekaterinburg", "Jekaterinburg"),
+    ("de", "Asia/Yerevan", "Eriwan"),
+    ("de", "Atlantic/Azores", "Azoren"),
+    ("de", "Atlantic/Canary", "Kanaren"),
+    ("de", "Atlantic/Cape_Verde", "Cabo Verde"),
+    ("de", "Atlantic/Faroe", "Färöer"),
+    ("de", "Atlantic/Reykjavik", "Reyk\u{AD}ja\u{AD}vík"),
+    ("de", "Atlantic/South_Georgia", "Südgeorgien"),
+    ("de", "Europe/Astrakhan", "Astrachan"),
+    ("de", "Europe/Athens", "Athen"),
+    ("de", "Europe/Belgrade", "Belgrad"),
+    ("de", "Europe/Brussels", "Brüssel"),
+    ("de", "Europe/Bucharest", "Bukarest"),
+    ("de", "Europe/Busingen", "Büsingen"),
+    ("de", "Europe/Chisinau", "Kischinau"),
+    ("de", "Europe/Copenhagen", "Kopenhagen"),
+    ("de", "Europe/Kirov", "Kirow"),
+    ("de", "Europe/Kyiv", "Saporischja"),
+    ("de", "Europe/Lisbon", "Lissabon"),
+    ("de", "Europe/Luxembourg", "Luxemburg"),
+    ("de", "Europe/Moscow", "Moskau"),
+    ("de", "Europe/Prague", "Prag"),
+    ("de", "Europe/Rome", "Rom"),
+    ("de", "Europe/Saratov", "Saratow"),
+    ("de", "Europe/Tirane", "Tirana"),
+    ("de", "Europe/Ulyanovsk", "Uljanowsk"),
+    ("de", "Europe/Vatican", "Vatikan"),
+    ("de", "Europe/Vienna", "Wien"),
+    ("de", "Europe/Volgograd", "Wolgograd"),
+    ("de", "Europe/Warsaw", "Warschau"),
+    ("de", "Europe/Zurich", "Zürich"),
+    ("de", "Indian/Christmas", "Weihnachtsinsel"),
+    ("de", "Indian/Comoro", "Komoren"),
+    ("de", "Indian/Maldives", "Malediven"),
+    ("de", "Indian/Reunion", "Réunion"),
+    ("de", "Pacific/Easter", "Osterinsel"),
+    ("de", "Pacific/Fiji", "Fidschi"),
+    ("en", "Africa/Sao_Tome", "São Tomé"),
+    ("en", "America/Asuncion", "Asunción"),
+    ("en", "America/Curacao", "Curaçao"),
+    ("en", "America/St_Barthelemy", "St. Barthélemy"),
+    ("en", "Asia/Ho_Chi_Minh", "Ho Chi Minh City"),
+    ("en", "Asia/Qostanay", "Kostanay"),
+    ("en", "Europe/Kyiv", "Uzhhorod"),
+    ("en", "Indian/Reunion", "Réunion"),
+    ("es", "Africa/Abidjan", "Abiyán"),
+    ("es", "Africa/Accra", "Acra"),
+    ("es", "Africa/Addis_Ababa", "Addis Abeba"),
+    ("es", "Africa/Algiers", "Argel"),
+    ("es", "Africa/Bissau", "Bisáu"),
+    ("es", "Africa/Cairo", "El Cairo"),
+    ("es", "Africa/Djibouti", "Yibuti"),
+    ("es", "Africa/Douala", "Duala"),
+    ("es", "Africa/El_Aaiun", "El Aaiún"),
+    ("es", "Africa/Johannesburg", "Johannesburgo"),
+    ("es", "Africa/Khartoum", "Jartún"),
+    ("es", "Africa/Lome", "Lomé"),
+    ("es", "Africa/Mogadishu", "Mogadiscio"),
+    ("es", "Africa/Ndjamena", "Yamena"),
+    ("es", "Africa/Nouakchott", "Nuakchot"),
+    ("es", "Africa/Ouagadougou", "Uagadugú"),
+    ("es", "Africa/Porto-Novo", "Portonovo"),
+    ("es", "Africa/Sao_Tome", "Santo Tomé"),
+    ("es", "Africa/Tripoli", "Trípoli"),
+    ("es", "Africa/Tunis", "Túnez"),
+    ("es", "America/Anguilla", "Anguila"),
+    ("es", "America/Araguaina", "Araguaína"),
+    ("es", "America/Argentina/Cordoba", "Córdoba"),
+    ("es", "America/Argentina/Rio_Gallegos", "Río Gallegos"),
+    ("es", "America/Argentina/Tucuman", "Tucumán"),
+    ("es", "America/Asuncion", "Asunción"),
+    ("es", "America/Bahia", "Bahía"),
+    ("es", "America/Belem", "Belén"),
+    ("es", "America/Belize", "Belice"),
+    ("es", "America/Bogota", "Bogotá"),
+    ("es", "America/Cayenne", "Cayena"),
+    ("es", "America/Cayman", "Caimán"),
+    ("es", "America/Cuiaba", "Cuiabá"),
+    ("es", "America/Curacao", "Curazao"),
+    ("es", "America/Eirunepe", "Eirunepé"),
+    ("es", "America/Grand_Turk", "Gran Turca"),
+    ("es", "America/Grenada", "Granada"),
+    ("es", "America/Guadeloupe", "Guadalupe"),
+    ("es", "America/Havana", "La Habana"),
+    ("es", "America/Indiana/Indianapolis", "Indianápolis"),
+    ("es", "America/Los_Angeles", "Los Ángeles"),
+    ("es", "America/Maceio", "Maceió"),
+    ("es", "America/Manaus", "Manaos"),
+    ("es", "America/Martinique", "Martinica"),
+    ("es", "America/Mazatlan", "Mazatlán"),
+    ("es", "America/Mexico_City", "Ciudad de México"),
+    ("es", "America/Miquelon", "Miquelón"),
+    ("es", "America/New_York", "Nueva York"),
+    ("es", "America/North_Dakota/Beulah", "Beulah, Dakota del Norte"),
+    ("es", "America/North_Dakota/Center", "Center, Dakota del Norte"),
+    ("es", "America/North_Dakota/New_Salem", "New Salem, Dakota del Norte"),
+    ("es", "America/Panama", "Panamá"),
+    ("es", "America/Port-au-Prince", "Puerto Príncipe"),
+    ("es", "America/Port_of_Spain", "Puerto España"),
+    ("es", "America/Rio_Branco", "Río Branco"),
+    ("es", "America/Santarem", "Santarém"),
+    ("es", "America/Santiago", "Santiago de Chile"),
+    ("es", "America/Sao_Paulo", "São Paulo"),
+    ("es", "America/St_Barthelemy", "San Bartolomé"),
+    ("es", "America/St_Johns", "San Juan de Terranova"),
+    ("es", "America/St_Kitts", "San Cristóbal"),
+    ("es", "America/St_Lucia", "Santa Lucía"),
+    ("es", "America/St_Vincent", "San Vicente"),
+    ("es", "America/Tortola", "Tórtola"),
+    ("es", "Asia/Aden", "Adén"),
+    ("es", "Asia/Amman", "Ammán"),
+    ("es", "Asia/Anadyr", "Anádyr"),
+    ("es", "Asia/Aqtau", "Aktau"),
+    ("es", "Asia/Aqtobe", "Aktobe"),
+    ("es", "Asia/Ashgabat", "Asjabad"),
+    ("es", "Asia/Baghdad", "Bagdad"),
+    ("es", "Asia/Bahrain", "Baréin"),
+    ("es", "Asia/Baku", "Bakú"),
+    ("es", "Asia/Barnaul", "Barnaúl"),
+    ("es", "Asia/Brunei", "Brunéi"),
+    ("es", "Asia/Chita", "Chitá"),
+    ("es", "Asia/Damascus", "Damasco"),
+    ("es", "Asia/Dhaka", "Daca"),
+    ("es", "Asia/Dubai", "Dubái"),
+    ("es", "Asia/Dushanbe", "Dusambé"),
+    ("es", "Asia/Hebron", "Hebrón"),
+    ("es", "Asia/Ho_Chi_Minh", "Ciudad Ho Chi Minh"),
+    ("es", "Asia/Jakarta", "Yakarta"),
+    ("es", "Asia/Jerusalem", "Jerusalén"),
+    ("es", "Asia/Kathmandu", "Katmandú"),
+    ("es", "Asia/Kolkata", "Calcuta"),
+    ("es", "Asia/Magadan", "Magadán"),
+    ("es", "Asia/Makassar", "Makasar"),
+    ("es", "Asia/Muscat", "Mascate"),
+    ("es", "Asia/Qatar", "Catar"),
+    ("es", "Asia/Qostanay", "Kostanái"),
+    ("es", "Asia/Qyzylorda", "Kyzylorda"),
+    ("es", "Asia/Riyadh", "Riad"),
+    ("es", "Asia/Sakhalin", "Sajalín"),
+    ("es", "Asia/Samarkand", "Samarcanda"),
+    ("es", "Asia/Seoul", "Seúl"),
+    ("es", "Asia/Shanghai", "Shanghái"),
+    ("es", "Asia/Singapore", "Singapur"),
+    ("es", "Asia/Srednekolymsk", "Srednekolimsk"),
+    ("es", "Asia/Taipei", "Taipéi"),
+    ("es", "Asia/Tashkent", "Taskent"),
+    ("es", "Asia/Tbilisi", "Tiflis"),
+    ("es", "Asia/Tehran", "Teherán"),
+    ("es", "Asia/Thimphu", "Timbu"),
+    ("es", "Asia/Tokyo", "Tokio"),
+    ("es", "Asia/Ulaanbaatar", "Ulán Bator"),
+    ("es", "Asia/Urumqi", "Ürümqi"),
+    ("es", "Asia/Vientiane", "Vientián"),
+    ("es", "Asia/Yangon", "Yangón (Rangún)"),
+    ("es", "Asia/Yekaterinburg", "Ekaterimburgo"),
+    ("es", "Asia/Yerevan", "Ereván"),
+    ("es", "Atlantic/Bermuda", "Bermudas"),
+    ("es", "Atlantic/Canary", "Canarias"),
+    ("es", "Atlantic/Cape_Verde", "Cabo Verde"),
+    ("es", "Atlantic/Faroe", "Islas Feroe"),
+    ("es", "Atlantic/Reykjavik", "Reikiavik"),
+    ("es", "Atlantic/South_Georgia", "Georgia del Sur"),
+    ("es", "Atlantic/St_Helena", "Santa Elena"),
+    ("es", "Australia/Adelaide", "Adelaida"),
+    ("es", "Australia/Sydney", "Sídney"),
+    ("es", "Europe/Amsterdam", "Ámsterdam"),
+    ("es", "Europe/Astrakhan", "Astracán"),
+    ("es", "Europe/Athens", "Atenas"),
+    ("es", "Europe/Belgrade", "Belgrado"),
+    ("es", "Europe/Berlin", "Berlín"),
+    ("es", "Europe/Brussels", "Bruselas"),
+    ("es", "Europe/Bucharest", "Bucarest"),
+    ("es", "Europe/Chisinau", "Chisináu"),
+    ("es", "Europe/Copenhagen", "Copenhague"),
+    ("es", "Europe/Dublin", "Dublín"),
+    ("es", "Europe/Guernsey", "Guernesey"),
+    ("es", "Europe/Isle_of_Man", "Isla de Man"),
+    ("es", "Europe/Istanbul", "Estambul"),
+    ("es", "Europe/Kaliningrad", "Kaliningrado"),
+    ("es", "Europe/Kirov", "Kírov"),
+    ("es", "Europe/Kyiv", "Zaporiyia"),
+    ("es", "Europe/Lisbon", "Lisboa"),
+    ("es", "Europe/Ljubljana", "Liubliana"),
+    ("es", "Europe/London", "Londres"),
+    ("es", "Europe/Luxembourg", "Luxemburgo"),
+    ("es", "Europe/Monaco", "Mónaco"),
+    ("es", "Europe/Moscow", "Moscú"),
+    ("es", "Europe/Paris", "París"),
+    ("es", "Europe/Prague", "Praga"),
+    ("es", "Europe/Rome", "Roma"),
+    ("es", "Europe/Saratov", "Sarátov"),
+    ("es", "Europe/Simferopol", "Simferópol"),
+    ("es", "Europe/Skopje", "Skopie"),
+    ("es", "Europe/Sofia", "Sofía"),
+    ("es", "Europe/Stockholm", "Estocolmo"),
+    ("es", "Europe/Tallinn", "Tallin"),
+    ("es", "Europe/Tirane", "Tirana"),
+    ("es", "Europe/Ulyanovsk", "Uliánovsk"),
+    ("es", "Europe/Vatican", "El Vaticano"),
+    ("es", "Europe/Vienna", "Viena"),
+    ("es", "Europe/Vilnius", "Vilna"),
+    ("es", "Europe/Volgograd", "Volgogrado"),
+    ("es", "Europe/Warsaw", "Varsovia"),
+    ("es", "Europe/Zurich", "Zúrich"),
+    ("es", "Indian/Christmas", "Navidad"),
+    ("es", "Indian/Comoro", "Comoras"),
+    ("es", "Indian/Mahe", "Mahé"),
+    ("es", "Indian/Maldives", "Maldivas"),
+    ("es", "Indian/Mauritius", "Mauricio"),
+    ("es", "Indian/Reunion", "Reunión"),
+    ("es", "Pacific/Easter", "Isla de Pascua"),
+    ("es", "Pacific/Fiji", "Fiyi"),
+    ("es", "Pacific/Galapagos", "Galápagos"),
+    ("es", "Pacific/Honolulu", "Honolulú"),
+    ("es", "Pacific/Noumea", "Numea"),
+    ("es", "Pacific/Palau", "Palaos"),
+    ("es", "Pacific/Saipan", "Saipán"),
+    ("es", "Pacific/Tahiti", "Tahití"),
+    ("fr", "Africa/Addis_Ababa", "Addis-Abeba"),
+    ("fr", "Africa/Algiers", "Alger"),
+    ("fr", "Africa/Cairo", "Le Caire"),
+    ("fr", "Africa/El_Aaiun", "Laâyoune"),
+    ("fr", "Africa/Lome", "Lomé"),
+    ("fr", "Africa/Mogadishu", "Mogadiscio"),
+    ("fr", "Africa/Ndjamena", "N’Djamena"),
+    ("fr", "Africa/Sao_Tome", "São Tomé"),
+    ("fr", "Africa/Tripoli", "Tripoli (Libye)"),
+    ("fr", "America/Araguaina", "Araguaína"),
+    ("fr", "America/Argentina/Cordoba", "Córdoba"),
+    ("fr", "America/Argentina/Rio_Gallegos", "Río Gallegos"),
+    ("fr", "America/Argentina/Tucuman", "Tucumán"),
+    ("fr", "America/Argentina/Ushuaia", "Ushuaïa"),
+    ("fr", "America/Asuncion", "Asunción"),
+    ("fr", "America/Bahia_Banderas", "Bahia de Banderas"),
+    ("fr", "America/Barbados", "La Barbade"),
+    ("fr", "America/Belem", "Belém"),
+    ("fr", "America/Cayman", "Caïmans"),
+    ("fr", "America/Cuiaba", "Cuiabá"),
+    ("fr", "America/Curacao", "Curaçao"),
+    ("fr", "America/Detroit", "Détroit"),
+    ("fr", "America/Dominica", "Dominique"),
+    ("fr", "America/Eirunepe", "Eirunepé"),
+    ("fr", "America/Grenada", "Grenade"),
+    ("fr", "America/Havana", "La Havane"),
+    ("fr", "America/Indiana/Knox", "Knox [Indiana]"),
+    ("fr", "America/Indiana/Marengo", "Marengo [Indiana]"),
+    ("fr", "America/Indiana/Petersburg", "Petersburg [Indiana]"),
+    ("fr", "America/Indiana/Tell_City", "Tell City [Indiana]"),
+    ("fr", "America/Indiana/Vevay", "Vevay [Indiana]"),
+    ("fr", "America/Indiana/Vincennes", "Vincennes [Indiana]"),
+    ("fr", "America/Indiana/Winamac", "Winamac [Indiana]"),
+    ("fr", "America/Jamaica", "Jamaïque"),
+    ("fr", "America/Kentucky/Monticello", "Monticello [Kentucky]"),
+    ("fr", "America/Maceio", "Maceió"),
+    ("fr", "America/Manaus", "Manaos"),
+    ("fr", "America/Mazatlan", "Mazatlán"),
+    ("fr", "America/Mexico_City", "Mexico"),
+    ("fr", "America/North_Dakota/Beulah", "Beulah (Dakota du Nord)"),
+    ("fr", "America/North_Dakota/Center", "Center (Dakota du Nord)"),
+    ("fr", "America/North_Dakota/New_Salem", "New Salem (Dakota du Nord)"),
+    ("fr", "America/Port_of_Spain", "Port-d’Espagne"),
+    ("fr", "America/Puerto_Rico", "Porto Rico"),
+    ("fr", "America/Santarem", "Santarém"),
+    ("fr", "America/Santo_Domingo", "Saint-Domingue"),
+    ("fr", "America/Sao_Paulo", "São Paulo"),
+    ("fr", "America/St_Barthelemy", "Saint-Barthélemy"),
+    ("fr", "America/St_Johns", "Saint-Jean de Terre-Neuve"),
+    ("fr", "America/St_Kitts", "Saint-Christophe"),
+    ("fr", "America/St_Lucia", "Sainte-Lucie"),
+    ("fr", "America/St_Thomas", "Saint-Thomas"),
+    ("fr", "America/St_Vincent", "Saint-Vincent"),
+    ("fr", "America/Tegucigalpa", "Tégucigalpa"),
+    ("fr", "America/Thule", "Thulé"),
+    ("fr", "Antarctica/Syowa", "Showa"),
+    ("fr", "Asia/Almaty", "Alma Ata"),
+    ("fr", "Asia/Aqtau", "Aktaou"),
+    ("fr", "Asia/Aqtobe", "Aktioubinsk"),
+    ("fr", "Asia/Ashgabat", "Achgabat"),
+    ("fr", "Asia/Atyrau", "Atyraou"),
+    ("fr", "Asia/Baghdad", "Bagdad"),
+    ("fr", "Asia/Bahrain", "Bahreïn"),
+    ("fr", "Asia/Baku", "Bakou"),
+    ("fr", "Asia/Beirut", "Beyrouth"),
+    ("fr", "Asia/Bishkek", "Bichkek"),
+    ("fr", "Asia/Chita", "Tchita"),
+    ("fr", "Asia/Damascus", "Damas"),
+    ("fr", "Asia/Dubai", "Dubaï"),
+    ("fr", "Asia/Dushanbe", "Douchanbé"),
+    ("fr", "Asia/Famagusta", "Famagouste"),
+    ("fr", "Asia/Hebron", "Hébron"),
+    ("fr", "Asia/Ho_Chi_Minh", "Hô-Chi-Minh-Ville"),
+    ("fr", "Asia/Irkutsk", "Irkoutsk"),
+    ("fr", "Asia/Jerusalem", "Jérusalem"),
+    ("fr", "Asia/Kabul", "Kaboul"),
+    ("fr", "Asia/Kamchatka", "Kamtchatka"),
+    ("fr", "Asia/Kathmandu", "Katmandou"),
+    ("fr", "Asia/Krasnoyarsk", "Krasnoïarsk"),
+    ("fr", "Asia/Kuwait", "Koweït"),
+    ("fr", "Asia/Makassar", "Macassar"),
+    ("fr", "Asia/Manila", "Manille"),
+    ("fr", "Asia/Muscat", "Mascate"),
+    ("fr", "Asia/Nicosia", "Nicosie"),
+    ("fr", "Asia/Novosibirsk", "Novossibirsk"),
+    ("fr", "Asia/Oral", "Ouralsk"),
+    ("fr", "Asia/Qostanay", "Kostanaï"),
+    ("fr", "Asia/Qyzylorda", "Kzyl Orda"),
+    ("fr", "Asia/Riyadh", "Riyad"),
+    ("fr", "Asia/Sakhalin", "Sakhaline"),
+    ("fr", "Asia/Samarkand", "Samarcande"),
+    ("fr", "Asia/Seoul", "Séoul"),
+    ("fr", "Asia/Singapore", "Singapour"),
+    ("fr", "Asia/Tashkent", "Tachkent"),
+    ("fr", "Asia/Tbilisi", "Tbilissi"),
+    ("fr", "Asia/Tehran", "Téhéran"),
+    ("fr", "Asia/Ulaanbaatar", "Oulan-Bator"),
+    ("fr", "Asia/Urumqi", "Ürümqi"),
+    ("fr", "Asia/Yakutsk", "Iakoutsk"),
+    ("fr", "Asia/Yangon", "Rangoun"),
+    ("fr", "Asia/Yekaterinburg", "Ekaterinbourg"),
+    ("fr", "Asia/Yerevan", "Erevan"),
+    ("fr", "Atlantic/Azores", "Açores"),
+    ("fr", "Atlantic/Bermuda", "Bermudes"),
+    ("fr", "Atlantic/Canary", "Îles Canaries"),
+    ("fr", "Atlantic/Cape_Verde", "Cap-Vert"),
+    ("fr", "Atlantic/Faroe", "Îles Féroé"),
+    ("fr", "Atlantic/Madeira", "Madère"),
+    ("fr", "Atlantic/South_Georgia", "Géorgie du Sud"),
+    ("fr", "Atlantic/St_Helena", "Sainte-Hélène"),
+    ("fr", "Australia/Adelaide", "Adélaïde"),
+    ("fr", "Europe/Andorra", "Andorre"),
+    ("fr", "Europe/Athens", "Athènes"),
+    ("fr", "Europe/Brussels", "Bruxelles"),
+    ("fr", "Europe/Bucharest", "Bucarest"),
+    ("fr", "Europe/Busingen", "Büsingen"),
+    ("fr", "Europe/Copenhagen", "Copenhague"),
+    ("fr", "Europe/Guernsey", "Guernesey"),
+    ("fr", "Europe/Isle_of_Man", "Île de Man"),
+    ("fr", "Europe/Kyiv", "Zaporojie"),
+    ("fr", "Europe/Lisbon", "Lisbonne"),
+    ("fr", "Europe/London", "Londres"),
+    ("fr", "Europe/Malta", "Malte"),
+    ("fr", "Europe/Moscow", "Moscou"),
+    ("fr", "Europe/San_Marino", "Saint-Marin"),
+    ("fr", "Europe/Tirane", "Tirana"),
+    ("fr", "Europe/Ulyanovsk", "Oulianovsk"),
+    ("fr", "Europe/Vatican", "Le Vatican"),
+    ("fr", "Europe/Vienna", "Vienne"),
+    ("fr", "Europe/Warsaw", "Varsovie"),
+    ("fr", "Indian/Comoro", "Comores"),
+    ("fr", "Indian/Mahe", "Mahé"),
+    ("fr", "Indian/Mauritius", "Maurice"),
+    ("fr", "Indian/Reunion", "La Réunion"),
+    ("fr", "Pacific/Easter", "Île de Pâques"),
+    ("fr", "Pacific/Efate", "Éfaté"),
+    ("fr", "Pacific/Fiji", "Fidji"),
+    ("fr", "Pacific/Galapagos", "Galápagos"),
+    ("fr", "Pacific/Marquesas", "Marquises"),
+    ("fr", "Pacific/Noumea", "Nouméa"),
+    ("it", "Africa/Addis_Ababa", "Addis Abeba"),
+    ("it", "Africa/Algiers", "Algeri"),
+    ("it", "Africa/Cairo", "Il Cairo"),
+    ("it", "Africa/Djibouti", "Gibuti"),
+    ("it", "Africa/El_Aaiun", "El Ayun"),
+    ("it", "Africa/Juba", "Giuba"),
+    ("it", "Africa/Khartoum", "Khartum"),
+    ("it", "Africa/Lome", "Lomé"),
+    ("it", "Africa/Mogadishu", "Mogadiscio"),
+    ("it", "Africa/Ndjamena", "N’Djamena"),
+    ("it", "Africa/Sao_Tome", "São Tomé"),
+    ("it", "Africa/Tunis", "Tunisi"),
+    ("it", "America/Argentina/Tucuman", "Tucumán"),
+    ("it", "America/Asuncion", "Asunción"),
+    ("it", "America/Bogota", "Bogotá"),
+    ("it", "America/Cayenne", "Caienna"),
+    ("it", "America/Curacao", "Curaçao"),
+    ("it", "America/Guadeloupe", "Guadalupa"),
+    ("it", "America/Havana", "L’Avana"),
+    ("it", "America/Jamaica", "Giamaica"),
+    ("it", "America/Martinique", "Martinica"),
+    ("it", "America/Mexico_City", "Città del Messico"),
+    ("it", "America/North_Dakota/Beulah", "Beulah, Dakota del nord"),
+    ("it", "America/North_Dakota/Center", "Center, Dakota del nord"),
+    ("it", "America/North_Dakota/New_Salem", "New Salem, Dakota del nord"),
+    ("it", "America/Panama", "Panamá"),
+    ("it", "America/Puerto_Rico", "Portorico"),
+    ("it", "America/Santarem", "Santarém"),
+    ("it", "America/Sao_Paulo", "San Paolo"),
+    ("it", "America/St_Barthelemy", "Saint-Barthélemy"),
+    ("it", "America/St_Lucia", "Santa Lucia"),
+    ("it", "America/St_Thomas", "Saint Thomas"),
+    ("it", "America/St_Vincent", "Saint Vincent"),
+    ("it", "Asia/Anadyr", "Anadyr’"),
+    ("it", "Asia/Aqtobe", "Aqtöbe"),
+    ("it", "Asia/Bahrain", "Bahrein"),
+    ("it", "Asia/Chita", "Čita"),
+    ("it", "Asia/Damascus", "Damasco"),
+    ("it", "Asia/Dhaka", "Dacca"),
+    ("it", "Asia/Famagusta", "Famagosta"),
+    ("it", "Asia/Jakarta", "Giacarta"),
+    ("it", "Asia/Jerusalem", "Gerusalemme"),
+    ("it", "Asia/Khandyga", "Chandyga"),
+    ("it", "Asia/Krasnoyarsk", "Krasnojarsk"),
+    ("it", "Asia/Muscat", "Mascate"),
+    ("it", "Asia/Novokuznetsk", "Novokuzneck"),
+    ("it", "Asia/Riyadh", "Riyad"),
+    ("it", "Asia/Sakhalin", "Sachalin"),
+    ("it", "Asia/Samarkand", "Samarcanda"),
+    ("it", "Asia/Seoul", "Seul"),
+    ("it", "Asia/Tehran", "Teheran"),
+    ("it", "Asia/Ust-Nera", "Ust’-Nera"),
+    ("it", "Asia/Yakutsk", "Jakutsk"),
+    ("it", "Asia/Yekaterinburg", "Ekaterinburg"),
+    ("it", "Atlantic/Azores", "Azzorre"),
+    ("it", "Atlantic/Canary", "Canarie"),
+    ("it", "Atlantic/Cape_Verde", "Capo Verde"),
+    ("it", "Atlantic/Faroe", "Isole Fær Øer"),
+    ("it", "Atlantic/Reykjavik", "Reykjavík"),
+    ("it", "Atlantic/South_Georgia", "Georgia del Sud"),
+    ("it", "Atlantic/St_Helena", "Sant’Elena"),
+    ("it", "Europe/Athens", "Atene"),
+    ("it", "Europe/Belgrade", "Belgrado"),
+    ("it", "Europe/Berlin", "Berlino"),
+    ("it", "Europe/Brussels", "Bruxelles"),
+    ("it", "Europe/Bucharest", "Bucarest"),
+    ("it", "Europe/Busingen", "Büsingen"),
+    ("it", "Europe/Copenhagen", "Copenaghen"),
+    ("it", "Europe/Dublin", "Dublino"),
+    ("it", "Europe/Gibraltar", "Gibilterra"),
+    ("it", "Europe/Isle_of_Man", "Isola di Man"),
+    ("it", "Europe/Kyiv", "Užhorod"),
+    ("it", "Europe/Lisbon", "Lisbona"),
+    ("it", "Europe/Ljubljana", "Lubiana"),
+    ("it", "Europe/London", "Londra"),
+    ("it", "Europe/Luxembourg", "Lussemburgo"),
+    ("it", "Europe/Moscow", "Mosca"),
+    ("it", "Europe/Paris", "Parigi"),
+    ("it", "Europe/Prague", "Praga"),
+    ("it", "Europe/Rome", "Roma"),
+    ("it", "Europe/Simferopol", "Sinferopoli"),
+    ("it", "Europe/Stockholm", "Stoccolma"),
+    ("it", "Europe/Tirane", "Tirana"),
+    ("it", "Europe/Vatican", "Città del Vaticano"),
+    ("it", "Europe/Warsaw", "Varsavia"),
+    ("it", "Europe/Zagreb", "Zagabria"),
+    ("it", "Europe/Zurich", "Zurigo"),
+    ("it", "Indian/Christmas", "Natale"),
+    ("it", "Indian/Comoro", "Comore"),
+    ("it", "Indian/Maldives", "Maldive"),
+    ("it", "Indian/Reunion", "La Riunione"),
+    ("it", "Pacific/Easter", "Pasqua"),
+    ("it", "Pacific/Fiji", "Figi"),
+    ("it", "Pacific/Marquesas", "Marchesi"),
+    ("pt", "Africa/Accra", "Acra"),
+    ("pt", "Africa/Addis_Ababa", "Adis Abeba"),
+    ("pt", "Africa/Algiers", "Argel"),
+    ("pt", "Africa/Conakry", "Conacri"),
+    ("pt", "Africa/Djibouti", "Djibuti"),
+    ("pt", "Africa/El_Aaiun", "El Aaiún"),
+    ("pt", "Africa/Johannesburg", "Joanesburgo"),
+    ("pt", "Africa/Khartoum", "Cartum"),
+    ("pt", "Africa/Lome", "Lomé"),
+    ("pt", "Africa/Mogadishu", "Mogadíscio"),
+    ("pt", "Africa/Monrovia", "Monróvia"),
+    ("pt", "Africa/Nairobi", "Nairóbi"),
+    ("pt", "Africa/Ndjamena", "N’Djamena"),
+    ("pt", "Africa/Porto-Novo", "Porto Novo"),
+    ("pt", "Africa/Sao_Tome", "São Tomé"),
+    ("pt", "Africa/Tripoli", "Trípoli"),
+    ("pt", "Africa/Tunis", "Túnis"),
+    ("pt", "America/Anguilla", "Anguila"),
+    ("pt", "America/Antigua", "Antígua"),
+    ("pt", "America/Araguaina", "Araguaína"),
+    ("pt", "America/Argentina/Cordoba", "Córdoba"),
+    ("pt", "America/Argentina/Tucuman", "Tucumã"),
+    ("pt", "America/Asuncion", "Assunção"),
+    ("pt", "America/Bahia_Banderas", "Bahia de Banderas"),
+    ("pt", "America/Belem", "Belém"),
+    ("pt", "America/Bogota", "Bogotá"),
+    ("pt", "America/Cayenne", "Caiena"),
+    ("pt", "America/Cuiaba", "Cuiabá"),
+    ("pt", "America/Curacao", "Curaçao"),
+    ("pt", "America/Eirunepe", "Eirunepé"),
+    ("pt", "America/Grenada", "Granada"),
+    ("pt", "America/Guadeloupe", "Guadalupe"),
+    ("pt", "America/Guayaquil", "Guaiaquil"),
+    ("pt", "America/Guyana", "Guiana"),
+    ("pt", "America/Indiana/Indianapolis", "Indianápolis"),
+    ("pt", "America/Maceio", "Maceió"),
+    ("pt", "America/Managua", "Manágua"),
+    ("pt", "America/Martinique", "Martinica"),
+    ("pt", "America/Mexico_City", "Cidade do México"),
+    ("pt", "America/Montevideo", "Montevidéu"),
+    ("pt", "America/New_York", "Nova York"),
+    ("pt", "America/Noronha", "Fernando de Noronha"),
+    ("pt", "America/North_Dakota/Beulah", "Beulah, Dakota do Norte"),
+    ("pt", "America/North_Dakota/Center", "Center, Dakota do Norte"),
+    ("pt", "America/North_Dakota/New_Salem", "New Salen, Dakota do Norte"),
+    ("pt", "America/Panama", "Panamá"),
+    ("pt", "America/Port-au-Prince", "Porto Príncipe"),
+    ("pt", "America/Puerto_Rico", "Porto Rico"),
+    ("pt", "America/Santarem", "Santarém"),
+    ("pt", "America/Sao_Paulo", "São Paulo"),
+    ("pt", "America/St_Barthelemy", "São Bartolomeu"),
+    ("pt", "America/St_Johns", "Saint John’s"),
+    ("pt", "America/St_Kitts", "São Cristóvão"),
+    ("pt", "America/St_Lucia", "Santa Lúcia"),
+    ("pt", "America/St_Thomas", "Saint Thomas"),
+    ("pt", "America/St_Vincent", "São Vicente"),
+    ("pt", "Asia/Aden", "Áden"),
+    ("pt", "Asia/Amman", "Amã"),
+    ("pt", "Asia/Aqtau", "Aktau"),
+    ("pt", "Asia/Aqtobe", "Aktobe"),
+    ("pt", "Asia/Ashgabat", "Asgabate"),
+    ("pt", "Asia/Baghdad", "Bagdá"),
+    ("pt", "Asia/Bahrain", "Bahrein"),
+    ("pt", "Asia/Beirut", "Beirute"),
+    ("pt", "Asia/Damascus", "Damasco"),
+    ("pt", "Asia/Dhaka", "Dacca"),
+    ("pt", "Asia/Dushanbe", "Duchambe"),
+    ("pt", "Asia/Ho_Chi_Minh", "Cidade de Ho Chi Minh"),
+    ("pt", "Asia/Jakarta", "Jacarta"),
+    ("pt", "Asia/Jerusalem", "Jerusalém"),
+    ("pt", "Asia/Kabul", "Cabul"),
+    ("pt", "Asia/Kolkata", "Calcutá"),
+    ("pt", "Asia/Muscat", "Mascate"),
+    ("pt", "Asia/Nicosia", "Nicósia"),
+    ("pt", "Asia/Qatar", "Catar"),
+    ("pt", "Asia/Riyadh", "Riade"),
+    ("pt", "Asia/Sakhalin", "Sacalina"),
+    ("pt", "Asia/Samarkand", "Samarcanda"),
+    ("pt", "Asia/Seoul", "Seul"),
+    ("pt", "Asia/Shanghai", "Xangai"),
+    ("pt", "Asia/Singapore", "Singapura"),
+    ("pt", "Asia/Tehran", "Teerã"),
+    ("pt", "Asia/Tokyo", "Tóquio"),
+    ("pt", "Asia/Ulaanbaatar", "Ulan Bator"),
+    ("pt", "Asia/Yangon", "Rangum"),
+    ("pt", "Asia/Yekaterinburg", "Ecaterimburgo"),
+    ("pt", "Atlantic/Azores", "Açores"),
+    ("pt", "Atlantic/Bermuda", "Bermudas"),
+    ("pt", "Atlantic/Canary", "Canárias"),
+    ("pt", "Atlantic/Cape_Verde", "Cabo Verde"),
+    ("pt", "Atlantic/Faroe", "Ilhas Faroé"),
+    ("pt", "Atlantic/Reykjavik", "Reykjavík"),
+    ("pt", "Atlantic/South_Georgia", "Geórgia do Sul"),
+    ("pt", "Atlantic/St_Helena", "Santa Helena"),
+    ("pt", "Europe/Amsterdam", "Amsterdã"),
+    ("pt", "Europe/Astrakhan", "Astracã"),
+    ("pt", "Europe/Athens", "Atenas"),
+    ("pt", "Europe/Belgrade", "Belgrado"),
+    ("pt", "Europe/Berlin", "Berlim"),
+    ("pt", "Europe/Brussels", "Bruxelas"),
+    ("pt", "Europe/Bucharest", "Bucareste"),
+    ("pt", "Europe/Budapest", "Budapeste"),
+    ("pt", "Europe/Busingen", "Büsingen"),
+    ("pt", "Europe/Copenhagen", "Copenhague"),
+    ("pt", "Europe/Helsinki", "Helsinque"),
+    ("pt", "Europe/Isle_of_Man", "Ilha de Man"),
+    ("pt", "Europe/Istanbul", "Istambul"),
+    ("pt", "Europe/Kaliningrad", "Kaliningrado"),
+    ("pt", "Europe/Kyiv", "Zaporizhia"),
+    ("pt", "Europe/Lisbon", "Lisboa"),
+    ("pt", "Europe/Ljubljana", "Liubliana"),
+    ("pt", "Europe/London", "Londres"),
+    ("pt", "Europe/Luxembourg", "Luxemburgo"),
+    ("pt", "Europe/Madrid", "Madri"),
+    ("pt", "Europe/Monaco", "Mônaco"),
+    ("pt", "Europe/Moscow", "Moscou"),
+    ("pt", "Europe/Prague", "Praga"),
+    ("pt", "Europe/Rome", "Roma"),
+    ("pt", "Europe/Sofia", "Sófia"),
+    ("pt", "Europe/Stockholm", "Estocolmo"),
+    ("pt", "Europe/Tirane", "Tirana"),
+    ("pt", "Europe/Ulyanovsk", "Ulianovsk"),
+    ("pt", "Europe/Vatican", "Vaticano"),
+    ("pt", "Europe/Vienna", "Viena"),
+    ("pt", "Europe/Volgograd", "Volgogrado"),
+    ("pt", "Europe/Warsaw", "Varsóvia"),
+    ("pt", "Europe/Zurich", "Zurique"),
+    ("pt", "Indian/Comoro", "Comores"),
+    ("pt", "Indian/Mahe", "Mahé"),
+    ("pt", "Indian/Maldives", "Maldivas"),
+    ("pt", "Indian/Mauritius", "Maurício"),
+    ("pt", "Indian/Reunion", "Reunião"),
+    ("pt", "Pacific/Chatham", "Chatnam"),
+    ("pt", "Pacific/Easter", "Ilha de Páscoa"),
+    ("pt", "Pacific/Efate", "Éfaté"),
+    ("pt", "Pacific/Galapagos", "Galápagos"),
+    ("pt", "Pacific/Noumea", "Nouméa"),
+    ("pt", "Pacific/Tahiti", "Taiti"),
+    ("pt", "Pacific/Tarawa", "Taraua"),
+];
+
+/// Month and weekday names of a locale, in format context (ie. "1er janvier"), weekdays from Monday
+struct CalendarNames {
+    locale: &'static str,
+    months: [&'static str; 12],
+    short_months: [&'static str; 12],
+    weekdays: [&'static str; 7],
+    short_weekdays: [&'static str; 7],
+}
+
+/// Calendar names of NAME_LOCALES, in the same order
+const CALENDAR_NAMES: &[CalendarNames] = &[
+    CalendarNames {
+        locale: "de",
+        months: ["Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September", "Oktober", "November", "Dezember"],
+        short_months: ["Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.", "Dez."],
+        weekdays: ["Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag", "Sonntag"],
+        short_weekdays: ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+    },
+    CalendarNames {
+        locale: "en",
+        months: ["January", "February", "March", "April", "May", "June", "July", "August", "September", "October", "November", "December"],
+        short_months: ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"],
+        weekdays: ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"],
+        short_weekdays: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    },
+    CalendarNames {
+        locale: "es",
+        months: ["enero", "febrero", "marzo", "abril", "mayo", "junio", "julio", "agosto", "septiembre", "octubre", "noviembre", "diciembre"],
+        short_months: ["ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic"],
+        weekdays: ["lunes", "martes", "miércoles", "jueves", "viernes", "sábado", "domingo"],
+        short_weekdays: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+    },
+    CalendarNames {
+        locale: "fr",
+        months: ["janvier", "février", "mars", "avril", "mai", "juin", "juillet", "août", "septembre", "octobre", "novembre", "décembre"],
+        short_months: ["janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.", "déc."],
+        weekdays: ["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche"],
+        short_weekdays: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+    },
+    CalendarNames {
+        locale: "it",
+        months: ["gennaio", "febbraio", "marzo", "aprile", "maggio", "giugno", "luglio", "agosto", "settembre", "ottobre", "novembre", "dicembre"],
+        short_months: ["gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic"],
+        weekdays: ["lunedì", "martedì", "mercoledì", "giovedì", "venerdì", "sabato", "domenica"],
+        short_weekdays: ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+    },
+    CalendarNames {
+        locale: "pt",
+        months: ["janeiro", "fevereiro", "março", "abril", "maio", "junho", "julho", "agosto", "setembro", "outubro", "novembro", "dezembro"],
+        short_months: ["jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.", "dez."],
+        weekdays: ["segunda-feira", "terça-feira", "quarta-feira", "quinta-feira", "sexta-feira", "sábado", "domingo"],
+        short_weekdays: ["seg.", "ter.", "qua.", "qui.", "sex.", "sáb.", "dom."],
+    },
+];
+
+/// Localized long names of a zone's local time, from CLDR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DisplayNames {
+    /// Standard time name: "heure normale d’Europe centrale"
+    pub standard: &'static str,
+    /// Daylight saving time name: "heure d’été d’Europe centrale"
+    pub daylight: Option<&'static str>,
+    /// Name for both: "heure d’Europe centrale"
+    pub generic: Option<&'static str>,
+}
+
+impl DisplayNames {
+    fn new(standard: &'static str, daylight: &'static str, generic: &'static str) -> DisplayNames {
+        let some = |name: &'static str| if name.is_empty() { None } else { Some(name) };
+        DisplayNames { standard, daylight: some(daylight), generic: some(generic) }
+    }
+}
+
+/// Language of a locale tag ("fr-CA", "pt_BR") if its names are embedded
+fn language(locale: &str) -> Option<&'static str> {
+    let language = locale.split(&['-', '_'][..]).next()?.to_ascii_lowercase();
+    NAME_LOCALES.iter().find(|l| **l == language).copied()
+}
+
+/// Returns the localized long names of a zone at an instant, in a locale of NAME_LOCALES (ie. "fr", "fr-CA").
+/// They come from the zone's metazone at that instant, or are the zone's own (ie. Europe/London).
+pub fn display_names(zone: &str, time: DateTime<Utc>, locale: &str) -> Option<DisplayNames> {
+    let language = language(locale)?;
+    let zone = canonical_zone(zone).unwrap_or(zone);
+    if let Ok(i) = ZONE_NAMES.binary_search_by_key(&(language, zone), |n| (n.0, n.1)) {
+        let (_, _, standard, daylight, generic) = ZONE_NAMES[i];
+        return Some(DisplayNames::new(standard, daylight, generic));
+    }
+    let metazone = metazone(zone, time)?;
+    let i = METAZONE_NAMES.binary_search_by_key(&(language, metazone), |n| (n.0, n.1)).ok()?;
+    let (_, _, standard, daylight, generic) = METAZONE_NAMES[i];
+    Some(DisplayNames::new(standard, daylight, generic))
+}
+
+/// Returns the localized exemplar city of a zone ("Londres" for Europe/London in French),
+/// None for zones without a city (ie. Etc/UTC) or locales not in NAME_LOCALES.
+pub fn exemplar_city(zone: &str, locale: &str) -> Option<String> {
+    let language = language(locale)?;
+    let zone = canonical_zone(zone).unwrap_or(zone);
+    if zone.starts_with("Etc/") || !zone.contains('/') {
+        return None;
+    }
+    match CITIES.binary_search_by_key(&(language, zone), |c| (c.0, c.1)) {
+        Ok(i) => Some(CITIES[i].2.to_string()),
+        Err(_) => zone.rsplit('/').next().map(|city| city.replace('_', " ")),
+    }
+}
+
+/// Formats a time with a chrono strftime pattern, where %B, %b (%h), %A and %a give month and weekday names
+/// in a locale of NAME_LOCALES rather than English, None for an invalid pattern: "%A %e %B %Y" gives "dimanche 14 juillet 2019" in French.
+pub fn format_localized<Tz: TimeZone>(time: &DateTime<Tz>, pattern: &str, locale: &str) -> Option<String>
+where
+    Tz::Offset: fmt::Display,
+{
+    let language = language(locale)?;
+    let names = CALENDAR_NAMES.iter().find(|n| n.locale == language)?;
+    let mut localized = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            localized.push(c);
+            continue;
+        }
+        let name = match chars.next() {
+            Some('B') => names.months[time.month0() as usize],
+            Some('b') | Some('h') => names.short_months[time.month0() as usize],
+            Some('A') => names.weekdays[time.weekday().num_days_from_monday() as usize],
+            Some('a') => names.short_weekdays[time.weekday().num_days_from_monday() as usize],
+            // Other specifiers, and "%%", are left to chrono
+            Some(other) => {
+                localized.push('%');
+                localized.push(other);
+                continue;
+            }
+            None => {
+                localized.push('%');
+                continue;
+            }
+        };
+        localized.push_str(&name.replace('%', "%%"));
+    }
+    // An invalid pattern makes chrono's Display fail
+    let mut formatted = String::new();
+    write!(formatted, "{}", time.format(&localized)).ok()?;
+    Some(formatted)
+}
+
+impl Tzinfo {
+    /// Formats the local time with localized month and weekday names, see format_localized.
+    pub fn format_localized(&self, pattern: &str, locale: &str) -> Option<String> {
+        format_localized(&self.datetime, pattern, locale)
+    }
+}
+
+impl Zone {
+    /// Returns the localized long name of the zone's local time at an instant (ie. "Central European Summer Time"),
+    /// standard or daylight according to the zone's DST flag, see display_names.
+    pub fn display_name(&self, time: DateTime<Utc>, locale: &str) -> Option<&'static str> {
+        let names = display_names(self.name(), time, locale)?;
+        match self.offset_at(time)?.isdst {
+            true => names.daylight,
+            false => Some(names.standard),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TzDatabase;
+    #[test]
+    fn names() {
+        let summer = Utc.ymd(2019, 7, 1).and_hms(0, 0, 0);
+        let paris = display_names("Europe/Paris", summer, "fr-FR").unwrap();
+        assert_eq!(paris.standard, "heure normale d’Europe centrale");
+        assert_eq!(paris.generic, Some("heure d’Europe centrale"));
+        assert_eq!(display_names("Europe/London", summer, "en").unwrap().daylight, Some("British Summer Time"));
+        assert!(display_names("Europe/Paris", summer, "tlh").is_none());
+        assert_eq!(exemplar_city("Europe/London", "fr").unwrap(), "Londres");
+        assert_eq!(exemplar_city("Europe/Vienna", "de").unwrap(), "Wien");
+        assert_eq!(exemplar_city("America/Los_Angeles", "en").unwrap(), "Los Angeles");
+        assert_eq!(exemplar_city("Asia/Saigon", "en").unwrap(), "Ho Chi Minh City");
+        assert_eq!(exemplar_city("Etc/UTC", "en"), None);
+        let db = TzDatabase::open("/usr/share/zoneinfo");
+        let berlin = db.zone("Europe/Berlin").unwrap();
+        assert_eq!(berlin.display_name(summer, "de").unwrap(), "Mitteleuropäische Sommerzeit");
+        assert_eq!(berlin.display_name(Utc.ymd(2019, 1, 1).and_hms(0, 0, 0), "en").unwrap(), "Central European Standard Time");
+        for table in &[METAZONE_NAMES, ZONE_NAMES] {
+            assert!(table.windows(2).all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+        }
+        assert!(CITIES.windows(2).all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+        }
+
+    #[test]
+    fn calendar_names() {
+        let time = FixedOffset::east(7200).ymd(2019, 7, 14).and_hms(12, 0, 0);
+        assert_eq!(format_localized(&time, "%A %e %B %Y", "fr").unwrap(), "dimanche 14 juillet 2019");
+        assert_eq!(format_localized(&time, "%a, %d. %b %H:%M", "de-AT").unwrap(), "So., 14. Juli 12:00");
+        assert_eq!(format_localized(&time, "100%% %B", "en").unwrap(), "100% July");
+        assert!(format_localized(&time, "%B %", "en").is_none());
+        assert!(format_localized(&time, "%B", "tlh").is_none());
+        let tzinfo = crate::get_zoneinfo_at("/usr/share/zoneinfo/Europe/Paris", Utc.ymd(2019, 12, 2).and_hms(8, 0, 0)).unwrap();
+        assert_eq!(tzinfo.format_localized("%A %e %B", "es").unwrap(), "lunes  2 diciembre");
+        for names in CALENDAR_NAMES {
+            assert!(NAME_LOCALES.contains(&names.locale));
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Newline-delimited JSON export, with the "json" feature.
+
+use crate::{Error, Timechange, TzDatabase, Tzinfo, Zone};
+use chrono::prelude::*;
+use serde::Serialize;
+use std::io::{self, Write};
+
+const OPERATION: &str = "writing NDJSON";
+
+/// Transition line
+#[derive(Serialize)]
+struct TransitionRecord<'a> {
+    zone: &'a str,
+    time: DateTime<Utc>,
+    gmtoff: isize,
+    isdst: bool,
+    abbreviation: &'a str,
+}
+
+/// Streams transitions and Tzinfo snapshots to a writer as newline-delimited JSON, one document per line,
+/// so that exports of the whole database are never buffered. Transition lines have a "zone" key,
+/// snapshot lines are the documents of Tzinfo::to_json, with a "timezone" key.
+#[derive(Debug)]
+pub struct NdjsonWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> NdjsonWriter<W> {
+    /// Writes to `writer`: wrap it in a BufWriter for files and sockets.
+    pub fn new(writer: W) -> NdjsonWriter<W> {
+        NdjsonWriter { writer }
+    }
+
+    fn line<T: Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        serde_json::to_writer(&mut self.writer, value)
+            .map_err(io::Error::from)
+            .and_then(|_| self.writer.write_all(b"\n"))
+            .map_err(|e| Error::io(e, OPERATION))
+    }
+
+    /// Writes a transition of a zone.
+    pub fn write_timechange(&mut self, zone: &str, tc: &Timechange) -> Result<(), Error> {
+        self.line(&TransitionRecord {
+            zone,
+            time: tc.time,
+            gmtoff: tc.gmtoff,
+            isdst: tc.isdst,
+            abbreviation: &tc.abbreviation,
+        })
+        .map_err(|e| e.with_zone(zone))
+    }
+
+    /// Writes a Tzinfo snapshot.
+    pub fn write_tzinfo(&mut self, tzinfo: &Tzinfo) -> Result<(), Error> {
+        self.line(tzinfo).map_err(|e| e.with_zone(&tzinfo.timezone))
+    }
+
+    /// Writes the snapshot of a zone at `now`, then its recorded transitions.
+    pub fn write_zone(&mut self, zone: &Zone, now: DateTime<Utc>) -> Result<(), Error> {
+        self.write_tzinfo(&zone.tzinfo(now)?)?;
+        for tc in zone.timechanges() {
+            self.write_timechange(zone.name(), tc)?;
+        }
+        Ok(())
+    }
+
+    /// Flushes the writer.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.writer.flush().map_err(|e| Error::io(e, OPERATION))
+    }
+
+    /// Returns the writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl TzDatabase {
+    /// Streams every zone of the tree to `writer` as newline-delimited JSON (see NdjsonWriter::write_zone),
+    /// loading one zone at a time. Zones without a snapshot at `now` only get their transitions.
+    pub fn export_ndjson<W: Write>(&self, writer: W, now: DateTime<Utc>) -> Result<W, Error> {
+        let mut out = NdjsonWriter::new(writer);
+        for name in self.zones()? {
+            let zone = self.zone(&name)?;
+            if let Ok(tzinfo) = zone.tzinfo(now) {
+                out.write_tzinfo(&tzinfo)?;
+            }
+            for tc in zone.timechanges() {
+                out.write_timechange(&name, tc)?;
+            }
+        }
+        out.flush()?;
+        Ok(out.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use std::{env, fs};
+    #[test]
+    fn ndjson() {
+        let now = Utc.ymd(2019, 7, 14).and_hms(12, 0, 0);
+        let phoenix = Zone::from_tzfile("/usr/share/zoneinfo/America/Phoenix").unwrap();
+        let mut out = NdjsonWriter::new(Vec::new());
+        out.write_zone(&phoenix, now).unwrap();
+        let out = String::from_utf8(out.into_inner()).unwrap();
+        let lines: Vec<Value> = out.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+        assert_eq!(lines.len(), 1 + phoenix.timechanges().len());
+        assert_eq!(lines[0], phoenix.tzinfo(now).unwrap().to_json_value().unwrap());
+        assert_eq!(lines[1]["time"], "1883-11-18T19:00:00Z");
+        assert_eq!(lines[1]["abbreviation"], "MST");
+        assert!(out.ends_with('\n'));
+
+        let dir = env::temp_dir().join(format!("tzparse-ndjson-{}", std::process::id()));
+        fs::create_dir_all(dir.join("America")).unwrap();
+        fs::copy("/usr/share/zoneinfo/America/Phoenix", dir.join("America/Phoenix")).unwrap();
+        fs::copy("/usr/share/zoneinfo/Etc/UTC", dir.join("UTC")).unwrap();
+        let export = TzDatabase::open(&dir).export_ndjson(Vec::new(), now).unwrap();
+        let zones: Vec<Value> = String::from_utf8(export)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        // UTC has a snapshot, without transitions
+        assert_eq!(zones.len(), 13);
+        assert_eq!(zones[12]["timezone"], "UTC");
+        assert_eq!(zones[0]["timezone"], "America/Phoenix");
+        assert_eq!(zones.iter().filter(|line| line["zone"] == "America/Phoenix").count(), 11);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,76 @@
+/// What to answer for instants and years outside a zone's recorded transitions,
+/// set per zone with Zone::with_range_policy or per query with ZoneQuery::range_policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RangePolicy {
+    /// Extrapolates with the POSIX footer rule after the last recorded transition,
+    /// and uses the initial local time type (ie. LMT) before the first one. The default.
+    #[default]
+    Extrapolate,
+    /// Answers with the nearest recorded transition: the last one after the recorded data, the first one before.
+    Clamp,
+    /// Fails with ErrorKind::OutOfRange. Zones without transitions have a single local time type and never fail.
+    Reject,
+}
+
+/// What an answer about a zone is based on, for auditing timestamp conversions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Provenance {
+    /// Recorded transitions
+    Recorded,
+    /// POSIX footer rule, extrapolating the zone after its last recorded transition
+    Rule,
+    /// Initial local time type before the first recorded transition, ie. Local Mean Time
+    Initial,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dst_bounds_with_provenance, project_transitions_at, ErrorKind, Zone, ZoneQuery};
+    use chrono::prelude::*;
+    #[test]
+    fn policies() {
+        let paris = Zone::from_tzfile("/usr/share/zoneinfo/Europe/Paris").unwrap();
+        let (summer, early) = (Utc.ymd(2100, 7, 14).and_hms(12, 0, 0), Utc.ymd(1850, 1, 1).and_hms(0, 0, 0));
+        assert_eq!(paris.try_offset_at(summer).unwrap().abbreviation, "CEST");
+        assert_eq!(paris.try_offset_at(early).unwrap().abbreviation, "LMT");
+        let clamped = paris.clone().with_range_policy(RangePolicy::Clamp);
+        // The last recorded transition, in October 2037
+        assert_eq!(clamped.try_offset_at(summer).unwrap().abbreviation, "CET");
+        assert_eq!(clamped.try_offset_at(early).unwrap().abbreviation, "PMT");
+        let strict = paris.with_range_policy(RangePolicy::Reject);
+        assert_eq!(strict.try_offset_at(summer).unwrap_err().kind(), ErrorKind::OutOfRange);
+        assert_eq!(strict.offset_at(early), None);
+        assert_eq!(strict.offset_at(Utc.ymd(2019, 7, 14).and_hms(12, 0, 0)).unwrap().abbreviation, "CEST");
+        let utc = Zone::from_tzfile("/usr/share/zoneinfo/Etc/UTC").unwrap().with_range_policy(RangePolicy::Reject);
+        assert_eq!(utc.try_offset_at(summer).unwrap().gmtoff, 0);
+
+        let query = |policy| ZoneQuery::new("Europe/Paris").years(2100).fallback(true).range_policy(policy).run();
+        assert_eq!(query(RangePolicy::Extrapolate).unwrap().len(), 2);
+        let clamped = query(RangePolicy::Clamp).unwrap();
+        assert_eq!(clamped.len(), 1);
+        assert_eq!(clamped[0].time, Utc.ymd(2037, 10, 25).and_hms(1, 0, 0));
+        assert_eq!(query(RangePolicy::Reject).unwrap_err().kind(), ErrorKind::OutOfRange);
+        assert_eq!(ZoneQuery::new("Europe/Paris").years(2019).range_policy(RangePolicy::Reject).run().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn provenance() {
+        let paris = Zone::from_tzfile("/usr/share/zoneinfo/Europe/Paris").unwrap();
+        let at = |y, m, d| Utc.ymd(y, m, d).and_hms(12, 0, 0);
+        assert_eq!(paris.offset_with_provenance(at(2019, 7, 14)).unwrap().1, Provenance::Recorded);
+        let (offset, provenance) = paris.offset_with_provenance(at(2100, 7, 14)).unwrap();
+        assert_eq!((offset.abbreviation.as_str(), provenance), ("CEST", Provenance::Rule));
+        assert_eq!(paris.provenance(at(1850, 1, 1)), Provenance::Initial);
+        let clamped = paris.clone().with_range_policy(RangePolicy::Clamp);
+        assert_eq!(clamped.provenance(at(2100, 7, 14)), Provenance::Recorded);
+        assert_eq!(clamped.provenance(at(1850, 1, 1)), Provenance::Recorded);
+        let projected = project_transitions_at(&paris, 1, at(2037, 1, 1));
+        let provenances: Vec<_> = projected.iter().map(|p| p.provenance()).collect();
+        assert_eq!(provenances, vec![Provenance::Recorded, Provenance::Recorded, Provenance::Rule, Provenance::Rule]);
+        assert_eq!(dst_bounds_with_provenance(&paris, 2019).unwrap().1, Provenance::Recorded);
+        assert_eq!(dst_bounds_with_provenance(&paris, 2050).unwrap().1, Provenance::Rule);
+        let utc = Zone::from_tzfile("/usr/share/zoneinfo/Etc/UTC").unwrap();
+        assert_eq!(utc.provenance(at(2019, 7, 14)), Provenance::Rule);
+    }
+}
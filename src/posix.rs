@@ -0,0 +1,410 @@
+use crate::{Error, ErrorKind, Timechange, ZoneOffset};
+use chrono::{prelude::*, Duration};
+use std::{fmt, str::FromStr};
+
+/// A POSIX TZ rule string, ie. "CET-1CEST,M3.5.0,M10.5.0/3".
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PosixTz {
+    /// Standard time abbreviation
+    pub std_abbreviation: String,
+    /// Standard offset to GMT, in seconds (positive east of Greenwich, unlike in the string)
+    pub std_offset: isize,
+    /// Daylight saving time, if observed
+    pub dst: Option<PosixDst>,
+}
+
+/// Daylight saving time part of a POSIX TZ string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PosixDst {
+    /// DST abbreviation
+    pub abbreviation: String,
+    /// DST offset to GMT, in seconds
+    pub offset: isize,
+    /// Start of DST, in standard local time
+    pub start: PosixRule,
+    /// End of DST, in DST local time
+    pub end: PosixRule,
+}
+
+/// Date and local time of a yearly change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PosixRule {
+    /// Day of the change
+    pub date: RuleDate,
+    /// Local time of the change, in seconds after midnight (may be negative or exceed 24 hours)
+    pub time: i32,
+}
+
+/// Day of a yearly change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuleDate {
+    /// Jn: day of year, 1 to 365, February 29th never counted
+    Julian(u16),
+    /// n: zero-based day of year, 0 to 365, February 29th counted
+    Day(u16),
+    /// Mm.w.d: weekday d (0 = Sunday) of week w (5 = last) of month m
+    MonthWeekDay { month: u8, week: u8, weekday: u8 },
+}
+
+impl FromStr for PosixTz {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<PosixTz, Error> {
+        let mut p = Parser { s: s.as_bytes(), pos: 0 };
+        PosixTz::from_parser(&mut p).map_err(|pos| {
+            Error::new(ErrorKind::InvalidPosixString, "parsing POSIX TZ string")
+                .with_zone(s)
+                .with_offset(pos as u64)
+        })
+    }
+}
+
+impl PosixTz {
+    /// Parses a TZ string, returning the position of the error on failure
+    fn from_parser(p: &mut Parser) -> Result<PosixTz, usize> {
+        let std_abbreviation = p.name()?;
+        let std_offset = -p.hms(24)? as isize;
+        let dst = if p.at_end() {
+            None
+        } else {
+            let abbreviation = p.name()?;
+            let offset = match p.peek() {
+                Some(c) if c.is_ascii_digit() || c == b'+' || c == b'-' => -p.hms(24)? as isize,
+                _ => std_offset + 3600,
+            };
+            // Rules default to the US ones, like glibc does without a posixrules file
+            let (start, end) = if p.eat(b',') {
+                let start = p.rule()?;
+                p.expect(b',')?;
+                (start, p.rule()?)
+            } else {
+                (
+                    PosixRule { date: RuleDate::MonthWeekDay { month: 3, week: 2, weekday: 0 }, time: 7200 },
+                    PosixRule { date: RuleDate::MonthWeekDay { month: 11, week: 1, weekday: 0 }, time: 7200 },
+                )
+            };
+            Some(PosixDst { abbreviation, offset, start, end })
+        };
+        if !p.at_end() {
+            return Err(p.pos);
+        }
+        Ok(PosixTz { std_abbreviation, std_offset, dst })
+    }
+}
+
+impl fmt::Display for PosixTz {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_name(f, &self.std_abbreviation)?;
+        write_hms(f, (self.std_offset as i64).wrapping_neg())?;
+        if let Some(dst) = &self.dst {
+            write_name(f, &dst.abbreviation)?;
+            if dst.offset != self.std_offset + 3600 {
+                write_hms(f, (dst.offset as i64).wrapping_neg())?;
+            }
+            write!(f, ",{},{}", dst.start, dst.end)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for PosixRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.date)?;
+        if self.time != 7200 {
+            f.write_str("/")?;
+            write_hms(f, self.time as i64)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for RuleDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuleDate::Julian(n) => write!(f, "J{}", n),
+            RuleDate::Day(n) => write!(f, "{}", n),
+            RuleDate::MonthWeekDay { month, week, weekday } => write!(f, "M{}.{}.{}", month, week, weekday),
+        }
+    }
+}
+
+fn write_name(f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+    if name.chars().all(|c| c.is_ascii_alphabetic()) {
+        f.write_str(name)
+    } else {
+        write!(f, "<{}>", name)
+    }
+}
+
+fn write_hms(f: &mut fmt::Formatter, seconds: i64) -> fmt::Result {
+    if seconds < 0 {
+        f.write_str("-")?;
+    }
+    let seconds = seconds.checked_abs().unwrap_or(i64::MAX);
+    write!(f, "{}", seconds / 3600)?;
+    if seconds % 3600 != 0 {
+        write!(f, ":{:02}", seconds / 60 % 60)?;
+        if seconds % 60 != 0 {
+            write!(f, ":{:02}", seconds % 60)?;
+        }
+    }
+    Ok(())
+}
+
+impl PosixTz {
+    /// Returns the year's timechanges, in chronological order. Empty if DST is not observed.
+    pub fn timechanges(&self, year: i32) -> Vec<Timechange> {
+        let dst = match &self.dst {
+            Some(dst) => dst,
+            None => return Vec::new(),
+        };
+        let mut timechanges = Vec::new();
+        if let Some(time) = dst.start.utc(year, self.std_offset) {
+            timechanges.push(Timechange {
+                time,
+                gmtoff: dst.offset,
+                isdst: true,
+                abbreviation: dst.abbreviation.clone(),
+            });
+        }
+        if let Some(time) = dst.end.utc(year, dst.offset) {
+            timechanges.push(Timechange {
+                time,
+                gmtoff: self.std_offset,
+                isdst: false,
+                abbreviation: self.std_abbreviation.clone(),
+            });
+        }
+        timechanges.sort_by_key(|tc| tc.time);
+        timechanges
+    }
+
+    /// Returns the local time type in force at the given instant.
+    pub fn offset_at(&self, time: DateTime<Utc>) -> ZoneOffset {
+        let year = time.year();
+        // Southern hemisphere DST started the previous year
+        let in_force = (year.saturating_sub(1)..=year)
+            .rev()
+            .flat_map(|y| self.timechanges(y).into_iter().rev())
+            .find(|tc| tc.time <= time);
+        match in_force {
+            Some(tc) => ZoneOffset {
+                gmtoff: tc.gmtoff,
+                isdst: tc.isdst,
+                abbreviation: tc.abbreviation,
+            },
+            None => ZoneOffset {
+                gmtoff: self.std_offset,
+                isdst: false,
+                abbreviation: self.std_abbreviation.clone(),
+            },
+        }
+    }
+}
+
+impl PosixRule {
+    /// UTC time of the change in given year, the rule's local time being at `gmtoff`
+    fn utc(&self, year: i32, gmtoff: isize) -> Option<DateTime<Utc>> {
+        // Offsets are checked against a week, so that Duration never overflows
+        const WEEK: i64 = 7 * 86400;
+        let gmtoff = gmtoff as i64;
+        if !(-WEEK..=WEEK).contains(&gmtoff) {
+            return None;
+        }
+        let local = self
+            .date
+            .date(year)?
+            .and_hms_opt(0, 0, 0)?
+            .checked_add_signed(Duration::seconds(self.time as i64 - gmtoff))?;
+        Some(Utc.from_utc_datetime(&local))
+    }
+}
+
+impl RuleDate {
+    /// Day of the change in given year
+    fn date(&self, year: i32) -> Option<NaiveDate> {
+        let leap = NaiveDate::from_ymd_opt(year, 2, 29).is_some();
+        match *self {
+            RuleDate::Julian(n) if leap && n >= 60 => NaiveDate::from_yo_opt(year, n as u32 + 1),
+            RuleDate::Julian(n) => NaiveDate::from_yo_opt(year, n as u32),
+            RuleDate::Day(n) => NaiveDate::from_yo_opt(year, n as u32 + 1),
+            RuleDate::MonthWeekDay { month, week, weekday } => {
+                let first = NaiveDate::from_ymd_opt(year, month as u32, 1)?;
+                let next = if month == 12 {
+                    NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+                } else {
+                    NaiveDate::from_ymd_opt(year, month as u32 + 1, 1)?
+                };
+                let days_in_month = next.signed_duration_since(first).num_days() as u32;
+                let shift = (weekday as u32 + 7 - first.weekday().num_days_from_sunday()) % 7;
+                let mut day = 1 + shift + (week as u32).saturating_sub(1) * 7;
+                while day > days_in_month {
+                    day -= 7;
+                }
+                NaiveDate::from_ymd_opt(year, month as u32, day)
+            }
+        }
+    }
+}
+
+struct Parser<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos == self.s.len()
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> Result<(), usize> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.pos)
+        }
+    }
+
+    /// Abbreviation: at least 3 letters, or <quoted> alphanumerics and signs
+    fn name(&mut self) -> Result<String, usize> {
+        let quoted = self.eat(b'<');
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            let valid = if quoted {
+                c.is_ascii_alphanumeric() || c == b'+' || c == b'-'
+            } else {
+                c.is_ascii_alphabetic()
+            };
+            if !valid {
+                break;
+            }
+            self.pos += 1;
+        }
+        let name = String::from_utf8_lossy(&self.s[start..self.pos]).into_owned();
+        if quoted {
+            self.expect(b'>')?;
+        }
+        if name.len() < 3 {
+            return Err(self.pos);
+        }
+        Ok(name)
+    }
+
+    fn number(&mut self, max: u32) -> Result<u32, usize> {
+        let start = self.pos;
+        let mut n: u32 = 0;
+        while let Some(c) = self.peek() {
+            if !c.is_ascii_digit() {
+                break;
+            }
+            n = n.saturating_mul(10).saturating_add((c - b'0') as u32);
+            self.pos += 1;
+        }
+        if self.pos == start || n > max {
+            return Err(self.pos);
+        }
+        Ok(n)
+    }
+
+    /// [+-]hh[:mm[:ss]], in seconds
+    fn hms(&mut self, max_hours: u32) -> Result<i32, usize> {
+        let sign = if self.eat(b'-') {
+            -1
+        } else {
+            self.eat(b'+');
+            1
+        };
+        let mut seconds = self.number(max_hours)? * 3600;
+        if self.eat(b':') {
+            seconds += self.number(59)? * 60;
+            if self.eat(b':') {
+                seconds += self.number(59)?;
+            }
+        }
+        Ok(sign * seconds as i32)
+    }
+
+    /// date[/time]
+    fn rule(&mut self) -> Result<PosixRule, usize> {
+        let date = if self.eat(b'J') {
+            let n = self.number(365)?;
+            if n == 0 {
+                return Err(self.pos);
+            }
+            RuleDate::Julian(n as u16)
+        } else if self.eat(b'M') {
+            let month = self.number(12)?;
+            self.expect(b'.')?;
+            let week = self.number(5)?;
+            self.expect(b'.')?;
+            let weekday = self.number(6)?;
+            if month == 0 || week == 0 {
+                return Err(self.pos);
+            }
+            RuleDate::MonthWeekDay { month: month as u8, week: week as u8, weekday: weekday as u8 }
+        } else {
+            RuleDate::Day(self.number(365)? as u16)
+        };
+        // Hours beyond 24 and negative times are allowed, as in TZif version 3 footers
+        let time = if self.eat(b'/') { self.hms(167)? } else { 7200 };
+        Ok(PosixRule { date, time })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn parse_and_evaluate() {
+        let paris: PosixTz = "CET-1CEST,M3.5.0,M10.5.0/3".parse().unwrap();
+        assert_eq!(paris.std_offset, 3600);
+        assert_eq!(paris.dst.as_ref().unwrap().offset, 7200);
+        let tz = vec![
+            Timechange {
+                time: Utc.ymd(2019, 3, 31).and_hms(1, 0, 0),
+                gmtoff: 7200,
+                isdst: true,
+                abbreviation: String::from("CEST"),
+            },
+            Timechange {
+                time: Utc.ymd(2019, 10, 27).and_hms(1, 0, 0),
+                gmtoff: 3600,
+                isdst: false,
+                abbreviation: String::from("CET"),
+            },
+        ];
+        assert_eq!(paris.timechanges(2019), tz);
+
+        // Southern hemisphere: DST ends in April, starts in October
+        let sydney: PosixTz = "AEST-10AEDT,M10.1.0,M4.1.0/3".parse().unwrap();
+        let changes = sydney.timechanges(2020);
+        assert_eq!(changes[0].time, Utc.ymd(2020, 4, 4).and_hms(16, 0, 0));
+        assert!(!changes[0].isdst);
+        assert_eq!(changes[1].time, Utc.ymd(2020, 10, 3).and_hms(16, 0, 0));
+
+        let kolkata: PosixTz = "<+0530>-5:30".parse().unwrap();
+        assert_eq!(kolkata.std_abbreviation, "+0530");
+        assert_eq!(kolkata.std_offset, 19800);
+        assert!(kolkata.timechanges(2020).is_empty());
+
+        for s in &["CET-1CEST,M3.5.0,M10.5.0/3", "<+0530>-5:30", "EST5EDT,M3.2.0,M11.1.0", "<-03>3<-02>,M3.5.0/-2,M10.5.0/-1"] {
+            assert_eq!(s.parse::<PosixTz>().unwrap().to_string(), *s);
+        }
+        for s in &["", "CE", "CET", "CET-1CEST,M3.5.0", "CET-1CEST,M13.5.0,M10.5.0", "<+05"] {
+            assert!(s.parse::<PosixTz>().is_err(), "{} accepted", s);
+        }
+    }
+}
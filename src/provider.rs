@@ -0,0 +1,82 @@
+use crate::{
+    leap, timechanges, tzif::Tzif, Error, LeapSecond, Limits, RangePolicy, Resolver, Timechange, YearSpec, ZoneOffset,
+};
+use std::path::Path;
+
+/// Source of zone transitions. The tzfile reader is the default one, other backends
+/// (embedded tables, network services, test fixtures) plug in by implementing this trait, then loading a Zone.
+pub trait TransitionProvider {
+    /// Returns all timechanges of a zone, in chronological order.
+    fn timechanges(&self, zone: &str) -> Result<Vec<Timechange>, Error>;
+
+    /// Returns the POSIX TZ string ruling the zone after its last timechange, if any.
+    fn footer(&self, _zone: &str) -> Result<Option<String>, Error> {
+        Ok(None)
+    }
+
+    /// Returns the local time type in force before the first timechange, if known.
+    fn initial(&self, _zone: &str) -> Result<Option<ZoneOffset>, Error> {
+        Ok(None)
+    }
+
+    /// Returns the leap seconds counted by the zone's clock, if it is TAI-based.
+    fn leap_seconds(&self, _zone: &str) -> Result<Vec<LeapSecond>, Error> {
+        Ok(Vec::new())
+    }
+}
+
+/// Reads zones from tzfiles. Zone names are looked up with a Resolver, absolute paths are read as is.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TzfileProvider {
+    resolver: Resolver,
+    limits: Limits,
+}
+
+impl TzfileProvider {
+    /// Creates a provider looking up zone names with the given resolver.
+    pub fn new(resolver: Resolver) -> TzfileProvider {
+        TzfileProvider {
+            resolver,
+            limits: Limits::UNLIMITED,
+        }
+    }
+
+    /// Rejects tzfiles exceeding the given limits before parsing them.
+    pub fn limits(&mut self, limits: Limits) -> &mut TzfileProvider {
+        self.limits = limits;
+        self
+    }
+
+    fn path(&self, zone: &str) -> Result<String, Error> {
+        if Path::new(zone).is_absolute() {
+            Ok(zone.to_string())
+        } else {
+            self.resolver.resolve(zone)
+        }
+    }
+}
+
+/// Provider looking up zone names in the system search path (see Resolver::system).
+impl Default for TzfileProvider {
+    fn default() -> TzfileProvider {
+        TzfileProvider::new(Resolver::system())
+    }
+}
+
+impl TransitionProvider for TzfileProvider {
+    fn timechanges(&self, zone: &str) -> Result<Vec<Timechange>, Error> {
+        timechanges(&self.path(zone)?, &YearSpec::All, &self.limits, false, RangePolicy::Extrapolate)
+    }
+
+    fn footer(&self, zone: &str) -> Result<Option<String>, Error> {
+        Ok(Tzif::load(&self.path(zone)?, &self.limits)?.footer)
+    }
+
+    fn initial(&self, zone: &str) -> Result<Option<ZoneOffset>, Error> {
+        Ok(Tzif::load(&self.path(zone)?, &self.limits)?.initial())
+    }
+
+    fn leap_seconds(&self, zone: &str) -> Result<Vec<LeapSecond>, Error> {
+        Ok(leap::leap_seconds(&Tzif::load(&self.path(zone)?, &self.limits)?.leap_seconds))
+    }
+}
@@ -0,0 +1,300 @@
+use crate::{
+    group_by_year, select, tzif::Tzif, Clock, Error, Limits, RangePolicy, Resolver, Timechange, TransitionKind,
+    YearSpec,
+};
+use chrono::prelude::*;
+use std::{collections::BTreeMap, path::Path};
+
+/// Chronological order of a query's results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// Oldest first
+    Asc,
+    /// Newest first
+    Desc,
+}
+
+/// Query on a zone's timechanges, gathering the selection and filtering options in one place:
+/// `ZoneQuery::new("Europe/Paris").years(2020..=2030).dst_only(true).order(Order::Desc).run()`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZoneQuery {
+    zone: String,
+    resolver: Resolver,
+    years: YearSpec,
+    fallback: bool,
+    dst_only: bool,
+    direction: Option<TransitionKind>,
+    dedup: bool,
+    since_1970: bool,
+    order: Order,
+    offset: usize,
+    limit: Option<usize>,
+    limits: Limits,
+    range_policy: RangePolicy,
+    now: Option<DateTime<Utc>>,
+}
+
+impl ZoneQuery {
+    /// Creates a query on all recorded timechanges of a zone.
+    /// The zone is either a tzfile path or a zone name (ie. "Europe/Paris") resolved with Resolver::system.
+    pub fn new(zone: &str) -> ZoneQuery {
+        ZoneQuery {
+            zone: zone.to_string(),
+            resolver: Resolver::system(),
+            years: YearSpec::All,
+            fallback: false,
+            dst_only: false,
+            direction: None,
+            dedup: false,
+            since_1970: false,
+            order: Order::Asc,
+            offset: 0,
+            limit: None,
+            limits: Limits::UNLIMITED,
+            range_policy: RangePolicy::Extrapolate,
+            now: None,
+        }
+    }
+
+    /// Resolves the zone name with this resolver instead of the system one.
+    pub fn resolver(&mut self, resolver: Resolver) -> &mut ZoneQuery {
+        self.resolver = resolver;
+        self
+    }
+
+    /// Selects years: a YearSpec, a single year or a range of years.
+    pub fn years<Y: Into<YearSpec>>(&mut self, years: Y) -> &mut ZoneQuery {
+        self.years = years.into();
+        self
+    }
+
+    /// When no timechange occurs in the selected years, returns the one in force (see get_timechanges_for).
+    /// Off by default: the result is then empty.
+    pub fn fallback(&mut self, fallback: bool) -> &mut ZoneQuery {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Keeps DST timechanges only.
+    pub fn dst_only(&mut self, dst_only: bool) -> &mut ZoneQuery {
+        self.dst_only = dst_only;
+        self
+    }
+
+    /// Keeps timechanges of this kind only, ie. TransitionKind::FallBack for the ends of DST
+    /// (and the starts of negative DST, as in Europe/Dublin).
+    pub fn direction(&mut self, direction: TransitionKind) -> &mut ZoneQuery {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Drops no-op timechanges, which keep the offset, DST flag and abbreviation of the previous one.
+    /// The timechange in force returned by fallback is always kept.
+    pub fn dedup(&mut self, dedup: bool) -> &mut ZoneQuery {
+        self.dedup = dedup;
+        self
+    }
+
+    /// Drops timechanges before 1970, where tzdata does not guarantee accuracy and zones start with Local Mean Time.
+    /// The timechange in force returned by fallback is always kept.
+    pub fn since_1970(&mut self, since_1970: bool) -> &mut ZoneQuery {
+        self.since_1970 = since_1970;
+        self
+    }
+
+    /// Sets the order of results, chronological by default.
+    pub fn order(&mut self, order: Order) -> &mut ZoneQuery {
+        self.order = order;
+        self
+    }
+
+    /// Skips the first `offset` results, for paging through long histories.
+    /// Paging applies to the filtered results, in the requested order: the filters stop once the page is full.
+    pub fn offset(&mut self, offset: usize) -> &mut ZoneQuery {
+        self.offset = offset;
+        self
+    }
+
+    /// Returns at most `limit` results, for paging through long histories.
+    pub fn limit(&mut self, limit: usize) -> &mut ZoneQuery {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Rejects a tzfile exceeding the given limits before parsing it.
+    pub fn limits(&mut self, limits: Limits) -> &mut ZoneQuery {
+        self.limits = limits;
+        self
+    }
+
+    /// Sets what to answer for years outside the recorded transitions, RangePolicy::Extrapolate by default.
+    pub fn range_policy(&mut self, policy: RangePolicy) -> &mut ZoneQuery {
+        self.range_policy = policy;
+        self
+    }
+
+    /// Reads the current year of YearSpec::Current from this clock rather than the system one.
+    pub fn clock<C: Clock + ?Sized>(&mut self, clock: &C) -> &mut ZoneQuery {
+        self.now = Some(clock.now());
+        self
+    }
+
+    /// Takes the current year of YearSpec::Current from this instant rather than the system clock.
+    pub fn now(&mut self, now: DateTime<Utc>) -> &mut ZoneQuery {
+        self.now = Some(now);
+        self
+    }
+
+    /// Runs the query.
+    pub fn run(&self) -> Result<Vec<Timechange>, Error> {
+        let path = if Path::new(&self.zone).is_absolute() {
+            self.zone.clone()
+        } else {
+            self.resolver.resolve(&self.zone)?
+        };
+        let years = match self.now {
+            Some(now) => self.years.at(now),
+            None => self.years.clone(),
+        };
+        let tzif = Tzif::load(&path, &self.limits)?;
+        let mut result = select(&path, &tzif, &years, false, self.range_policy)?;
+        let fell_back = result.is_empty() && self.fallback;
+        if fell_back {
+            result = select(&path, &tzif, &years, true, self.range_policy)?;
+        }
+        // The previous timechange may lie before the selected years, recorded or extrapolated with the footer rule
+        let mut all = Vec::new();
+        if self.direction.is_some() || (self.dedup && !fell_back) {
+            all = select(&path, &tzif, &YearSpec::All, false, RangePolicy::Extrapolate)?;
+            if let Some((first, last)) = years.bounds() {
+                let around = YearSpec::Range(first.saturating_sub(1)..=last);
+                let latest = all.last().map(|tc| tc.time);
+                let projected = select(&path, &tzif, &around, false, RangePolicy::Extrapolate)?;
+                all.extend(projected.into_iter().filter(|tc| !matches!(latest, Some(latest) if tc.time <= latest)));
+            }
+        }
+        let previous = |tc: &Timechange| match all.binary_search_by_key(&tc.time, |a| a.time) {
+            Ok(i) if i > 0 => all.get(i - 1),
+            _ => None,
+        };
+        let keep = |tc: &Timechange| {
+            if self.dst_only && !tc.isdst {
+                return false;
+            }
+            if self.since_1970 && !fell_back && tc.time.timestamp() < 0 {
+                return false;
+            }
+            if let Some(direction) = self.direction {
+                if TransitionKind::of(previous(tc), tc) != direction {
+                    return false;
+                }
+            }
+            if self.dedup && !fell_back {
+                if let Some(p) = previous(tc) {
+                    return p.gmtoff != tc.gmtoff || p.isdst != tc.isdst || p.abbreviation != tc.abbreviation;
+                }
+            }
+            true
+        };
+        // Filters run in the requested order, and stop once the page is full
+        let ordered: Box<dyn Iterator<Item = Timechange>> = match self.order {
+            Order::Asc => Box::new(result.into_iter()),
+            Order::Desc => Box::new(result.into_iter().rev()),
+        };
+        let limit = self.limit.unwrap_or(usize::MAX);
+        Ok(ordered.filter(|tc| keep(tc)).skip(self.offset).take(limit).collect())
+    }
+
+    /// Runs the query, grouping the timechanges by (UTC) year.
+    pub fn run_by_year(&self) -> Result<BTreeMap<i32, Vec<Timechange>>, Error> {
+        Ok(group_by_year(self.run()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn query() {
+        let result = ZoneQuery::new("Europe/Paris")
+            .years(2019..=2021)
+            .dst_only(true)
+            .order(Order::Desc)
+            .run()
+            .unwrap();
+        let starts: Vec<_> = result.iter().map(|tc| tc.time).collect();
+        assert_eq!(
+            starts,
+            vec![
+                Utc.ymd(2021, 3, 28).and_hms(1, 0, 0),
+                Utc.ymd(2020, 3, 29).and_hms(1, 0, 0),
+                Utc.ymd(2019, 3, 31).and_hms(1, 0, 0),
+            ]
+        );
+        assert!(ZoneQuery::new("America/Phoenix").years(2020).run().unwrap().is_empty());
+        assert_eq!(ZoneQuery::new("America/Phoenix").years(2020).fallback(true).run().unwrap().len(), 1);
+        assert!(ZoneQuery::new("../etc/passwd").run().is_err());
+        let clock = crate::FixedClock(Utc.ymd(2019, 7, 14).and_hms(12, 0, 0));
+        let current = ZoneQuery::new("Europe/Paris").years(YearSpec::Current).clock(&clock).run().unwrap();
+        assert_eq!(current[0].time, Utc.ymd(2019, 3, 31).and_hms(1, 0, 0));
+    }
+
+    #[test]
+    fn paging() {
+        let mut query = ZoneQuery::new("Europe/Paris");
+        query.order(Order::Desc).limit(10);
+        let first = query.run().unwrap();
+        let second = query.offset(10).run().unwrap();
+        assert_eq!(first.len(), 10);
+        assert_eq!(second.len(), 10);
+        assert!(first[9].time > second[0].time);
+        assert!(query.offset(10_000).run().unwrap().is_empty());
+        // Pages of filtered results, newest first
+        let mut query = ZoneQuery::new("Europe/Paris");
+        query.dst_only(true).direction(TransitionKind::SpringForward).order(Order::Desc);
+        let filtered = query.run().unwrap();
+        assert_eq!(query.offset(5).limit(3).run().unwrap(), filtered[5..8].to_vec());
+    }
+
+    #[test]
+    fn dedup() {
+        // zic marks the end of 32-bit time with a no-op timechange
+        let all = ZoneQuery::new("Asia/Dubai").run().unwrap();
+        let meaningful = ZoneQuery::new("Asia/Dubai").dedup(true).run().unwrap();
+        assert_eq!(all.last().unwrap().time, Utc.ymd(2038, 1, 19).and_hms(3, 14, 7));
+        assert_eq!(meaningful.len(), all.len() - 1);
+        assert!(ZoneQuery::new("Asia/Dubai").years(2038).dedup(true).run().unwrap().is_empty());
+        assert_eq!(ZoneQuery::new("Asia/Dubai").years(2040).dedup(true).fallback(true).run().unwrap().len(), 1);
+        // Extrapolated timechanges are compared to the extrapolated previous one, not dropped nor kept blindly
+        let late = ZoneQuery::new("Europe/Paris").years(2037..=2040).dedup(true).run().unwrap();
+        assert_eq!(late, ZoneQuery::new("Europe/Paris").years(2037..=2040).run().unwrap());
+        assert_eq!(late.len(), 8);
+    }
+
+    #[test]
+    fn direction() {
+        let ends = ZoneQuery::new("Europe/Paris").years(2020).direction(TransitionKind::FallBack).run().unwrap();
+        assert_eq!(ends.len(), 1);
+        assert_eq!(ends[0].time, Utc.ymd(2020, 10, 25).and_hms(1, 0, 0));
+        // Phoenix switched from Local Mean Time to MST in 1883
+        let changes = ZoneQuery::new("America/Phoenix").direction(TransitionKind::StandardOffsetChange).run().unwrap();
+        assert_eq!(changes[0].time.year(), 1883);
+        // Past the recorded timechanges, the previous one is extrapolated too
+        let ends = ZoneQuery::new("Europe/Paris").years(2040).direction(TransitionKind::FallBack).run().unwrap();
+        assert_eq!(ends, vec![Timechange::new(Utc.ymd(2040, 10, 28).and_hms(1, 0, 0), 3600, false, "CET")]);
+        let ends = ZoneQuery::new("Europe/Paris").years(2037..=2038).direction(TransitionKind::FallBack).run().unwrap();
+        assert_eq!(ends.len(), 2);
+        // Negative DST: clocks go back in October, when entering GMT flagged DST
+        let dublin = ZoneQuery::new("Europe/Dublin").years(2019).direction(TransitionKind::FallBack).run().unwrap();
+        assert_eq!(dublin, vec![Timechange::new(Utc.ymd(2019, 10, 27).and_hms(1, 0, 0), 0, true, "GMT")]);
+    }
+
+    #[test]
+    fn since_1970() {
+        let modern = ZoneQuery::new("Europe/Paris").since_1970(true).run().unwrap();
+        assert_eq!(modern[0].time, Utc.ymd(1976, 3, 28).and_hms(0, 0, 0));
+        assert!(ZoneQuery::new("America/Phoenix").since_1970(true).run().unwrap().is_empty());
+        let in_force = ZoneQuery::new("America/Phoenix").years(2020).fallback(true).since_1970(true).run().unwrap();
+        assert_eq!(in_force[0].time.year(), 1967);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{Error, ErrorKind, Timechange, ZoneOffset};
+use chrono::prelude::*;
+use libtzfile::Tz;
+
+/// A tzfile as parsed by libtzfile, for the fields this crate does not surface.
+/// The parsed structure is available with inner(), without depending on a matching libtzfile version.
+#[derive(Debug)]
+pub struct RawTzfile {
+    tz: Tz,
+}
+
+/// Parses a tzfile with libtzfile, see RawTzfile.
+pub fn get_raw_tzfile(requested_timezone: &str) -> Result<RawTzfile, Error> {
+    libtzfile::parse(requested_timezone)
+        .map(RawTzfile::from)
+        .map_err(|e| Error::tzfile(e, "parsing tzfile").with_zone(requested_timezone))
+}
+
+impl From<Tz> for RawTzfile {
+    fn from(tz: Tz) -> RawTzfile {
+        RawTzfile { tz }
+    }
+}
+
+impl RawTzfile {
+    /// The structure parsed by libtzfile
+    pub fn inner(&self) -> &Tz {
+        &self.tz
+    }
+
+    /// Returns the structure parsed by libtzfile.
+    pub fn into_inner(self) -> Tz {
+        self.tz
+    }
+
+    /// Transition times, as recorded: right/ tzfiles count leap seconds in them
+    pub fn transition_times(&self) -> &[i64] {
+        &self.tz.tzh_timecnt_data
+    }
+
+    /// Index of the local time type of each transition
+    pub fn transition_types(&self) -> &[u8] {
+        &self.tz.tzh_timecnt_indices
+    }
+
+    /// Abbreviations, indexed by the local time types
+    pub fn abbreviations(&self) -> &[String] {
+        &self.tz.tz_abbr
+    }
+
+    /// Returns a local time type, None if the index or its abbreviation index is out of bounds.
+    pub fn local_time_type(&self, index: usize) -> Option<ZoneOffset> {
+        let ttinfo = self.tz.tzh_typecnt.get(index)?;
+        Some(ZoneOffset {
+            gmtoff: ttinfo.tt_gmtoff,
+            isdst: ttinfo.tt_isdst == 1,
+            abbreviation: self.tz.tz_abbr.get(ttinfo.tt_abbrind as usize)?.to_string(),
+        })
+    }
+
+    /// Returns the local time types, in tzfile order.
+    pub fn local_time_types(&self) -> Result<Vec<ZoneOffset>, Error> {
+        (0..self.tz.tzh_typecnt.len())
+            .map(|i| self.local_time_type(i).ok_or_else(|| Error::new(ErrorKind::InvalidTzfile, "converting tzfile")))
+            .collect()
+    }
+
+    /// Converts the transitions to timechanges, as recorded: unlike Zone, timestamps are not corrected
+    /// for leap seconds nor sorted.
+    pub fn timechanges(&self) -> Result<Vec<Timechange>, Error> {
+        const OPERATION: &str = "converting tzfile";
+        self.transition_times()
+            .iter()
+            .zip(self.transition_types())
+            .map(|(time, index)| {
+                let offset = self
+                    .local_time_type(*index as usize)
+                    .ok_or_else(|| Error::new(ErrorKind::InvalidTzfile, OPERATION))?;
+                let time = Utc
+                    .timestamp_opt(*time, 0)
+                    .single()
+                    .ok_or_else(|| Error::new(ErrorKind::OutOfRange, OPERATION))?;
+                Ok(Timechange::new(time, offset.gmtoff, offset.isdst, &offset.abbreviation))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{get_timechanges_for, YearSpec};
+    #[test]
+    fn raw() {
+        let raw = get_raw_tzfile("/usr/share/zoneinfo/America/Phoenix").unwrap();
+        assert_eq!(raw.transition_times().len(), raw.inner().tzh_timecnt_data.len());
+        assert_eq!(raw.transition_types().len(), raw.transition_times().len());
+        let all = get_timechanges_for("/usr/share/zoneinfo/America/Phoenix", YearSpec::All).unwrap();
+        assert_eq!(raw.timechanges().unwrap(), all);
+        let types = raw.local_time_types().unwrap();
+        assert!(types.contains(&ZoneOffset { gmtoff: -25200, isdst: false, abbreviation: "MST".to_string() }));
+        assert_eq!(raw.local_time_type(types.len()), None);
+        assert!(get_raw_tzfile("/usr/share/zoneinfo/Nowhere").is_err());
+    }
+}
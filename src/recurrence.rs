@@ -0,0 +1,96 @@
+use crate::{Disambiguation, Error, ErrorKind, Zone};
+use chrono::prelude::*;
+use std::ops::Range;
+
+/// A recurring wall-clock time, ie. "every weekday at 09:00", expanded in a zone so that it keeps its local time
+/// across timechanges.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Recurrence {
+    time: NaiveTime,
+    weekdays: Vec<Weekday>,
+    policy: Disambiguation,
+}
+
+impl Recurrence {
+    /// Occurs every day at `time`.
+    pub fn daily(time: NaiveTime) -> Recurrence {
+        Recurrence {
+            time,
+            weekdays: Vec::new(),
+            policy: Disambiguation::Compatible,
+        }
+    }
+
+    /// Occurs every week on `weekday` at `time`.
+    pub fn weekly(time: NaiveTime, weekday: Weekday) -> Recurrence {
+        Recurrence {
+            weekdays: vec![weekday],
+            ..Recurrence::daily(time)
+        }
+    }
+
+    /// Occurs on these days of the week only.
+    pub fn weekdays(&mut self, weekdays: &[Weekday]) -> &mut Recurrence {
+        self.weekdays = weekdays.to_vec();
+        self
+    }
+
+    /// Resolves an occurrence skipped or repeated by a timechange with this policy, Disambiguation::Compatible by default.
+    /// With Disambiguation::Reject, such an occurrence is dropped.
+    pub fn policy(&mut self, policy: Disambiguation) -> &mut Recurrence {
+        self.policy = policy;
+        self
+    }
+
+    /// Returns the occurrences within `range` (start included, end excluded) in chronological order.
+    pub fn expand(&self, zone: &Zone, range: Range<DateTime<Utc>>) -> Result<Vec<DateTime<Utc>>, Error> {
+        let out_of_range = || Error::new(ErrorKind::OutOfRange, "expanding recurrence").with_zone(zone.name());
+        if range.start >= range.end {
+            return Ok(Vec::new());
+        }
+        // A day around the range, in case the policy moves an occurrence across midnight
+        let mut date = zone.to_local(range.start)?.naive_local().date().pred_opt().ok_or_else(out_of_range)?;
+        let last = zone.to_local(range.end)?.naive_local().date().succ_opt().ok_or_else(out_of_range)?;
+        let mut occurrences = Vec::new();
+        while date <= last {
+            if self.weekdays.is_empty() || self.weekdays.contains(&date.weekday()) {
+                match zone.to_utc(date.and_time(self.time), self.policy) {
+                    Ok(instant) if range.contains(&instant) => occurrences.push(instant),
+                    Ok(_) => {}
+                    Err(e) if matches!(e.kind(), ErrorKind::AmbiguousLocalTime | ErrorKind::NonexistentLocalTime) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            date = date.succ_opt().ok_or_else(out_of_range)?;
+        }
+        occurrences.sort();
+        occurrences.dedup();
+        Ok(occurrences)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TzDatabase;
+    #[test]
+    fn expand() {
+        let paris = TzDatabase::open("/usr/share/zoneinfo").zone("Europe/Paris").unwrap();
+        let daily = Recurrence::daily(NaiveTime::from_hms(9, 0, 0));
+        let range = Utc.ymd(2019, 3, 30).and_hms(0, 0, 0)..Utc.ymd(2019, 4, 2).and_hms(0, 0, 0);
+        let hours: Vec<_> = daily.expand(&paris, range).unwrap().iter().map(|t| t.hour()).collect();
+        assert_eq!(hours, vec![8, 7, 7]);
+        // 02:30 does not exist on the last Sunday of March
+        let mut weekly = Recurrence::weekly(NaiveTime::from_hms(2, 30, 0), Weekday::Sun);
+        let march = Utc.ymd(2019, 3, 1).and_hms(0, 0, 0)..Utc.ymd(2019, 4, 1).and_hms(0, 0, 0);
+        assert_eq!(weekly.expand(&paris, march.clone()).unwrap().len(), 5);
+        let rejected = weekly.policy(Disambiguation::Reject).expand(&paris, march).unwrap();
+        let days: Vec<_> = rejected.iter().map(|t| t.day()).collect();
+        assert_eq!(days, vec![3, 10, 17, 24]);
+        let weekdays = Recurrence::daily(NaiveTime::from_hms(9, 0, 0))
+            .weekdays(&[Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri])
+            .expand(&paris, Utc.ymd(2019, 7, 1).and_hms(0, 0, 0)..Utc.ymd(2019, 8, 1).and_hms(0, 0, 0))
+            .unwrap();
+        assert_eq!(weekdays.len(), 23);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{canonical_zone, Error, ErrorKind};
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+/// System zoneinfo directory: Linux, macOS and the BSDs
+#[cfg(not(any(target_os = "solaris", target_os = "illumos", target_os = "aix")))]
+pub const ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+/// System zoneinfo directory: Solaris, illumos and AIX
+#[cfg(any(target_os = "solaris", target_os = "illumos", target_os = "aix"))]
+pub const ZONEINFO_DIR: &str = "/usr/share/lib/zoneinfo";
+
+/// Returns the tzfile path of a zone name (ie. "Europe/Paris"), looked up in the system search path (see Resolver::system).
+pub fn resolve_zone(name: &str) -> Result<String, Error> {
+    Resolver::system().resolve(name)
+}
+
+/// Returns the tzfile path of a zone name (ie. "Europe/Paris") in the given zoneinfo directory.
+/// Names with empty, "." or ".." components, absolute names and backslashes are rejected,
+/// so a user-supplied name can never designate a file outside the directory.
+pub fn resolve_zone_in(root: &str, name: &str) -> Result<String, Error> {
+    check_name(name)?;
+    let mut path = Path::new(root).to_path_buf();
+    for component in name.split('/') {
+        path.push(component);
+    }
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// Resolves zone names in an ordered list of zoneinfo directories, and nowhere else.
+/// Directories are searched in order, the first one containing the zone wins.
+/// On top of the checks of resolve_zone_in, a tzfile reached through a symlink must still lie inside its directory.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Resolver {
+    roots: Vec<PathBuf>,
+}
+
+impl Resolver {
+    /// Creates a resolver allowed to read the given zoneinfo directories.
+    pub fn new<I, P>(roots: I) -> Resolver
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        Resolver {
+            roots: roots.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Creates a resolver searching the directory in the TZDIR environment variable if set, then the system zoneinfo directory.
+    pub fn system() -> Resolver {
+        let mut resolver = Resolver::new(vec![ZONEINFO_DIR]);
+        if let Some(dir) = env::var_os("TZDIR") {
+            resolver.push_front(dir);
+        }
+        resolver
+    }
+
+    /// Adds a directory searched after the others (ie. a bundled fallback).
+    pub fn push<P: Into<PathBuf>>(&mut self, dir: P) -> &mut Resolver {
+        self.roots.push(dir.into());
+        self
+    }
+
+    /// Adds a directory searched before the others (ie. a project-local override).
+    pub fn push_front<P: Into<PathBuf>>(&mut self, dir: P) -> &mut Resolver {
+        self.roots.insert(0, dir.into());
+        self
+    }
+
+    /// Allowed zoneinfo directories, in search order
+    pub fn roots(&self) -> &[PathBuf] {
+        &self.roots
+    }
+
+    /// Returns the tzfile path of a zone name, from the first allowed directory containing it.
+    /// Deprecated names missing from all directories (ie. "US/Eastern") fall back to their canonical zone.
+    pub fn resolve(&self, name: &str) -> Result<String, Error> {
+        check_name(name)?;
+        for root in &self.roots {
+            let root = match root.canonicalize() {
+                Ok(root) => root,
+                Err(_) => continue,
+            };
+            let path = match root.join(name).canonicalize() {
+                Ok(path) => path,
+                Err(_) => {
+                    trace!("{} not found in {}", name, root.display());
+                    continue;
+                }
+            };
+            if !path.starts_with(&root) || !path.is_file() {
+                debug!("{} refused: {} is outside {} or not a file", name, path.display(), root.display());
+                return Err(Error::new(ErrorKind::InvalidTimezone, "resolving zone name").with_zone(name));
+            }
+            debug!("{} resolved to {}", name, path.display());
+            return Ok(path.to_string_lossy().into_owned());
+        }
+        if let Some(canonical) = canonical_zone(name) {
+            debug!("{} is deprecated, resolving {}", name, canonical);
+            return self.resolve(canonical).map_err(|e| e.with_zone(name));
+        }
+        Err(Error::new(ErrorKind::InvalidTimezone, "resolving zone name").with_zone(name))
+    }
+}
+
+/// Checks that a zone name is a plain relative name
+pub(crate) fn check_name(name: &str) -> Result<(), Error> {
+    let invalid = || Error::new(ErrorKind::InvalidTimezone, "checking zone name").with_zone(name);
+    if name.is_empty() || Path::new(name).is_absolute() {
+        return Err(invalid());
+    }
+    for component in name.split('/') {
+        if component.is_empty()
+            || component == "."
+            || component == ".."
+            || component.contains(&['\\', ':', '\0'][..])
+        {
+            return Err(invalid());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn traversal() {
+        assert_eq!(
+            resolve_zone_in("/usr/share/zoneinfo", "Europe/Paris").unwrap(),
+            "/usr/share/zoneinfo/Europe/Paris"
+        );
+        for name in &["../../etc/passwd", "Europe/../../etc/passwd", "/etc/passwd", "Europe//Paris", "./UTC", "..\\secret", "C:secret", ""] {
+            assert!(resolve_zone(name).is_err(), "{} accepted", name);
+        }
+    }
+
+    #[test]
+    fn allowed_roots() {
+        let resolver = Resolver::new(vec!["/nonexistent", "/usr/share/zoneinfo"]);
+        assert!(resolver.resolve("Europe/Paris").unwrap().ends_with("Europe/Paris"));
+        assert!(resolver.resolve("Europe/Nowhere").is_err());
+        assert!(resolver.resolve("/usr/share/zoneinfo/Europe/Paris").is_err());
+        assert!(Resolver::new(vec!["/usr/share/zoneinfo/Europe"]).resolve("../America/Phoenix").is_err());
+    }
+
+    #[test]
+    fn search_order() {
+        let mut resolver = Resolver::new(vec!["/usr/share/zoneinfo"]);
+        resolver.push_front("/usr/share/zoneinfo/America").push("/usr/share/zoneinfo/Europe");
+        assert_eq!(resolver.roots()[0], PathBuf::from("/usr/share/zoneinfo/America"));
+        assert!(resolver.resolve("Phoenix").unwrap().ends_with("America/Phoenix"));
+        assert!(resolver.resolve("Paris").unwrap().ends_with("Europe/Paris"));
+        assert!(resolver.resolve("Asia/Tokyo").unwrap().ends_with("Asia/Tokyo"));
+    }
+}
@@ -0,0 +1,186 @@
+//! Markdown and HTML tables of transitions and DST calendars, for documentation and status pages or chat messages.
+
+use crate::{DstYear, Timechange, TransitionKind};
+use chrono::{prelude::*, Duration};
+use std::fmt::Write;
+
+/// Offset to GMT as "+01:00", or "+00:09:21"
+fn offset(gmtoff: isize) -> String {
+    match FixedOffset::east_opt(gmtoff as i32) {
+        Some(offset) => format!("{:?}", offset),
+        None => gmtoff.to_string(),
+    }
+}
+
+/// Escapes a Markdown table cell
+fn cell(s: &str) -> String {
+    s.replace('|', "\\|")
+}
+
+/// Escapes HTML text
+fn html(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// Renders timechanges as a GitHub-flavored Markdown table: UTC time, local time after the change, offset, DST flag
+/// and abbreviation.
+pub fn timechanges_markdown(timechanges: &[Timechange]) -> String {
+    let mut out = String::from("| UTC time | Local time | Offset | DST | Abbreviation |\n|---|---|---|---|---|\n");
+    for tc in timechanges {
+        let local = tc.time.naive_utc() + Duration::seconds(tc.gmtoff as i64);
+        let _ = writeln!(
+            out,
+            "| {} | {} | {} | {} | {} |",
+            tc.time.format("%Y-%m-%d %H:%M:%S"),
+            local.format("%Y-%m-%d %H:%M:%S"),
+            offset(tc.gmtoff),
+            if tc.isdst { "yes" } else { "no" },
+            cell(&tc.abbreviation)
+        );
+    }
+    out
+}
+
+/// Renders a DST calendar (see dst_calendar) as a GitHub-flavored Markdown table, in UTC.
+pub fn dst_calendar_markdown(calendar: &[DstYear]) -> String {
+    let mut out = String::from("| Year | DST start | DST end | Saving | Rules changed |\n|---|---|---|---|---|\n");
+    let time = |time: DateTime<Utc>| time.format("%Y-%m-%d %H:%M").to_string();
+    for year in calendar {
+        let (start, end) = match year.period {
+            Some((start, end)) => (time(start), time(end)),
+            None => (String::from("-"), String::from("-")),
+        };
+        let _ = writeln!(
+            out,
+            "| {} | {} | {} | {} | {} |",
+            year.year,
+            start,
+            end,
+            offset(year.saving),
+            if year.rules_changed { "yes" } else { "no" }
+        );
+    }
+    out
+}
+
+/// Renders timechanges as an HTML table with the columns of timechanges_markdown, for status pages.
+/// Rows have a "dst" or "standard" class, and the direction of the change from the previous row
+/// (see Timechange::classify): "spring-forward", "fall-back", "standard-offset-change" or "abbreviation-only".
+pub fn timechanges_html(timechanges: &[Timechange]) -> String {
+    let mut out = String::from(
+        "<table class=\"tzparse-timechanges\">\n<thead><tr><th>UTC time</th><th>Local time</th><th>Offset</th>\
+         <th>DST</th><th>Abbreviation</th></tr></thead>\n<tbody>\n",
+    );
+    let mut previous: Option<&Timechange> = None;
+    for tc in timechanges {
+        let mut class = String::from(if tc.isdst { "dst" } else { "standard" });
+        if let Some(previous) = previous {
+            class.push(' ');
+            class.push_str(match tc.classify(previous) {
+                TransitionKind::SpringForward => "spring-forward",
+                TransitionKind::FallBack => "fall-back",
+                TransitionKind::StandardOffsetChange => "standard-offset-change",
+                TransitionKind::AbbreviationOnly => "abbreviation-only",
+            });
+        }
+        let local = tc.time.naive_utc() + Duration::seconds(tc.gmtoff as i64);
+        let _ = writeln!(
+            out,
+            "<tr class=\"{}\"><td><time datetime=\"{}\">{}</time></td>\
+             <td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            class,
+            tc.time.format("%Y-%m-%dT%H:%M:%SZ"),
+            tc.time.format("%Y-%m-%d %H:%M:%S"),
+            local.format("%Y-%m-%d %H:%M:%S"),
+            offset(tc.gmtoff),
+            if tc.isdst { "yes" } else { "no" },
+            html(&tc.abbreviation)
+        );
+        previous = Some(tc);
+    }
+    out.push_str("</tbody>\n</table>\n");
+    out
+}
+
+/// Renders a DST calendar (see dst_calendar) as an HTML table with the columns of dst_calendar_markdown.
+/// Years whose rules changed have a "rules-changed" class, years without DST a "no-dst" one.
+pub fn dst_calendar_html(calendar: &[DstYear]) -> String {
+    let mut out = String::from(
+        "<table class=\"tzparse-dst-calendar\">\n<thead><tr><th>Year</th><th>DST start</th><th>DST end</th>\
+         <th>Saving</th><th>Rules changed</th></tr></thead>\n<tbody>\n",
+    );
+    let time = |time: DateTime<Utc>| {
+        format!("<time datetime=\"{}\">{}</time>", time.format("%Y-%m-%dT%H:%M:%SZ"), time.format("%Y-%m-%d %H:%M"))
+    };
+    for year in calendar {
+        let (start, end) = match year.period {
+            Some((start, end)) => (time(start), time(end)),
+            None => (String::from("-"), String::from("-")),
+        };
+        let class = match (year.period.is_some(), year.rules_changed) {
+            (true, true) => " class=\"rules-changed\"",
+            (true, false) => "",
+            (false, true) => " class=\"no-dst rules-changed\"",
+            (false, false) => " class=\"no-dst\"",
+        };
+        let _ = writeln!(
+            out,
+            "<tr{}><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            class,
+            year.year,
+            start,
+            end,
+            offset(year.saving),
+            if year.rules_changed { "yes" } else { "no" }
+        );
+    }
+    out.push_str("</tbody>\n</table>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dst_calendar, get_timechanges_for, TzDatabase};
+    #[test]
+    fn markdown() {
+        let paris = get_timechanges_for("/usr/share/zoneinfo/Europe/Paris", 2019.into()).unwrap();
+        assert_eq!(
+            timechanges_markdown(&paris),
+            "| UTC time | Local time | Offset | DST | Abbreviation |\n\
+             |---|---|---|---|---|\n\
+             | 2019-03-31 01:00:00 | 2019-03-31 03:00:00 | +02:00 | yes | CEST |\n\
+             | 2019-10-27 01:00:00 | 2019-10-27 02:00:00 | +01:00 | no | CET |\n"
+        );
+        let new_york = TzDatabase::open("/usr/share/zoneinfo").zone("America/New_York").unwrap();
+        let calendar = dst_calendar_markdown(&dst_calendar(&new_york, 2006..=2007));
+        let lines: Vec<_> = calendar.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[3], "| 2007 | 2007-03-11 07:00 | 2007-11-04 06:00 | +01:00 | yes |");
+        assert_eq!(cell("A|B"), "A\\|B");
+    }
+
+    #[test]
+    fn html_tables() {
+        let new_york = get_timechanges_for("/usr/share/zoneinfo/America/New_York", 2019.into()).unwrap();
+        let table = timechanges_html(&new_york);
+        let rows: Vec<_> = table.lines().filter(|line| line.starts_with("<tr class")).collect();
+        assert_eq!(
+            rows,
+            vec![
+                "<tr class=\"dst\"><td><time datetime=\"2019-03-10T07:00:00Z\">2019-03-10 07:00:00</time></td>\
+                 <td>2019-03-10 03:00:00</td><td>-04:00</td><td>yes</td><td>EDT</td></tr>",
+                "<tr class=\"standard fall-back\">\
+                 <td><time datetime=\"2019-11-03T06:00:00Z\">2019-11-03 06:00:00</time></td>\
+                 <td>2019-11-03 01:00:00</td><td>-05:00</td><td>no</td><td>EST</td></tr>",
+            ]
+        );
+        assert!(table.starts_with("<table class=\"tzparse-timechanges\">") && table.ends_with("</table>\n"));
+        let db = TzDatabase::open("/usr/share/zoneinfo");
+        let calendar = dst_calendar_html(&dst_calendar(&db.zone("America/New_York").unwrap(), 2006..=2007));
+        assert_eq!(calendar.matches("<tr class=\"rules-changed\">").count(), 1);
+        let phoenix = dst_calendar_html(&dst_calendar(&db.zone("America/Phoenix").unwrap(), 2019..=2019));
+        assert!(phoenix.contains("<tr class=\"no-dst\"><td>2019</td><td>-</td>"));
+        assert_eq!(html("<A&B>"), "&lt;A&amp;B&gt;");
+    }
+}
@@ -0,0 +1,552 @@
+use crate::{
+    day_bounds_utc, Clock, Error, ErrorKind, Provenance, SystemClock, Timechange, TransitionKind, Zone, ZoneOffset,
+};
+use chrono::{prelude::*, Duration};
+use std::{
+    collections::BTreeMap,
+    convert::TryFrom,
+    ops::{Range, RangeInclusive},
+};
+
+/// A timechange, either recorded in the zone's data or projected from its POSIX footer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectedTimechange {
+    /// The timechange
+    pub timechange: Timechange,
+    /// Computed from the footer rule, after the last recorded timechange
+    pub projected: bool,
+}
+
+impl ProjectedTimechange {
+    /// What the timechange is based on: the recorded transitions or the footer rule
+    pub fn provenance(&self) -> Provenance {
+        if self.projected {
+            Provenance::Rule
+        } else {
+            Provenance::Recorded
+        }
+    }
+}
+
+/// A timechange, with the time elapsed since the one before it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SpacedTimechange {
+    /// The timechange
+    pub timechange: Timechange,
+    /// Length of the period the timechange ends, None for the first one
+    pub duration_since_previous: Option<Duration>,
+}
+
+/// Pairs each timechange of a chronological list with the duration since the preceding one,
+/// ie. the length of DST periods from the timechanges ending them.
+pub fn with_durations(timechanges: &[Timechange]) -> Vec<SpacedTimechange> {
+    let mut previous: Option<DateTime<Utc>> = None;
+    timechanges
+        .iter()
+        .map(|tc| SpacedTimechange {
+            timechange: tc.clone(),
+            duration_since_previous: previous.replace(tc.time).map(|previous| tc.time - previous),
+        })
+        .collect()
+}
+
+/// A timechange of one of several zones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZoneTimechange {
+    /// Name of the zone
+    pub zone: String,
+    /// The timechange
+    pub timechange: Timechange,
+}
+
+/// Merges the timechanges of several zones within `range` into one chronological stream, tagged with the zone names.
+/// Timechanges at the same instant are ordered by zone name.
+pub fn merge_transitions(zones: &[Zone], range: Range<DateTime<Utc>>) -> Vec<ZoneTimechange> {
+    // changes_between excludes its start
+    let start = range.start.checked_sub_signed(Duration::nanoseconds(1)).unwrap_or(range.start);
+    let mut merged: Vec<ZoneTimechange> = zones
+        .iter()
+        .flat_map(|zone| {
+            zone.changes_between(start, range.end)
+                .into_iter()
+                .filter(|tc| range.contains(&tc.time))
+                .map(move |timechange| ZoneTimechange { zone: zone.name().to_string(), timechange })
+        })
+        .collect();
+    merged.sort_by(|a, b| (a.timechange.time, &a.zone).cmp(&(b.timechange.time, &b.zone)));
+    merged
+}
+
+/// Start and end of a DST period
+pub type DstPeriod = (DateTime<Utc>, DateTime<Utc>);
+
+/// Returns the DST periods of several zones over a range of years, by zone name then by year (see dst_bounds),
+/// as a table for planning across zones.
+pub fn dst_matrix(zones: &[Zone], years: RangeInclusive<i32>) -> BTreeMap<String, BTreeMap<i32, Option<DstPeriod>>> {
+    zones
+        .iter()
+        .map(|zone| {
+            let row = years.clone().map(|year| (year, dst_bounds(zone, year))).collect();
+            (zone.name().to_string(), row)
+        })
+        .collect()
+}
+
+/// A span of time during which a zone keeps the same local time type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OffsetPeriod {
+    /// Start, included
+    pub start: DateTime<Utc>,
+    /// End, excluded
+    pub end: DateTime<Utc>,
+    /// Local time type in force
+    pub offset: ZoneOffset,
+}
+
+/// Returns the contiguous periods of constant local time type covering `range`, in chronological order.
+/// No-op timechanges do not split a period.
+pub fn offset_periods(zone: &Zone, range: Range<DateTime<Utc>>) -> Result<Vec<OffsetPeriod>, Error> {
+    if range.start >= range.end {
+        return Ok(Vec::new());
+    }
+    let offset = zone
+        .offset_at(range.start)
+        .ok_or_else(|| Error::new(ErrorKind::NoData, "computing offset periods").with_zone(zone.name()))?;
+    let mut periods = vec![OffsetPeriod {
+        start: range.start,
+        end: range.end,
+        offset,
+    }];
+    for tc in zone.changes_between(range.start, range.end) {
+        let offset = ZoneOffset::from(&tc);
+        if tc.time >= range.end || matches!(periods.last(), Some(last) if last.offset == offset) {
+            continue;
+        }
+        if let Some(last) = periods.last_mut() {
+            last.end = tc.time;
+        }
+        periods.push(OffsetPeriod {
+            start: tc.time,
+            end: range.end,
+            offset,
+        });
+    }
+    Ok(periods)
+}
+
+/// Kind of observance, as iCalendar's STANDARD and DAYLIGHT components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObservanceKind {
+    Standard,
+    Daylight,
+}
+
+/// A segment of a zone's history observing one local time type, in the shape of an iCalendar observance.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Observance {
+    /// STANDARD or DAYLIGHT
+    pub kind: ObservanceKind,
+    /// Start, included
+    pub start: DateTime<Utc>,
+    /// End, excluded
+    pub end: DateTime<Utc>,
+    /// Offset to GMT before the start (TZOFFSETFROM), the observed one for the first segment
+    pub offset_from: isize,
+    /// Observed offset to GMT (TZOFFSETTO)
+    pub offset_to: isize,
+    /// Observed abbreviation (TZNAME)
+    pub name: String,
+}
+
+/// Returns the STANDARD and DAYLIGHT observances covering `range`, in chronological order (see offset_periods).
+/// Changes of standard time give consecutive observances of the same kind.
+pub fn observances(zone: &Zone, range: Range<DateTime<Utc>>) -> Result<Vec<Observance>, Error> {
+    let periods = offset_periods(zone, range)?;
+    let mut offset_from = periods.first().map(|period| period.offset.gmtoff);
+    Ok(periods
+        .into_iter()
+        .map(|period| Observance {
+            kind: if period.offset.isdst { ObservanceKind::Daylight } else { ObservanceKind::Standard },
+            start: period.start,
+            end: period.end,
+            offset_from: offset_from.replace(period.offset.gmtoff).unwrap_or(period.offset.gmtoff),
+            offset_to: period.offset.gmtoff,
+            name: period.offset.abbreviation,
+        })
+        .collect())
+}
+
+/// Local time type of a local calendar day.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DayOffset {
+    /// Local calendar day
+    pub date: NaiveDate,
+    /// Local time type in force at the start of the day
+    pub offset: ZoneOffset,
+    /// A timechange occurs during the day
+    pub transition: bool,
+}
+
+/// Returns, for each local calendar day of a year, the local time type in force at its start (local midnight,
+/// or the end of a gap skipping it), and whether a timechange occurs that day.
+pub fn daily_offsets(zone: &Zone, year: i32) -> Result<Vec<DayOffset>, Error> {
+    const OPERATION: &str = "computing daily offsets";
+    let mut date = NaiveDate::from_ymd_opt(year, 1, 1)
+        .ok_or_else(|| Error::new(ErrorKind::OutOfRange, OPERATION).with_zone(zone.name()))?;
+    let mut days = Vec::new();
+    while date.year() == year {
+        let day = day_bounds_utc(zone, date)?;
+        let offset = zone
+            .offset_at(day.start)
+            .ok_or_else(|| Error::new(ErrorKind::NoData, OPERATION).with_zone(zone.name()))?;
+        // Timechanges happen on whole seconds: a second back, the one starting the day is included
+        let transition = zone
+            .changes_between(day.start - Duration::seconds(1), day.end)
+            .iter()
+            .any(|tc| tc.time < day.end);
+        days.push(DayOffset { date, offset, transition });
+        date = match date.succ_opt() {
+            Some(next) => next,
+            None => break,
+        };
+    }
+    Ok(days)
+}
+
+/// Returns the timechanges expected from now to the end of the year `years` years ahead, see project_transitions_at.
+pub fn project_transitions(zone: &Zone, years: u32) -> Vec<ProjectedTimechange> {
+    project_transitions_at(zone, years, SystemClock.now())
+}
+
+/// Returns the timechanges expected after `now` until the end of the year `years` years ahead, in chronological order.
+/// Past the last recorded timechange, they are projected from the POSIX footer rule (zic records them until 2037 only).
+pub fn project_transitions_at(zone: &Zone, years: u32, now: DateTime<Utc>) -> Vec<ProjectedTimechange> {
+    let end = i32::try_from(years)
+        .ok()
+        .and_then(|years| now.year().checked_add(years)?.checked_add(1))
+        .and_then(|year| NaiveDate::from_ymd_opt(year, 1, 1))
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|end| Utc.from_utc_datetime(&end));
+    let end = match end {
+        Some(end) => end,
+        None => return Vec::new(),
+    };
+    let last = zone.timechanges().last().map(|tc| tc.time);
+    zone.changes_between(now, end)
+        .into_iter()
+        .filter(|tc| tc.time < end)
+        .map(|timechange| ProjectedTimechange {
+            projected: !matches!(last, Some(last) if timechange.time <= last),
+            timechange,
+        })
+        .collect()
+}
+
+/// Returns the DST period starting in a (UTC) year: from its first switch to DST until the following switch
+/// back to standard time, which falls in the next year in the southern hemisphere.
+/// Switches are told by the clocks moving forward then back (see Timechange::classify), so that the period
+/// of a zone with negative DST (ie. Europe/Dublin, whose GMT winter time is flagged DST) is its summer too.
+/// None if the zone does not switch to DST that year, or never leaves it.
+pub fn dst_bounds(zone: &Zone, year: i32) -> Option<DstPeriod> {
+    let january = |year: i32| {
+        NaiveDate::from_ymd_opt(year, 1, 1)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|start| Utc.from_utc_datetime(&start))
+    };
+    let (start, next) = (january(year)?, january(year.checked_add(1)?)?);
+    // DST started in a year ends within the next one
+    let end = january(year.checked_add(2)?)?;
+    let mut previous = zone
+        .offset_at(start)
+        .map(|offset| Timechange::new(start, offset.gmtoff, offset.isdst, &offset.abbreviation));
+    let mut dst_start = None;
+    for tc in zone.changes_between(start, end) {
+        let kind = TransitionKind::of(previous.as_ref(), &tc);
+        match dst_start {
+            None if tc.time >= next => return None,
+            None if kind == TransitionKind::SpringForward => dst_start = Some(tc.time),
+            Some(dst_start) if kind == TransitionKind::FallBack => return Some((dst_start, tc.time)),
+            _ => {}
+        }
+        previous = Some(tc);
+    }
+    None
+}
+
+/// A year of a DST calendar.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DstYear {
+    /// The (UTC) year
+    pub year: i32,
+    /// DST period starting in the year, see dst_bounds
+    pub period: Option<DstPeriod>,
+    /// DST offset minus standard offset, in seconds: 0 without DST
+    pub saving: isize,
+    /// Do the rules differ from the previous year: DST observed or not, start or end local dates and times
+    /// (ie. "second Sunday of March at 02:00"), saving or standard offset ?
+    pub rules_changed: bool,
+}
+
+/// Local date and time of a DST start or end: month, day of the month, days in the month, weekday and time.
+type LocalChange = (u32, u32, u32, u32, NaiveTime);
+
+/// Rules of a DST period: the POSIX footer's own, or the local dates and times of its start and end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DstRules {
+    Footer,
+    Local(LocalChange, LocalChange),
+}
+
+/// Shape of a DST period as POSIX rules put it, with the standard and DST offsets.
+type DstShape = (DstRules, isize, isize);
+
+fn dst_shape(zone: &Zone, (start, end): DstPeriod) -> Option<DstShape> {
+    let standard = zone.offset_at(start - Duration::seconds(1))?.gmtoff;
+    let dst = zone.offset_at(start)?.gmtoff;
+    // Periods following the footer rule take its dates, ie. "Friday before the last Sunday" rather than
+    // the fourth or last Friday, depending on the year
+    if let Some(rule) = zone.rule() {
+        let changes: Vec<Timechange> = (start.year() - 1..=end.year() + 1).flat_map(|y| rule.timechanges(y)).collect();
+        let found = |time, gmtoff| changes.iter().any(|tc| (tc.time, tc.gmtoff) == (time, gmtoff));
+        if found(start, dst) && found(end, standard) {
+            return Some((DstRules::Footer, standard, dst));
+        }
+    }
+    let local = |time: DateTime<Utc>, gmtoff: isize| {
+        let local = (time + Duration::seconds(gmtoff as i64)).naive_utc();
+        let next_month = match local.month() {
+            12 => NaiveDate::from_ymd_opt(local.year() + 1, 1, 1),
+            month => NaiveDate::from_ymd_opt(local.year(), month + 1, 1),
+        };
+        let days = next_month.and_then(|next| next.pred_opt()).map_or(31, |last| last.day());
+        (local.month(), local.day(), days, local.weekday().num_days_from_sunday(), local.time())
+    };
+    Some((DstRules::Local(local(start, standard), local(end, dst)), standard, dst))
+}
+
+/// Do two DST periods follow the same rules ? A weekday on the 22nd to the 28th is both the fourth
+/// and possibly the last of its month: the same rule is either one every year.
+fn same_rules(a: Option<DstShape>, b: Option<DstShape>) -> bool {
+    let same_day = |(month, day, days, weekday, time): LocalChange, other: LocalChange| {
+        let week = |day: u32| (day - 1) / 7;
+        let last = |day: u32, days: u32| day + 7 > days;
+        (month, weekday, time) == (other.0, other.3, other.4)
+            && (week(day) == week(other.1) || (last(day, days) && last(other.1, other.2)))
+    };
+    match (a, b) {
+        (None, None) => true,
+        (Some((a, a_standard, a_dst)), Some((b, b_standard, b_dst))) => {
+            (a_standard, a_dst) == (b_standard, b_dst)
+                && match (a, b) {
+                    (DstRules::Footer, DstRules::Footer) => true,
+                    (DstRules::Local(a_start, a_end), DstRules::Local(b_start, b_end)) => {
+                        same_day(a_start, b_start) && same_day(a_end, b_end)
+                    }
+                    _ => false,
+                }
+        }
+        _ => false,
+    }
+}
+
+/// Returns the DST calendar of a zone over a range of years, as a wall calendar shows it:
+/// per year, the DST period, its saving and whether the rules changed from the previous year.
+pub fn dst_calendar(zone: &Zone, years: RangeInclusive<i32>) -> Vec<DstYear> {
+    let shape = |year: i32| dst_bounds(zone, year).and_then(|period| dst_shape(zone, period));
+    let mut previous = years.start().checked_sub(1).and_then(shape);
+    years
+        .map(|year| {
+            let period = dst_bounds(zone, year);
+            let current = period.and_then(|period| dst_shape(zone, period));
+            let rules_changed = !same_rules(current, previous);
+            previous = current;
+            DstYear {
+                year,
+                period,
+                saving: current.map_or(0, |(_, standard, dst)| dst - standard),
+                rules_changed,
+            }
+        })
+        .collect()
+}
+
+/// Same as dst_bounds, with what the period is based on: the footer rule if it ends after the recorded transitions.
+pub fn dst_bounds_with_provenance(zone: &Zone, year: i32) -> Option<(DstPeriod, Provenance)> {
+    let bounds = dst_bounds(zone, year)?;
+    Some((bounds, zone.provenance(bounds.1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TzDatabase;
+    #[test]
+    fn projection() {
+        let paris = TzDatabase::open("/usr/share/zoneinfo").zone("Europe/Paris").unwrap();
+        let projected = project_transitions_at(&paris, 10, Utc.ymd(2030, 6, 1).and_hms(0, 0, 0));
+        // October 2030, then two a year until 2040
+        assert_eq!(projected.len(), 21);
+        assert!(!projected[0].projected);
+        let first = projected.iter().find(|p| p.projected).unwrap();
+        assert_eq!(first.timechange.time, Utc.ymd(2038, 3, 28).and_hms(1, 0, 0));
+        assert_eq!(first.timechange.abbreviation, "CEST");
+        assert_eq!(projected.last().unwrap().timechange.time, Utc.ymd(2040, 10, 28).and_hms(1, 0, 0));
+    }
+
+    #[test]
+    fn dst() {
+        let db = TzDatabase::open("/usr/share/zoneinfo");
+        let bounds = |zone| dst_bounds(&db.zone(zone).unwrap(), 2019);
+        assert_eq!(
+            bounds("Europe/Paris"),
+            Some((Utc.ymd(2019, 3, 31).and_hms(1, 0, 0), Utc.ymd(2019, 10, 27).and_hms(1, 0, 0)))
+        );
+        assert_eq!(
+            bounds("Australia/Sydney"),
+            Some((Utc.ymd(2019, 10, 5).and_hms(16, 0, 0), Utc.ymd(2020, 4, 4).and_hms(16, 0, 0)))
+        );
+        assert_eq!(bounds("America/Phoenix"), None);
+        // Negative DST: the period is the summer one, when clocks are ahead
+        assert_eq!(
+            bounds("Europe/Dublin"),
+            Some((Utc.ymd(2019, 3, 31).and_hms(1, 0, 0), Utc.ymd(2019, 10, 27).and_hms(1, 0, 0)))
+        );
+        assert_eq!(dst_calendar(&db.zone("Europe/Dublin").unwrap(), 2019..=2019)[0].saving, 3600);
+        // Projected from the footer rule
+        assert!(dst_bounds(&db.zone("Europe/Paris").unwrap(), 2050).is_some());
+    }
+
+    #[test]
+    fn durations() {
+        let paris = crate::get_timechanges_for("/usr/share/zoneinfo/Europe/Paris", 2019.into()).unwrap();
+        let spaced = with_durations(&paris);
+        assert_eq!(spaced.len(), 2);
+        assert_eq!(spaced[0].duration_since_previous, None);
+        // From the last Sunday of March to the last Sunday of October
+        assert_eq!(spaced[1].duration_since_previous, Some(Duration::days(210)));
+        assert_eq!(spaced[1].timechange, paris[1]);
+        assert!(with_durations(&[]).is_empty());
+    }
+
+    #[test]
+    fn observance() {
+        let db = TzDatabase::open("/usr/share/zoneinfo");
+        let year = Utc.ymd(2019, 1, 1).and_hms(0, 0, 0)..Utc.ymd(2020, 1, 1).and_hms(0, 0, 0);
+        let paris = observances(&db.zone("Europe/Paris").unwrap(), year.clone()).unwrap();
+        let kinds: Vec<_> = paris.iter().map(|o| o.kind).collect();
+        assert_eq!(kinds, vec![ObservanceKind::Standard, ObservanceKind::Daylight, ObservanceKind::Standard]);
+        assert_eq!(
+            paris[1],
+            Observance {
+                kind: ObservanceKind::Daylight,
+                start: Utc.ymd(2019, 3, 31).and_hms(1, 0, 0),
+                end: Utc.ymd(2019, 10, 27).and_hms(1, 0, 0),
+                offset_from: 3600,
+                offset_to: 7200,
+                name: "CEST".to_string(),
+            }
+        );
+        assert_eq!((paris[0].offset_from, paris[2].offset_from), (3600, 7200));
+        assert_eq!(paris[2].end, year.end);
+        // Standard time change
+        let pyongyang = db.zone("Asia/Pyongyang").unwrap();
+        let year = Utc.ymd(2015, 1, 1).and_hms(0, 0, 0)..Utc.ymd(2016, 1, 1).and_hms(0, 0, 0);
+        let pyongyang = observances(&pyongyang, year).unwrap();
+        assert_eq!(pyongyang.len(), 2);
+        assert!(pyongyang.iter().all(|o| o.kind == ObservanceKind::Standard));
+        assert_eq!((pyongyang[1].offset_from, pyongyang[1].offset_to), (32400, 30600));
+    }
+
+    #[test]
+    fn calendar() {
+        let db = TzDatabase::open("/usr/share/zoneinfo");
+        let changes = |zone, years| {
+            dst_calendar(&db.zone(zone).unwrap(), years).iter().map(|y| y.rules_changed).collect::<Vec<_>>()
+        };
+        // Second Sunday of March rather than first Sunday of April since 2007
+        let new_york = dst_calendar(&db.zone("America/New_York").unwrap(), 2005..=2008);
+        assert_eq!(new_york.iter().map(|y| y.rules_changed).collect::<Vec<_>>(), vec![false, false, true, false]);
+        assert_eq!(new_york[2].period.unwrap().0, Utc.ymd(2007, 3, 11).and_hms(7, 0, 0));
+        assert_eq!(new_york[2].saving, 3600);
+        // Last Sundays fall on the 25th to the 31st
+        assert_eq!(changes("Europe/Paris", 2015..=2025), vec![false; 11]);
+        // Friday before the last Sunday of March: the fourth or the last Friday, depending on the year
+        assert_eq!(changes("Asia/Jerusalem", 2014..=2030), vec![false; 17]);
+        // Periods starting in 2007 end on the first Sunday of April, and start on the first Sunday of October from 2008
+        assert_eq!(changes("Australia/Sydney", 2007..=2009), vec![true, true, false]);
+        let phoenix = dst_calendar(&db.zone("America/Phoenix").unwrap(), 2019..=2020);
+        assert!(phoenix.iter().all(|y| y.period.is_none() && y.saving == 0 && !y.rules_changed));
+        // Half an hour
+        assert_eq!(dst_calendar(&db.zone("Australia/Lord_Howe").unwrap(), 2019..=2019)[0].saving, 1800);
+    }
+
+    #[test]
+    fn merge() {
+        let db = TzDatabase::open("/usr/share/zoneinfo");
+        let zones: Vec<Zone> = ["Europe/Paris", "America/New_York", "Europe/London"]
+            .iter()
+            .map(|name| db.zone(name).unwrap())
+            .collect();
+        let march = Utc.ymd(2019, 3, 1).and_hms(0, 0, 0)..Utc.ymd(2019, 4, 1).and_hms(0, 0, 0);
+        let merged = merge_transitions(&zones, march);
+        let tagged: Vec<_> = merged.iter().map(|m| (m.zone.as_str(), m.timechange.time)).collect();
+        assert_eq!(
+            tagged,
+            vec![
+                ("America/New_York", Utc.ymd(2019, 3, 10).and_hms(7, 0, 0)),
+                ("Europe/London", Utc.ymd(2019, 3, 31).and_hms(1, 0, 0)),
+                ("Europe/Paris", Utc.ymd(2019, 3, 31).and_hms(1, 0, 0)),
+            ]
+        );
+        // Start included, end excluded
+        let exact = Utc.ymd(2019, 3, 10).and_hms(7, 0, 0)..Utc.ymd(2019, 3, 31).and_hms(1, 0, 0);
+        assert_eq!(merge_transitions(&zones, exact).len(), 1);
+    }
+
+    #[test]
+    fn periods() {
+        let paris = TzDatabase::open("/usr/share/zoneinfo").zone("Europe/Paris").unwrap();
+        let year = Utc.ymd(2019, 1, 1).and_hms(0, 0, 0)..Utc.ymd(2020, 1, 1).and_hms(0, 0, 0);
+        let periods = offset_periods(&paris, year.clone()).unwrap();
+        let abbreviations: Vec<_> = periods.iter().map(|p| p.offset.abbreviation.as_str()).collect();
+        assert_eq!(abbreviations, vec!["CET", "CEST", "CET"]);
+        assert_eq!(periods[0].start, year.start);
+        assert_eq!(periods[1].start, Utc.ymd(2019, 3, 31).and_hms(1, 0, 0));
+        assert_eq!(periods[1].end, periods[2].start);
+        assert!(periods[1].offset.isdst);
+        assert_eq!(periods[2].end, year.end);
+        // The no-op timechange of 2038 does not split Dubai's period
+        let dubai = TzDatabase::open("/usr/share/zoneinfo").zone("Asia/Dubai").unwrap();
+        let around = Utc.ymd(2037, 1, 1).and_hms(0, 0, 0)..Utc.ymd(2039, 1, 1).and_hms(0, 0, 0);
+        assert_eq!(offset_periods(&dubai, around).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn daily() {
+        let paris = TzDatabase::open("/usr/share/zoneinfo").zone("Europe/Paris").unwrap();
+        let days = daily_offsets(&paris, 2019).unwrap();
+        assert_eq!(days.len(), 365);
+        let transitions: Vec<_> = days.iter().filter(|d| d.transition).map(|d| d.date).collect();
+        assert_eq!(transitions, vec![NaiveDate::from_ymd(2019, 3, 31), NaiveDate::from_ymd(2019, 10, 27)]);
+        assert_eq!(days[89].offset.gmtoff, 3600);
+        assert_eq!(days[90].offset.gmtoff, 7200);
+        // The day starts with the timechange in Sao Paulo
+        let sao_paulo = TzDatabase::open("/usr/share/zoneinfo").zone("America/Sao_Paulo").unwrap();
+        let day = daily_offsets(&sao_paulo, 2018).unwrap().into_iter().find(|d| d.date.ordinal() == 308).unwrap();
+        assert_eq!(day.date, NaiveDate::from_ymd(2018, 11, 4));
+        assert!(day.transition);
+        assert!(day.offset.isdst);
+    }
+
+    #[test]
+    fn matrix() {
+        let db = TzDatabase::open("/usr/share/zoneinfo");
+        let zones = vec![db.zone("Europe/London").unwrap(), db.zone("America/Phoenix").unwrap()];
+        let matrix = dst_matrix(&zones, 2019..=2021);
+        assert_eq!(matrix.len(), 2);
+        let london = &matrix["Europe/London"];
+        assert_eq!(london.keys().copied().collect::<Vec<_>>(), vec![2019, 2020, 2021]);
+        assert_eq!(london[&2020].unwrap().0, Utc.ymd(2020, 3, 29).and_hms(1, 0, 0));
+        assert!(matrix["America/Phoenix"].values().all(|bounds| bounds.is_none()));
+    }
+}
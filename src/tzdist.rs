@@ -0,0 +1,182 @@
+//! Client of the Time Zone Data Distribution Service protocol (RFC 7808), with the "tzdist" feature.
+
+use crate::{resolve::check_name, Error, ErrorKind, TzDatabase};
+use std::{
+    fs,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+/// Largest response accepted from a server: tzfiles are a few KB, zone lists a few hundred
+const MAX_RESPONSE: u64 = 16 * 1024 * 1024;
+
+/// Zones listed by a tzdist server.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZoneList {
+    /// Token to pass as `changedsince` on the next request, to list updated zones only
+    pub synctoken: Option<String>,
+    /// Zone identifiers
+    pub zones: Vec<String>,
+}
+
+/// Fetches zone data from a tzdist server, as tzfiles (application/tzif, RFC 8536).
+#[derive(Debug, Clone)]
+pub struct TzdistClient {
+    base: String,
+    agent: ureq::Agent,
+}
+
+impl TzdistClient {
+    /// Creates a client of the service at `base` (ie. "https://tz.example.com/tzdist"), found with
+    /// the /.well-known/timezone redirection of RFC 7808.
+    pub fn new(base: &str) -> TzdistClient {
+        TzdistClient {
+            base: base.trim_end_matches('/').to_string(),
+            agent: ureq::Agent::new(),
+        }
+    }
+
+    fn get(&self, path: &str, accept: &str, operation: &'static str) -> Result<Vec<u8>, Error> {
+        let url = format!("{}{}", self.base, path);
+        debug!("tzdist GET {}", url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Accept", accept)
+            .call()
+            .map_err(|e| Error::other(ErrorKind::Network, e, operation).with_zone(&url))?;
+        let mut body = Vec::new();
+        response
+            .into_reader()
+            .take(MAX_RESPONSE)
+            .read_to_end(&mut body)
+            .map_err(|e| Error::other(ErrorKind::Network, e, operation).with_zone(&url))?;
+        Ok(body)
+    }
+
+    /// Lists the zones of the server; with a synctoken of a previous list, the zones changed since then only.
+    pub fn zones(&self, changedsince: Option<&str>) -> Result<ZoneList, Error> {
+        const OPERATION: &str = "listing tzdist zones";
+        let path = match changedsince {
+            Some(token) => format!("/zones?changedsince={}", encode(token)),
+            None => String::from("/zones"),
+        };
+        let body = self.get(&path, "application/json", OPERATION)?;
+        let invalid = || Error::new(ErrorKind::NoData, OPERATION).with_zone(&self.base);
+        let json: serde_json::Value = serde_json::from_slice(&body).map_err(|_| invalid())?;
+        let zones = json["zones"]
+            .as_array()
+            .ok_or_else(invalid)?
+            .iter()
+            .filter_map(|zone| zone["tzid"].as_str().map(String::from))
+            .collect();
+        Ok(ZoneList {
+            synctoken: json["synctoken"].as_str().map(String::from),
+            zones,
+        })
+    }
+
+    /// Fetches the tzfile of a zone.
+    pub fn tzif(&self, tzid: &str) -> Result<Vec<u8>, Error> {
+        const OPERATION: &str = "fetching tzdist zone";
+        check_name(tzid)?;
+        let body = self.get(&format!("/zones/{}", encode_path(tzid)), "application/tzif", OPERATION)?;
+        if !body.starts_with(b"TZif") {
+            return Err(Error::new(ErrorKind::InvalidTzfile, OPERATION).with_zone(tzid));
+        }
+        Ok(body)
+    }
+
+    /// Fetches the zones of the server (or the ones changed since a previous synctoken) as tzfiles
+    /// into the `dir` zoneinfo tree, and opens it. Returns the database and the synctoken for the next update.
+    pub fn sync<P: AsRef<Path>>(
+        &self,
+        dir: P,
+        changedsince: Option<&str>,
+    ) -> Result<(TzDatabase, Option<String>), Error> {
+        const OPERATION: &str = "writing tzdist zone";
+        let dir = dir.as_ref();
+        let list = self.zones(changedsince)?;
+        for tzid in &list.zones {
+            let data = self.tzif(tzid)?;
+            let path: PathBuf = dir.join(tzid);
+            let write = || -> std::io::Result<()> {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, &data)
+            };
+            write().map_err(|e| Error::io(e, OPERATION).with_zone(tzid))?;
+        }
+        Ok((TzDatabase::open(dir), list.synctoken))
+    }
+}
+
+/// Percent-encodes a query parameter value
+fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Percent-encodes a zone identifier, keeping its slashes
+fn encode_path(tzid: &str) -> String {
+    tzid.split('/').map(encode).collect::<Vec<_>>().join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    /// Serves canned responses to `count` requests, as a tzdist server would
+    fn serve(count: usize) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            for stream in listener.incoming().take(count) {
+                let mut stream = stream.unwrap();
+                let mut request = String::new();
+                BufReader::new(&stream).read_line(&mut request).unwrap();
+                let (kind, body) = if request.starts_with("GET /zones?changedsince=t%201 ") {
+                    ("application/json", br#"{"synctoken":"t 2","zones":[]}"#.to_vec())
+                } else if request.starts_with("GET /zones ") {
+                    ("application/json", br#"{"synctoken":"t 1","zones":[{"tzid":"Europe/Paris"}]}"#.to_vec())
+                } else {
+                    ("application/tzif", fs::read("/usr/share/zoneinfo/Europe/Paris").unwrap())
+                };
+                let header = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    kind,
+                    body.len()
+                );
+                stream.write_all(header.as_bytes()).unwrap();
+                stream.write_all(&body).unwrap();
+            }
+        });
+        base
+    }
+
+    #[test]
+    fn tzdist() {
+        let client = TzdistClient::new(&serve(3));
+        let dir = std::env::temp_dir().join(format!("tzparse-tzdist-{}", std::process::id()));
+        let (db, token) = client.sync(&dir, None).unwrap();
+        assert_eq!(token.as_deref(), Some("t 1"));
+        assert_eq!(db.zones().unwrap(), vec!["Europe/Paris"]);
+        assert_eq!(db.zone("Europe/Paris").unwrap().timechanges().len(), 184);
+        assert!(client.zones(Some("t 1")).unwrap().zones.is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(client.tzif("../etc/passwd").is_err());
+        let err = TzdistClient::new("http://127.0.0.1:1").zones(None).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Network);
+    }
+}
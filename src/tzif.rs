@@ -0,0 +1,267 @@
+//! Low-level access to TZif data: headers and footer, which libtzfile does not expose, and the decoding of
+//! tzfiles read once, so that the bytes checked against the limits are the bytes parsed.
+
+use crate::{Error, ErrorKind, Limits, ZoneOffset};
+use libtzfile::{Ttinfo, Tz, TzError};
+use std::{
+    convert::TryFrom,
+    fs::File,
+    io::{Read, Seek, SeekFrom},
+    str,
+};
+
+/// Length of a TZif header, in bytes
+pub(crate) const HEADER_LEN: u64 = 44;
+
+/// Counts and version stored in a TZif header.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Header {
+    pub version: u8,
+    pub isutcnt: u64,
+    pub isstdcnt: u64,
+    pub leapcnt: u64,
+    pub timecnt: u64,
+    pub typecnt: u64,
+    pub charcnt: u64,
+}
+
+impl Header {
+    /// Decodes a header found at `offset`, checking the TZif magic.
+    pub fn decode(buf: &[u8; HEADER_LEN as usize], offset: u64) -> Result<Header, Error> {
+        if &buf[0..4] != b"TZif" {
+            return Err(Error::new(ErrorKind::InvalidTzfile, "reading tzfile header").with_offset(offset));
+        }
+        let count = |i: usize| {
+            let mut b = [0; 4];
+            b.copy_from_slice(&buf[i..i + 4]);
+            u32::from_be_bytes(b) as u64
+        };
+        Ok(Header {
+            version: buf[4],
+            isutcnt: count(20),
+            isstdcnt: count(24),
+            leapcnt: count(28),
+            timecnt: count(32),
+            typecnt: count(36),
+            charcnt: count(40),
+        })
+    }
+
+    /// Length of the data block following this header, with `time_size` bytes timestamps (4 for v1 data, 8 for v2+).
+    pub fn data_len(&self, time_size: u64) -> u64 {
+        self.timecnt * (time_size + 1)
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+/// Reads the headers of a tzfile without loading its data.
+/// Returns the v1 header, and the v2+ header when the file has one.
+pub(crate) fn read_headers(file: &mut File) -> Result<(Header, Option<Header>), Error> {
+    let mut buf = [0; HEADER_LEN as usize];
+    let read = |file: &mut File, buf: &mut [u8; HEADER_LEN as usize], offset: u64| {
+        file.seek(SeekFrom::Start(offset))
+            .and_then(|_| file.read_exact(buf))
+            .map_err(|e| Error::io(e, "reading tzfile header").with_offset(offset))
+    };
+    read(file, &mut buf, 0)?;
+    let v1 = Header::decode(&buf, 0)?;
+    if v1.version < b'2' {
+        return Ok((v1, None));
+    }
+    let offset = HEADER_LEN + v1.data_len(4);
+    read(file, &mut buf, offset)?;
+    Ok((v1, Some(Header::decode(&buf, offset)?)))
+}
+
+/// Returns the TZif version of a tzfile: 1, 2, 3 or 4. Version 3 footers may set transition times
+/// beyond 24 hours or negative (ie. "M3.4.4/26"), which PosixTz handles.
+pub fn get_tzif_version(requested_timezone: &str) -> Result<u8, Error> {
+    let mut file = File::open(requested_timezone)
+        .map_err(|e| Error::io(e, "reading tzfile header").with_zone(requested_timezone))?;
+    let (v1, _) = read_headers(&mut file).map_err(|e| e.with_zone(requested_timezone))?;
+    Ok(match v1.version {
+        0 => 1,
+        version => version.wrapping_sub(b'0'),
+    })
+}
+
+/// Decodes the headers at the start of a tzfile's bytes.
+/// Returns the v1 header, and the v2+ header when the file has one.
+pub(crate) fn headers(buf: &[u8]) -> Result<(Header, Option<Header>), Error> {
+    let header = |offset: u64| -> Result<Header, Error> {
+        let mut bytes = [0; HEADER_LEN as usize];
+        let len = HEADER_LEN as usize;
+        let start = usize::try_from(offset).ok().filter(|start| start.saturating_add(len) <= buf.len());
+        let invalid = || Error::new(ErrorKind::InvalidTzfile, "reading tzfile header").with_offset(offset);
+        let start = start.ok_or_else(invalid)?;
+        bytes.copy_from_slice(&buf[start..start + len]);
+        Header::decode(&bytes, offset)
+    };
+    let v1 = header(0)?;
+    if v1.version < b'2' {
+        return Ok((v1, None));
+    }
+    Ok((v1, Some(header(HEADER_LEN + v1.data_len(4))?)))
+}
+
+/// A tzfile read in one go: its data, footer and leap seconds are decoded from the same bytes.
+#[derive(Debug)]
+pub(crate) struct Tzif {
+    /// Data of the v2+ block, as libtzfile structures it
+    pub tz: Tz,
+    /// POSIX TZ string following the data, if not empty
+    pub footer: Option<String>,
+    /// Leap second records, as (occurrence, correction) pairs: files of the right/ tree
+    /// count leap seconds in their timestamps. Empty for usual tzfiles.
+    pub leap_seconds: Vec<(i64, i32)>,
+}
+
+impl Tzif {
+    /// Reads a tzfile within the limits, then decodes it.
+    pub fn load(path: &str, limits: &Limits) -> Result<Tzif, Error> {
+        let buf = limits.read(path)?;
+        Tzif::decode(&buf).map_err(|e| e.with_zone(path))
+    }
+
+    /// Decodes the v2+ data block of a tzfile and its footer. Version 1 tzfiles are not supported, as by libtzfile.
+    pub fn decode(buf: &[u8]) -> Result<Tzif, Error> {
+        const OPERATION: &str = "parsing tzfile";
+        if buf.len() < HEADER_LEN as usize || &buf[0..4] != b"TZif" {
+            return Err(Error::tzfile(TzError::InvalidMagic, OPERATION));
+        }
+        let (v1, v2) = headers(buf)?;
+        let v2 = v2.ok_or_else(|| Error::tzfile(TzError::UnsupportedFormat, OPERATION))?;
+        let start = HEADER_LEN + v1.data_len(4) + HEADER_LEN;
+        let end = start + v2.data_len(8);
+        let parse_error = |offset: u64| Error::tzfile(TzError::ParseError, OPERATION).with_offset(offset);
+        if end > buf.len() as u64 {
+            return Err(parse_error(buf.len() as u64));
+        }
+        // Within the buffer: the counts fit in usize
+        let mut offset = start as usize;
+        let mut take = |len: u64| {
+            let block = &buf[offset..offset + len as usize];
+            offset += len as usize;
+            block
+        };
+        let be = |bytes: &[u8]| bytes.iter().fold(0i64, |n, b| (n << 8) | *b as i64);
+        let times: Vec<i64> = take(v2.timecnt * 8).chunks(8).map(be).collect();
+        let indices = take(v2.timecnt).to_vec();
+        let types: Vec<(isize, u8, usize)> = take(v2.typecnt * 6)
+            .chunks(6)
+            .map(|t| (be(&t[0..4]) as i32 as isize, t[4], t[5] as usize))
+            .collect();
+        let chars = take(v2.charcnt);
+        let leap_seconds = take(v2.leapcnt * 12).chunks(12).map(|l| (be(&l[0..8]), be(&l[8..12]) as i32)).collect();
+
+        // Abbreviations, NUL terminated, which the local time types point into
+        let mut tz_abbr: Vec<String> = Vec::new();
+        let mut starts: Vec<usize> = Vec::new();
+        // Position of the abbreviation starting at `start` in tz_abbr, and its length
+        let mut abbreviation = |start: usize| -> Result<(usize, usize), Error> {
+            if let Some(position) = starts.iter().position(|s| *s == start) {
+                return Ok((position, tz_abbr[position].len()));
+            }
+            let len = chars.get(start..).and_then(|rest| rest.iter().position(|c| *c == 0));
+            let len = len.ok_or_else(|| parse_error(start as u64))?;
+            let abbreviation = str::from_utf8(&chars[start..start + len])
+                .map_err(|_| Error::tzfile(TzError::BadUtf8String, OPERATION))?;
+            starts.push(start);
+            tz_abbr.push(abbreviation.to_string());
+            Ok((starts.len() - 1, len))
+        };
+        let mut start = 0;
+        while start < chars.len() {
+            start += abbreviation(start)?.1 + 1;
+        }
+        let mut tzh_typecnt = Vec::new();
+        for (gmtoff, isdst, index) in types {
+            // An index into the middle of an abbreviation shares its end
+            let tt_abbrind = u8::try_from(abbreviation(index)?.0).map_err(|_| parse_error(index as u64))?;
+            tzh_typecnt.push(Ttinfo { tt_gmtoff: gmtoff, tt_isdst: isdst, tt_abbrind });
+        }
+
+        // "\n" TZ string "\n"
+        let footer = &buf[end as usize..];
+        let invalid = || Error::new(ErrorKind::InvalidTzfile, "reading tzfile footer").with_offset(end);
+        if footer.len() < 2 || footer[0] != b'\n' || footer[footer.len() - 1] != b'\n' {
+            return Err(invalid());
+        }
+        let footer = str::from_utf8(&footer[1..footer.len() - 1]).map_err(|_| invalid())?;
+        Ok(Tzif {
+            tz: Tz { tzh_timecnt_data: times, tzh_timecnt_indices: indices, tzh_typecnt, tz_abbr },
+            footer: if footer.is_empty() { None } else { Some(footer.to_string()) },
+            leap_seconds,
+        })
+    }
+
+    /// Local time type in force before the first transition (RFC 8536: the first one), if any
+    pub fn initial(&self) -> Option<ZoneOffset> {
+        let ttinfo = self.tz.tzh_typecnt.first()?;
+        Some(ZoneOffset {
+            gmtoff: ttinfo.tt_gmtoff,
+            isdst: ttinfo.tt_isdst == 1,
+            abbreviation: self.tz.tz_abbr.get(ttinfo.tt_abbrind as usize)?.to_string(),
+        })
+    }
+}
+
+/// Reads the footer of a version 2+ tzfile: the POSIX TZ string following the data, if not empty.
+pub(crate) fn read_footer(path: &str) -> Result<Option<String>, Error> {
+    Ok(Tzif::load(path, &Limits::UNLIMITED)?.footer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PosixTz, Zone};
+    use chrono::prelude::*;
+    #[test]
+    fn version3() {
+        assert_eq!(get_tzif_version("/usr/share/zoneinfo/Europe/Paris").unwrap(), 2);
+        for (zone, start) in &[
+            // Friday 02:00, as Thursday 26:00
+            ("Asia/Jerusalem", Utc.ymd(2040, 3, 23).and_hms(0, 0, 0)),
+            // Saturday 23:00, as Sunday -1:00
+            ("America/Nuuk", Utc.ymd(2040, 3, 25).and_hms(1, 0, 0)),
+        ] {
+            let path = format!("/usr/share/zoneinfo/{}", zone);
+            assert_eq!(get_tzif_version(&path).unwrap(), 3);
+            // Past 2037, from the footer
+            let zone = Zone::from_tzfile(&path).unwrap();
+            assert!(!zone.offset_at(*start - chrono::Duration::seconds(1)).unwrap().isdst);
+            assert!(zone.offset_at(*start).unwrap().isdst);
+        }
+        // DST all year
+        let permanent: PosixTz = "EST5EDT,0/0,J365/25".parse().unwrap();
+        assert!(permanent.offset_at(Utc.ymd(2040, 1, 15).and_hms(0, 0, 0)).isdst);
+        assert!(permanent.offset_at(Utc.ymd(2040, 12, 31).and_hms(23, 0, 0)).isdst);
+        assert!(get_tzif_version("/usr/share/zoneinfo/Nowhere").is_err());
+    }
+
+    #[test]
+    fn decode() {
+        for zone in &["Europe/Paris", "America/Nuuk", "Etc/UTC", "right/Europe/Paris"] {
+            let path = format!("/usr/share/zoneinfo/{}", zone);
+            let tzif = Tzif::load(&path, &Limits::default()).unwrap();
+            let tz = libtzfile::parse(&path).unwrap();
+            assert_eq!(tzif.tz.tzh_timecnt_data, tz.tzh_timecnt_data);
+            assert_eq!(tzif.tz.tzh_timecnt_indices, tz.tzh_timecnt_indices);
+            let abbreviation = |tz: &Tz, i: usize| tz.tz_abbr[tz.tzh_typecnt[i].tt_abbrind as usize].clone();
+            for i in 0..tz.tzh_typecnt.len() {
+                assert_eq!(tzif.tz.tzh_typecnt[i].tt_gmtoff, tz.tzh_typecnt[i].tt_gmtoff);
+                assert_eq!(abbreviation(&tzif.tz, i), abbreviation(&tz, i));
+            }
+            assert_eq!(tzif.footer, read_footer(&path).unwrap());
+            assert_eq!(tzif.leap_seconds.is_empty(), !zone.starts_with("right/"));
+        }
+        // Truncated data, or footer
+        let buf = std::fs::read("/usr/share/zoneinfo/Europe/Paris").unwrap();
+        assert!(Tzif::decode(&buf[..buf.len() - 1]).is_err());
+        assert!(Tzif::decode(&buf[..buf.len() / 2]).unwrap_err().tzfile_error().is_some());
+    }
+}
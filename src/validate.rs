@@ -0,0 +1,83 @@
+use crate::{tzif, Error, Limits};
+
+/// An internal consistency problem found in a tzfile.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Violation {
+    /// Transition time not strictly after the previous one
+    UnsortedTransition { index: usize },
+    /// Fewer transition type indices than transition times
+    MissingTypeIndices { transitions: usize, indices: usize },
+    /// Transition referencing a missing local time type
+    TypeIndexOutOfRange { index: usize, type_index: u8 },
+    /// Local time type referencing a missing abbreviation
+    AbbreviationIndexOutOfRange { type_index: usize, abbreviation_index: u8 },
+    /// UT offset not within the -25 to +26 hours range recommended by RFC 8536
+    OffsetOutOfRange { type_index: usize, gmtoff: isize },
+    /// DST flag neither 0 nor 1
+    InvalidDstFlag { type_index: usize, isdst: u8 },
+    /// Abbreviation not made of 3 to 6 ASCII alphanumerics, "+" or "-"
+    MalformedAbbreviation { abbreviation: String },
+}
+
+/// Checks a tzfile's internal consistency: sorted transitions, indices in range, sane offsets, well-formed abbreviations.
+/// Returns every violation found, an empty list meaning the tzfile is sound.
+pub fn validate(requested_timezone: &str) -> Result<Vec<Violation>, Error> {
+    validate_with_limits(requested_timezone, &Limits::UNLIMITED)
+}
+
+/// Same as validate, but rejects a tzfile exceeding the given limits before parsing it.
+pub fn validate_with_limits(requested_timezone: &str, limits: &Limits) -> Result<Vec<Violation>, Error> {
+    let tz = tzif::Tzif::load(requested_timezone, limits)?.tz;
+    let mut violations = Vec::new();
+
+    for (index, pair) in tz.tzh_timecnt_data.windows(2).enumerate() {
+        if pair[1] <= pair[0] {
+            violations.push(Violation::UnsortedTransition { index: index + 1 });
+        }
+    }
+    if tz.tzh_timecnt_indices.len() < tz.tzh_timecnt_data.len() {
+        violations.push(Violation::MissingTypeIndices {
+            transitions: tz.tzh_timecnt_data.len(),
+            indices: tz.tzh_timecnt_indices.len(),
+        });
+    }
+    for (index, type_index) in tz.tzh_timecnt_indices.iter().enumerate() {
+        if *type_index as usize >= tz.tzh_typecnt.len() {
+            violations.push(Violation::TypeIndexOutOfRange { index, type_index: *type_index });
+        }
+    }
+    for (type_index, ttinfo) in tz.tzh_typecnt.iter().enumerate() {
+        if ttinfo.tt_abbrind as usize >= tz.tz_abbr.len() {
+            violations.push(Violation::AbbreviationIndexOutOfRange {
+                type_index,
+                abbreviation_index: ttinfo.tt_abbrind,
+            });
+        }
+        if ttinfo.tt_gmtoff <= -90000 || ttinfo.tt_gmtoff >= 93600 {
+            violations.push(Violation::OffsetOutOfRange { type_index, gmtoff: ttinfo.tt_gmtoff });
+        }
+        if ttinfo.tt_isdst > 1 {
+            violations.push(Violation::InvalidDstFlag { type_index, isdst: ttinfo.tt_isdst });
+        }
+    }
+    for abbreviation in &tz.tz_abbr {
+        let valid_chars = abbreviation
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-');
+        if !valid_chars || abbreviation.len() < 3 || abbreviation.len() > 6 {
+            violations.push(Violation::MalformedAbbreviation { abbreviation: abbreviation.clone() });
+        }
+    }
+    Ok(violations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn sound_tzfiles() {
+        assert_eq!(validate("/usr/share/zoneinfo/Europe/Paris").unwrap(), Vec::new());
+        assert_eq!(validate("/usr/share/zoneinfo/America/Phoenix").unwrap(), Vec::new());
+        assert!(validate("/usr/share/zoneinfo/zone.tab").is_err());
+    }
+}
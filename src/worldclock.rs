@@ -0,0 +1,109 @@
+use crate::{Error, ErrorKind, Zone, ZoneOffset};
+use chrono::prelude::*;
+use std::{cmp::Ordering, convert::TryFrom};
+
+/// Local day of a zone, relative to the reference zone's one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelativeDay {
+    /// An earlier day (the day before, or two days before across the date line)
+    Yesterday,
+    /// Same day
+    Today,
+    /// A later day (the day after, or two days after across the date line)
+    Tomorrow,
+}
+
+/// Local time of one of a WorldClock's zones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZoneTime {
+    /// Zone name
+    pub zone: String,
+    /// Local time
+    pub datetime: DateTime<FixedOffset>,
+    /// Local time type in force
+    pub offset: ZoneOffset,
+    /// Local day, relative to the reference zone
+    pub day: RelativeDay,
+}
+
+/// Local times of a set of zones at a given instant, ie. for meeting planners.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WorldClock {
+    reference: Zone,
+    zones: Vec<Zone>,
+}
+
+impl WorldClock {
+    /// Creates a world clock whose days are relative to the reference zone's (ie. the user's).
+    pub fn new(reference: Zone) -> WorldClock {
+        WorldClock {
+            reference,
+            zones: Vec::new(),
+        }
+    }
+
+    /// Adds a zone.
+    pub fn add(&mut self, zone: Zone) -> &mut WorldClock {
+        self.zones.push(zone);
+        self
+    }
+
+    /// Zones, in order of addition
+    pub fn zones(&self) -> &[Zone] {
+        &self.zones
+    }
+
+    /// Returns the local time of every zone at the given instant, in order of addition.
+    pub fn at(&self, instant: DateTime<Utc>) -> Result<Vec<ZoneTime>, Error> {
+        let today = local(&self.reference, instant)?.0.naive_local().date();
+        let mut times = Vec::new();
+        for zone in &self.zones {
+            let (datetime, offset) = local(zone, instant)?;
+            let day = match datetime.naive_local().date().cmp(&today) {
+                Ordering::Less => RelativeDay::Yesterday,
+                Ordering::Equal => RelativeDay::Today,
+                Ordering::Greater => RelativeDay::Tomorrow,
+            };
+            times.push(ZoneTime {
+                zone: zone.name().to_string(),
+                datetime,
+                offset,
+                day,
+            });
+        }
+        Ok(times)
+    }
+}
+
+/// Local time of a zone at an instant, with the local time type in force
+fn local(zone: &Zone, instant: DateTime<Utc>) -> Result<(DateTime<FixedOffset>, ZoneOffset), Error> {
+    let offset = zone
+        .offset_at(instant)
+        .ok_or_else(|| Error::new(ErrorKind::NoData, "computing local time").with_zone(zone.name()))?;
+    let fixed = i32::try_from(offset.gmtoff)
+        .ok()
+        .and_then(FixedOffset::east_opt)
+        .ok_or_else(|| Error::new(ErrorKind::OutOfRange, "computing local time").with_zone(zone.name()))?;
+    Ok((instant.with_timezone(&fixed), offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TzDatabase;
+    #[test]
+    fn world_clock() {
+        let db = TzDatabase::open("/usr/share/zoneinfo");
+        let mut clock = WorldClock::new(db.zone("Europe/Paris").unwrap());
+        clock
+            .add(db.zone("America/Los_Angeles").unwrap())
+            .add(db.zone("Europe/London").unwrap())
+            .add(db.zone("Asia/Tokyo").unwrap());
+        let times = clock.at(Utc.ymd(2019, 7, 14).and_hms(20, 0, 0)).unwrap();
+        let days: Vec<_> = times.iter().map(|t| t.day).collect();
+        assert_eq!(days, vec![RelativeDay::Today, RelativeDay::Today, RelativeDay::Tomorrow]);
+        assert_eq!(times[0].datetime.to_rfc3339(), "2019-07-14T13:00:00-07:00");
+        assert_eq!(times[1].offset.abbreviation, "BST");
+        assert_eq!(times[2].zone, "Asia/Tokyo");
+    }
+}
@@ -0,0 +1,486 @@
+use crate::{
+    leap, select, tzif::Tzif, year_timechanges, Error, ErrorKind, LeapSecond, Limits, PosixTz, Provenance, RangePolicy,
+    Timechange, TransitionProvider, Tzinfo, YearSpec, ZoneOffset,
+};
+use chrono::prelude::*;
+use std::{
+    hash::{Hash, Hasher},
+    mem,
+    ops::RangeInclusive,
+    sync::Mutex,
+};
+
+/// A zone loaded once from a TransitionProvider: its timechanges, in chronological order,
+/// its POSIX footer and its initial local time type. Analyses run on it whatever the backend.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Zone {
+    name: String,
+    timechanges: Vec<Timechange>,
+    footer: Option<String>,
+    initial: Option<ZoneOffset>,
+    leap_seconds: Vec<LeapSecond>,
+    range_policy: RangePolicy,
+    year_cache: YearCache,
+}
+
+/// Timechanges of the last year a Tzinfo was computed for, memoized by Zone::tzinfo.
+/// Not part of the zone's value: ignored by comparisons and hashing.
+#[derive(Debug, Default)]
+struct YearCache(Mutex<Option<(i32, Vec<Timechange>)>>);
+
+impl YearCache {
+    /// Returns the timechanges of a year, computing them on a cache miss.
+    fn get<F: FnOnce() -> Vec<Timechange>>(&self, year: i32, compute: F) -> Vec<Timechange> {
+        // A poisoned lock only means another thread panicked while computing
+        let mut cached = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        match &*cached {
+            Some((cached_year, timechanges)) if *cached_year == year => timechanges.clone(),
+            _ => {
+                let timechanges = compute();
+                *cached = Some((year, timechanges.clone()));
+                timechanges
+            }
+        }
+    }
+
+    /// Timechanges held
+    fn len(&self) -> usize {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).as_ref().map_or(0, |(_, timechanges)| timechanges.len())
+    }
+
+    /// Heap bytes of the timechanges held
+    fn heap_bytes(&self) -> usize {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).as_ref().map_or(0, |(_, timechanges)| heap_bytes(timechanges))
+    }
+}
+
+/// Heap bytes of timechanges: their buffer, at its capacity, and their abbreviations
+fn heap_bytes(timechanges: &Vec<Timechange>) -> usize {
+    timechanges.capacity() * mem::size_of::<Timechange>()
+        + timechanges.iter().map(|tc| tc.abbreviation.capacity()).sum::<usize>()
+}
+
+impl Clone for YearCache {
+    fn clone(&self) -> YearCache {
+        YearCache::default()
+    }
+}
+
+impl PartialEq for YearCache {
+    fn eq(&self, _: &YearCache) -> bool {
+        true
+    }
+}
+
+impl Eq for YearCache {}
+
+impl Hash for YearCache {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl Zone {
+    /// Loads a zone from a provider.
+    pub fn load<P: TransitionProvider + ?Sized>(provider: &P, name: &str) -> Result<Zone, Error> {
+        let mut timechanges = provider.timechanges(name)?;
+        timechanges.sort_by_key(|tc| tc.time);
+        Ok(Zone {
+            name: name.to_string(),
+            timechanges,
+            footer: provider.footer(name)?,
+            initial: provider.initial(name)?,
+            leap_seconds: provider.leap_seconds(name)?,
+            range_policy: RangePolicy::Extrapolate,
+            year_cache: YearCache::default(),
+        })
+    }
+
+    /// Loads a zone from a tzfile path.
+    pub fn from_tzfile(path: &str) -> Result<Zone, Error> {
+        let tzif = Tzif::load(path, &Limits::UNLIMITED)?;
+        Ok(Zone {
+            name: path.to_string(),
+            timechanges: select(path, &tzif, &YearSpec::All, false, RangePolicy::Extrapolate)?,
+            initial: tzif.initial(),
+            leap_seconds: leap::leap_seconds(&tzif.leap_seconds),
+            footer: tzif.footer,
+            range_policy: RangePolicy::Extrapolate,
+            year_cache: YearCache::default(),
+        })
+    }
+
+    /// Zone name, or tzfile path
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Timechanges, in chronological order
+    pub fn timechanges(&self) -> &[Timechange] {
+        &self.timechanges
+    }
+
+    /// POSIX TZ string ruling the zone after its last timechange, verbatim from the tzfile, if any
+    pub fn footer(&self) -> Option<&str> {
+        self.footer.as_deref()
+    }
+
+    /// Local time type in force before the first timechange, if known
+    pub fn initial(&self) -> Option<&ZoneOffset> {
+        self.initial.as_ref()
+    }
+
+    /// Leap seconds recorded in the zone, in chronological order. Only zones of the right/ tree have some.
+    pub fn leap_seconds(&self) -> &[LeapSecond] {
+        &self.leap_seconds
+    }
+
+    /// POSIX rule of the footer, if it parses
+    pub(crate) fn rule(&self) -> Option<PosixTz> {
+        self.footer.as_ref()?.parse().ok()
+    }
+
+    /// Sets what to answer for instants outside the recorded timechanges, RangePolicy::Extrapolate by default.
+    pub fn with_range_policy(mut self, policy: RangePolicy) -> Zone {
+        self.range_policy = policy;
+        self
+    }
+
+    /// Returns the local time type in force at the given instant: from the timechanges,
+    /// from the POSIX footer after the last one, and from the initial type before the first one.
+    /// None if unknown, or out of range with RangePolicy::Reject.
+    pub fn offset_at(&self, time: DateTime<Utc>) -> Option<ZoneOffset> {
+        self.try_offset_at(time).ok()
+    }
+
+    /// Same as offset_at, failing with ErrorKind::OutOfRange outside the recorded timechanges with RangePolicy::Reject,
+    /// and with ErrorKind::NoData if unknown.
+    pub fn try_offset_at(&self, time: DateTime<Utc>) -> Result<ZoneOffset, Error> {
+        const OPERATION: &str = "computing offset";
+        let (first, last) = match (self.timechanges.first(), self.timechanges.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => {
+                let offset = self.rule().map(|rule| rule.offset_at(time)).or_else(|| self.initial.clone());
+                return offset.ok_or_else(|| Error::new(ErrorKind::NoData, OPERATION).with_zone(&self.name));
+            }
+        };
+        match self.range_policy {
+            RangePolicy::Reject if time < first.time || last.time < time => {
+                return Err(Error::new(ErrorKind::OutOfRange, OPERATION).with_zone(&self.name));
+            }
+            RangePolicy::Clamp if time < first.time => return Ok(ZoneOffset::from(first)),
+            RangePolicy::Extrapolate if last.time < time => {
+                if let Some(rule) = self.rule() {
+                    return Ok(rule.offset_at(time));
+                }
+            }
+            _ => {}
+        }
+        match self.timechanges.iter().rev().find(|tc| tc.time <= time) {
+            Some(tc) => Ok(ZoneOffset::from(tc)),
+            None => self.initial.clone().ok_or_else(|| Error::new(ErrorKind::NoData, OPERATION).with_zone(&self.name)),
+        }
+    }
+
+    /// Tells what answers about an instant are based on, following the range policy.
+    pub fn provenance(&self, time: DateTime<Utc>) -> Provenance {
+        let (first, last) = match (self.timechanges.first(), self.timechanges.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ if self.rule().is_some() => return Provenance::Rule,
+            _ => return Provenance::Initial,
+        };
+        match self.range_policy {
+            RangePolicy::Clamp => Provenance::Recorded,
+            _ if time < first.time => Provenance::Initial,
+            RangePolicy::Extrapolate if last.time < time && self.rule().is_some() => Provenance::Rule,
+            _ => Provenance::Recorded,
+        }
+    }
+
+    /// Same as offset_at, with what the answer is based on.
+    pub fn offset_with_provenance(&self, time: DateTime<Utc>) -> Option<(ZoneOffset, Provenance)> {
+        Some((self.offset_at(time)?, self.provenance(time)))
+    }
+
+    /// Returns the timechanges in `(start, end]`: the recorded ones, then those of the POSIX footer
+    /// unless the range policy keeps to the recorded ones.
+    pub(crate) fn changes_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<Timechange> {
+        let mut changes: Vec<Timechange> = self
+            .timechanges
+            .iter()
+            .filter(|tc| start < tc.time && tc.time <= end)
+            .cloned()
+            .collect();
+        if let Some(rule) = self.rule().filter(|_| self.range_policy == RangePolicy::Extrapolate) {
+            let last = self.timechanges.last().map(|tc| tc.time);
+            for year in start.year()..=end.year() {
+                changes.extend(
+                    rule.timechanges(year)
+                        .into_iter()
+                        .filter(|tc| start < tc.time && tc.time <= end && !matches!(last, Some(last) if tc.time <= last)),
+                );
+            }
+        }
+        changes
+    }
+
+    /// Returns convenient data about the zone at the `now` instant.
+    /// The timechanges of the year are memoized: further calls in the same year only compute the instant's fields.
+    pub fn tzinfo(&self, now: DateTime<Utc>) -> Result<Tzinfo, Error> {
+        let selected = self.year_cache.get(now.year(), || self.year_changes(now.year()));
+        Tzinfo::from_selected(&self.name, selected, &self.timechanges, now)
+    }
+
+    /// Selects the timechanges of a year like get_timechanges_for: extrapolated with the footer rule
+    /// past the recorded ones, or the one in force when there's none that year.
+    fn year_changes(&self, year: i32) -> Vec<Timechange> {
+        let start = |y: i32| NaiveDate::from_ymd_opt(y, 1, 1).and_then(|d| d.and_hms_opt(0, 0, 0));
+        let (yearbeg, yearend) = match (start(year), year.checked_add(1).and_then(start)) {
+            (Some(yearbeg), Some(yearend)) => (Utc.from_utc_datetime(&yearbeg), Utc.from_utc_datetime(&yearend)),
+            _ => return Vec::new(),
+        };
+        // changes_between covers (start, end]
+        let second = chrono::Duration::seconds(1);
+        let selected = self.changes_between(yearbeg - second, yearend - second);
+        if !selected.is_empty() {
+            return selected;
+        }
+        match (&self.initial, self.timechanges.first()) {
+            // No timechange recorded (ie. Etc/UTC): the footer rule or the single local time type applies
+            (_, None) => self
+                .offset_at(yearbeg)
+                .map(|offset| Timechange::new(yearbeg, offset.gmtoff, offset.isdst, &offset.abbreviation))
+                .into_iter()
+                .collect(),
+            // Before the first timechange, the initial local time type applies since the start of the year
+            (Some(initial), Some(first)) if self.range_policy == RangePolicy::Extrapolate && yearbeg <= first.time => {
+                vec![Timechange::new(yearbeg, initial.gmtoff, initial.isdst, &initial.abbreviation)]
+            }
+            _ => year_timechanges(&self.timechanges, year),
+        }
+    }
+
+    /// Number of memoized year timechanges
+    pub(crate) fn cached_timechanges(&self) -> usize {
+        self.year_cache.len()
+    }
+
+    /// Heap bytes held by the zone, at the capacity of its buffers, memoized timechanges included
+    pub(crate) fn heap_bytes(&self) -> usize {
+        heap_bytes(&self.timechanges)
+            + self.year_cache.heap_bytes()
+            + self.name.capacity()
+            + self.footer.as_ref().map_or(0, String::capacity)
+            + self.initial.as_ref().map_or(0, |initial| initial.abbreviation.capacity())
+            + self.leap_seconds.capacity() * mem::size_of::<LeapSecond>()
+    }
+
+    /// Returns a stable fingerprint of the zone's data, see get_fingerprint: its timechanges, footer,
+    /// initial local time type and leap seconds.
+    pub fn fingerprint(&self) -> u64 {
+        // FNV-1a: unlike std's DefaultHasher, its output does not change between Rust releases
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        let mut feed = |bytes: &[u8]| {
+            for b in bytes {
+                hash ^= *b as u64;
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+        };
+        for tc in &self.timechanges {
+            feed(&tc.time.timestamp().to_be_bytes());
+            feed(&(tc.gmtoff as i64).to_be_bytes());
+            feed(&[tc.isdst as u8]);
+            feed(tc.abbreviation.as_bytes());
+            feed(&[0]);
+        }
+        // Slim tzfiles only record changes until the footer takes over: it is part of the data, as LMT and leap seconds
+        feed(&[0xff]);
+        match &self.footer {
+            Some(footer) => {
+                feed(&[1]);
+                feed(footer.as_bytes());
+                feed(&[0]);
+            }
+            None => feed(&[0]),
+        }
+        match &self.initial {
+            Some(initial) => {
+                feed(&[1]);
+                feed(&(initial.gmtoff as i64).to_be_bytes());
+                feed(&[initial.isdst as u8]);
+                feed(initial.abbreviation.as_bytes());
+                feed(&[0]);
+            }
+            None => feed(&[0]),
+        }
+        for leap in &self.leap_seconds {
+            feed(&leap.time.timestamp().to_be_bytes());
+            feed(&leap.correction.to_be_bytes());
+        }
+        hash
+    }
+}
+
+/// Declares a custom zone (ie. a plant-floor "shifted clock"), from explicit timechanges and/or a POSIX rule.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZoneBuilder {
+    name: String,
+    timechanges: Vec<Timechange>,
+    footer: Option<String>,
+    initial: Option<ZoneOffset>,
+}
+
+impl ZoneBuilder {
+    /// Starts an empty zone.
+    pub fn new(name: &str) -> ZoneBuilder {
+        ZoneBuilder {
+            name: name.to_string(),
+            timechanges: Vec::new(),
+            footer: None,
+            initial: None,
+        }
+    }
+
+    /// Sets the local time type in force before the first timechange.
+    pub fn initial(&mut self, initial: ZoneOffset) -> &mut ZoneBuilder {
+        self.initial = Some(initial);
+        self
+    }
+
+    /// Adds a timechange. At a given instant, the last declared timechange wins.
+    pub fn timechange(&mut self, timechange: Timechange) -> &mut ZoneBuilder {
+        self.timechanges.push(timechange);
+        self
+    }
+
+    /// Adds the timechanges of a POSIX rule over the given years, the rule becoming the zone's footer.
+    /// A rule without DST adds its standard time from the first year on.
+    pub fn rule(&mut self, rule: &PosixTz, years: RangeInclusive<i32>) -> &mut ZoneBuilder {
+        if rule.dst.is_none() {
+            if let Some(start) = NaiveDate::from_yo_opt(*years.start(), 1).and_then(|d| d.and_hms_opt(0, 0, 0)) {
+                let start = Utc.from_utc_datetime(&start);
+                self.timechanges.push(Timechange::new(start, rule.std_offset, false, &rule.std_abbreviation));
+            }
+        }
+        for year in years {
+            self.timechanges.extend(rule.timechanges(year));
+        }
+        self.footer = Some(rule.to_string());
+        self
+    }
+
+    /// Builds the zone.
+    pub fn build(&self) -> Zone {
+        let mut timechanges: Vec<Timechange> = Vec::new();
+        for tc in &self.timechanges {
+            match timechanges.iter_mut().find(|t| t.time == tc.time) {
+                Some(t) => *t = tc.clone(),
+                None => timechanges.push(tc.clone()),
+            }
+        }
+        timechanges.sort_by_key(|tc| tc.time);
+        Zone {
+            name: self.name.clone(),
+            timechanges,
+            footer: self.footer.clone(),
+            initial: self.initial.clone(),
+            leap_seconds: Vec::new(),
+            range_policy: RangePolicy::Extrapolate,
+            year_cache: YearCache::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TzfileProvider;
+
+    /// Fixed test data, as a remote service would provide
+    struct Fixture;
+
+    impl TransitionProvider for Fixture {
+        fn timechanges(&self, _zone: &str) -> Result<Vec<Timechange>, Error> {
+            Ok(vec![
+                Timechange::new(Utc.ymd(2019, 10, 27).and_hms(1, 0, 0), 3600, false, "CET"),
+                Timechange::new(Utc.ymd(2019, 3, 31).and_hms(1, 0, 0), 7200, true, "CEST"),
+            ])
+        }
+    }
+
+    #[test]
+    fn providers() {
+        let fixture = Zone::load(&Fixture, "Test/Zone").unwrap();
+        assert_eq!(fixture.timechanges()[0].abbreviation(), "CEST");
+        assert_eq!(fixture.footer(), None);
+        assert_eq!(fixture.stats().transition_count, 2);
+        let paris = Zone::load(&TzfileProvider::default(), "Europe/Paris").unwrap();
+        assert_eq!(paris.footer(), Some("CET-1CEST,M3.5.0,M10.5.0/3"));
+        assert_eq!(paris, Zone::load(&TzfileProvider::default(), "Europe/Paris").unwrap());
+        assert_eq!(paris.fingerprint(), Zone::from_tzfile("/usr/share/zoneinfo/Europe/Paris").unwrap().fingerprint());
+    }
+
+    #[test]
+    fn fingerprint() {
+        let paris = Zone::from_tzfile("/usr/share/zoneinfo/Europe/Paris").unwrap();
+        // Same timechanges, a tzdata upgrade changing the rule only
+        let mut upgraded = paris.clone();
+        upgraded.footer = Some(String::from("CET-1CEST,M3.5.0,M10.5.0/2"));
+        assert_ne!(upgraded.fingerprint(), paris.fingerprint());
+        let mut upgraded = paris.clone();
+        upgraded.initial = Some(ZoneOffset { gmtoff: 600, isdst: false, abbreviation: String::from("LMT") });
+        assert_ne!(upgraded.fingerprint(), paris.fingerprint());
+        let mut upgraded = paris.clone();
+        upgraded.leap_seconds.push(LeapSecond { time: Utc.ymd(2030, 1, 1).and_hms(0, 0, 0), correction: 1 });
+        assert_ne!(upgraded.fingerprint(), paris.fingerprint());
+        assert_eq!(paris.clone().fingerprint(), paris.fingerprint());
+    }
+
+    #[test]
+    fn offset_at() {
+        let paris = Zone::from_tzfile("/usr/share/zoneinfo/Europe/Paris").unwrap();
+        let at = |y, m, d| paris.offset_at(Utc.ymd(y, m, d).and_hms(12, 0, 0)).unwrap().abbreviation;
+        assert_eq!(at(1850, 1, 1), "LMT");
+        assert_eq!(at(2019, 7, 14), "CEST");
+        // Past the recorded timechanges, the footer rules
+        assert_eq!(at(2100, 7, 14), "CEST");
+        assert_eq!(at(2100, 12, 25), "CET");
+        let utc = Zone::from_tzfile("/usr/share/zoneinfo/Etc/UTC").unwrap();
+        assert_eq!(utc.offset_at(Utc::now()).unwrap().gmtoff, 0);
+    }
+
+    #[test]
+    fn builder() {
+        // Paris time, shifted by one hour
+        let rule: PosixTz = "SHIFT-2SHIFTS,M3.5.0/3,M10.5.0/4".parse().unwrap();
+        let zone = ZoneBuilder::new("Plant/Floor")
+            .timechange(Timechange::new(Utc.ymd(2000, 1, 1).and_hms(0, 0, 0), 7200, false, "SHIFT"))
+            .rule(&rule, 2019..=2021)
+            .build();
+        assert_eq!(zone.timechanges().len(), 7);
+        assert_eq!(zone.footer(), Some("SHIFT-2SHIFTS,M3.5.0/3,M10.5.0/4"));
+        let tzinfo = zone.tzinfo(Utc.ymd(2020, 7, 14).and_hms(12, 0, 0)).unwrap();
+        assert_eq!((tzinfo.dst_period, tzinfo.dst_offset), (true, 10800));
+        let utc = ZoneBuilder::new("Fixed").rule(&"UTC0".parse().unwrap(), 1970..=1970).build();
+        assert_eq!(utc.tzinfo(Utc::now()).unwrap().abbreviation, "UTC");
+    }
+
+    #[test]
+    fn year_cache() {
+        let paris = Zone::from_tzfile("/usr/share/zoneinfo/Europe/Paris").unwrap();
+        let summer = Utc.ymd(2019, 7, 14).and_hms(12, 0, 0);
+        assert_eq!(paris.cached_timechanges(), 0);
+        let first = paris.tzinfo(summer).unwrap();
+        assert_eq!(paris.cached_timechanges(), 2);
+        assert_eq!(paris.tzinfo(summer).unwrap(), first);
+        let winter = paris.tzinfo(Utc.ymd(2019, 12, 25).and_hms(12, 0, 0)).unwrap();
+        assert_eq!(winter.abbreviation, "CET");
+        assert_eq!(paris.tzinfo(Utc.ymd(1950, 1, 1).and_hms(0, 0, 0)).unwrap().abbreviation, "CET");
+        let uncached = Tzinfo::from_timechanges(paris.name(), paris.timechanges(), summer).unwrap();
+        assert_eq!(paris.tzinfo(summer).unwrap(), uncached);
+        // Past the recorded timechanges, from the footer rule
+        let late = paris.tzinfo(Utc.ymd(2040, 7, 1).and_hms(12, 0, 0)).unwrap();
+        assert_eq!((late.abbreviation.as_str(), late.dst_period), ("CEST", true));
+        assert_eq!(late.dst_until, Some(Utc.ymd(2040, 10, 28).and_hms(1, 0, 0)));
+        // The cache does not change the zone's value
+        assert_eq!(paris, Zone::from_tzfile("/usr/share/zoneinfo/Europe/Paris").unwrap());
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{bcp47::BCP47, canonical_zone, legacy::LEGACY, resolve::check_name, Error};
+use std::{borrow::Borrow, convert::TryFrom, fmt, iter, ops::Deref, str::FromStr, sync::Arc};
+
+/// A validated zone identifier, normalized once: known zones get their canonical casing ("europe/paris" gives
+/// "Europe/Paris") and deprecated names their current zone ("US/Eastern" gives "America/New_York").
+/// Cloning shares the name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ZoneId(Arc<str>);
+
+impl ZoneId {
+    /// Validates and normalizes a zone identifier. Names which could designate a file outside a zoneinfo tree
+    /// are rejected (see resolve_zone_in), unknown ones are kept as is.
+    pub fn new(name: &str) -> Result<ZoneId, Error> {
+        check_name(name)?;
+        let name = known_zone(name).unwrap_or(name);
+        Ok(ZoneId(Arc::from(canonical_zone(name).unwrap_or(name))))
+    }
+
+    /// The normalized name
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Known spelling of a zone name, matched case insensitively
+fn known_zone(name: &str) -> Option<&'static str> {
+    LEGACY
+        .iter()
+        .flat_map(|(legacy, zone)| iter::once(*legacy).chain(iter::once(*zone)))
+        .chain(BCP47.iter().map(|(_, zone)| *zone))
+        .find(|known| known.eq_ignore_ascii_case(name))
+}
+
+impl FromStr for ZoneId {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<ZoneId, Error> {
+        ZoneId::new(name)
+    }
+}
+
+impl TryFrom<&str> for ZoneId {
+    type Error = Error;
+
+    fn try_from(name: &str) -> Result<ZoneId, Error> {
+        ZoneId::new(name)
+    }
+}
+
+impl Deref for ZoneId {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for ZoneId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for ZoneId {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ZoneId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TzDatabase;
+    use std::collections::HashMap;
+    #[test]
+    fn zone_id() {
+        assert_eq!(ZoneId::new("europe/paris").unwrap().as_str(), "Europe/Paris");
+        assert_eq!(ZoneId::new("US/Eastern").unwrap(), ZoneId::new("America/New_York").unwrap());
+        assert_eq!(ZoneId::new("asia/calcutta").unwrap().as_str(), "Asia/Kolkata");
+        assert_eq!(ZoneId::new("Custom/Zone").unwrap().as_str(), "Custom/Zone");
+        assert!(ZoneId::new("../etc/passwd").is_err());
+        assert!("".parse::<ZoneId>().is_err());
+        let id: ZoneId = "Europe/Paris".parse().unwrap();
+        let mut offsets = HashMap::new();
+        offsets.insert(id.clone(), 3600);
+        assert_eq!(offsets["Europe/Paris"], 3600);
+        let db = TzDatabase::open("/usr/share/zoneinfo");
+        assert_eq!(db.zone(&id).unwrap().name(), "Europe/Paris");
+        let ids = db.zone_ids().unwrap();
+        assert!(ids.contains(&id));
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+    }
+}